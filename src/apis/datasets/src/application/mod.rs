@@ -0,0 +1,6 @@
+pub use shared::application::inputs::dataset_metadata as dataset_metadata_inputs;
+pub use shared::application::inputs::artifacts as artifact_inputs;
+pub use shared::application::inputs::artifact_publication as artifact_publication_inputs;
+pub use shared::application::services;
+pub use shared::application::ports;
+pub use shared::application::errors;
@@ -0,0 +1,81 @@
+//! This module contains factories that wire together infrastructure-level concerns
+//! with application-level concerns
+use mongodb::Database;
+use shared::application::errors::ApplicationError;
+use crate::application::ports::webhooks::WebhookNotifier;
+use crate::application::ports::repositories::{
+    ArtifactRepository,
+    ArtifactIngestionRepository,
+    ModelMetadataRepository,
+    ArtifactPublicationRepository,
+    WebhookDeliveryRepository,
+    DatasetMetadataRepository,
+};
+use crate::application::services::artifact_service::ArtifactService;
+use crate::application::services::dataset_metadata_service::DatasetMetadataService;
+use crate::infra::persistence::mongo::repositories::{
+    ArtifactRepository as MongoArtifactRepository,
+    ArtifactIngestionRepository as MongoArtifactIngestionRepository,
+    ModelMetadataRepository as MongoModelMetadataRepository,
+    ArtifactPublicationRepository as MongoArtifactPublicationRepository,
+    WebhookDeliveryRepository as MongoWebhookDeliveryRepository,
+    DatasetMetadataRepository as MongoDatasetMetadataRepository,
+};
+use crate::infra::messaging::rabbitmq::artifact_op_message_publisher::RabbitMQArtifactOpMessagePublisher;
+use client_provider::{ClientProviderError, ClientRegistry};
+use std::sync::Arc;
+
+#[cfg(feature = "mongo")]
+pub fn artifact_repo_factory(db: &Database) -> Arc<dyn ArtifactRepository> {
+    Arc::new(MongoArtifactRepository::new(db))
+}
+
+#[cfg(feature = "mongo")]
+pub fn artifact_ingestion_repo_factory(db: &Database) -> Arc<dyn ArtifactIngestionRepository> {
+    Arc::new(MongoArtifactIngestionRepository::new(db))
+}
+
+#[cfg(feature = "mongo")]
+pub fn model_metadata_repo_factory(db: &Database) -> Arc<dyn ModelMetadataRepository> {
+    Arc::new(MongoModelMetadataRepository::new(db))
+}
+
+#[cfg(feature = "mongo")]
+pub fn artifact_publication_repo_factory(db: &Database) -> Arc<dyn ArtifactPublicationRepository> {
+    Arc::new(MongoArtifactPublicationRepository::new(db))
+}
+
+#[cfg(feature = "mongo")]
+pub fn dataset_metadata_repo_factory(db: &Database) -> Arc<dyn DatasetMetadataRepository> {
+    Arc::new(MongoDatasetMetadataRepository::new(db))
+}
+
+#[cfg(feature = "mongo")]
+pub fn webhook_delivery_repo_factory(db: &Database) -> Arc<dyn WebhookDeliveryRepository> {
+    Arc::new(MongoWebhookDeliveryRepository::new(db))
+}
+
+pub fn artifact_service_factory(db: &Database, webhook_notifier: Arc<dyn WebhookNotifier>) -> Result<ArtifactService, ApplicationError> {    
+    Ok(ArtifactService::new(
+        artifact_repo_factory(db),
+        artifact_ingestion_repo_factory(db),
+        artifact_publication_repo_factory(db),
+        model_metadata_repo_factory(db),
+        dataset_metadata_repo_factory(db),
+        Arc::new(RabbitMQArtifactOpMessagePublisher {}),
+        webhook_notifier,
+        webhook_delivery_repo_factory(db),
+    ))
+}
+
+pub async fn dataset_metadata_service_factory(db: &Database) -> Result<DatasetMetadataService, ApplicationError> {
+    Ok(DatasetMetadataService::new(
+        dataset_metadata_repo_factory(db),
+        artifact_repo_factory(db),
+    ))
+}
+
+/// Builds the client registry from the environment
+pub fn client_registry_factory() -> Result<ClientRegistry, ClientProviderError<'static>> {
+    ClientRegistry::from_env()
+}
\ No newline at end of file
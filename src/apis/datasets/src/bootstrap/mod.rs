@@ -0,0 +1,2 @@
+pub mod factories;
+pub mod state;
\ No newline at end of file
@@ -1,3 +1 @@
-pub const DEFAULT_PORT: u16 = 8000;
-pub const DEFAULT_HOST: &str = "0.0.0.0";
 pub const VERSION: &str = "0.1.0";
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub use shared::domain::entities;
+pub use shared::domain::services;
\ No newline at end of file
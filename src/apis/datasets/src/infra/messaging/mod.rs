@@ -0,0 +1 @@
+pub mod rabbitmq;
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod web;
+pub mod persistence;
+pub mod messaging;
+pub mod webhooks;
\ No newline at end of file
@@ -0,0 +1,2 @@
+#[cfg(feature = "mongo")]
+pub use shared::infra::persistence::mongo;
\ No newline at end of file
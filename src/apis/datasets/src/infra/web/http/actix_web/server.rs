@@ -0,0 +1,83 @@
+use crate::presentation;
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::client_registry_factory;
+use crate::infra::persistence::mongo::database::{ClientParams, get_db};
+use crate::infra::webhooks::http_webhook_notifier::HttpWebhookNotifier;
+use actix_web::{App, HttpServer};
+use std::env;
+use std::sync::Arc;
+use actix_web::middleware::Logger;
+
+pub async fn run_server() -> std::io::Result<()> {
+    pub const DEFAULT_PORT: u16 = 8000;
+    pub const DEFAULT_HOST: &str = "0.0.0.0";
+    
+    // Initialize the logger
+    env_logger::init();
+
+    // Set the address from env vars HOST and PORT, fallback to default values
+    // if values for these env vars are not defined
+    let addrs = (
+        env::var("HOST").unwrap_or(DEFAULT_HOST.into()),
+        env::var("PORT")
+            .ok()
+            .and_then(|port| port.parse::<u16>().ok())
+            .unwrap_or(DEFAULT_PORT)
+    );
+
+    // Initialize the database
+    let db = get_db(ClientParams{
+        username: env::var("ARTIFACTS_DB_USERNAME").expect("ARTIFACTS_DB_USERNAME env var not set"),
+        password: env::var("ARTIFACTS_DB_PASSWORD").expect("ARTIFACTS_DB_PASSWORD env var not set"),
+        host: env::var("ARTIFACTS_DB_HOST").expect("ARTIFACTS_DB_HOST env var not set"),
+        port: env::var("ARTIFACTS_DB_PORT").expect("ARTIFACTS_DB_PORT env var not set"),
+        db: env::var("ARTIFACTS_DB_NAME").expect("ARTIFACTS_DB_NAME env var not set"),
+    })
+        .await
+        .map_err(|err| {
+            panic!("Database initialization error: {}", err.to_string().as_str()); 
+        })
+        .expect("Database initialization error");
+
+    // Initialize the registry of clients for each platform
+    let clients = client_registry_factory()
+        .map_err(|err| {
+            panic!("Client registry initialization error: {}", err.to_string().as_str());
+        })
+        .expect("Client registry initialization error");
+
+    // Initialize the webhook notifier shared by every request
+    let webhook_notifier = HttpWebhookNotifier::from_env()
+        .map_err(|err| {
+            panic!("Webhook notifier initialization error: {}", err.to_string().as_str());
+        })
+        .expect("Webhook notifier initialization error");
+
+    // Initialize AppState
+    let state = AppState {
+        db,
+        clients: Arc::new(clients),
+        webhook_notifier: Arc::new(webhook_notifier),
+    };
+
+    HttpServer::new(move || {
+        App::new()
+            .wrap(Logger::default())
+            .app_data(actix_web::web::Data::new(state.clone()))
+            .service(presentation::http::v1::actix_web::handlers::index::index)
+            .service(presentation::http::v1::actix_web::handlers::health_check::health_check)
+            .service(presentation::http::v1::actix_web::handlers::list_datasets::list_datasets)
+            .service(presentation::http::v1::actix_web::handlers::get_dataset::get_dataset)
+            .service(presentation::http::v1::actix_web::handlers::ingest_dataset::ingest_dataset)
+            .service(presentation::http::v1::actix_web::handlers::publish_dataset::publish_dataset)
+            .service(presentation::http::v1::actix_web::handlers::download_artifact::download_artifact)
+            .service(presentation::http::v1::actix_web::handlers::upload_artifact::upload_artifact)
+            .service(presentation::http::v1::actix_web::handlers::get_ingestion::get_ingestion)
+            .service(presentation::http::v1::actix_web::handlers::list_artifact_ingestions::list_artifact_ingestions)
+            .service(presentation::http::v1::actix_web::handlers::create_dataset_metadata::create_dataset_metadata)
+            .service(presentation::http::v1::actix_web::handlers::get_dataset_metadata::get_dataset_metadata)
+    })
+        .bind(addrs)?
+        .run()
+        .await
+}
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod actix_web;
\ No newline at end of file
@@ -0,0 +1 @@
+pub use shared::infra::webhooks::http_webhook_notifier;
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub mod application;
+pub mod infra;
+pub mod presentation;
+pub mod domain;
+pub mod config;
+pub mod bootstrap;
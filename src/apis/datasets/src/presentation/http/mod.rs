@@ -0,0 +1 @@
+pub mod v1;
\ No newline at end of file
@@ -0,0 +1,58 @@
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response,
+    build_success_response,
+};
+use crate::presentation::http::v1::dto::{
+    DatasetMetadata,
+    CreateDatasetMetadataPath,
+    CreateDatasetMetadata as CreateDatasetMetadataDto
+};
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::dataset_metadata_service_factory;
+use crate::application::dataset_metadata_inputs::CreateDatasetMetadata as CreateDatasetMetadataInput;
+use actix_web::{post, web, Responder};
+use shared::application::services::dataset_metadata_service::DatasetMetadataServiceError;
+use shared::logging::SharedLogger;
+
+#[post("datasets-api/artifacts/{artifact_id}/metadata")]
+async fn create_dataset_metadata(
+    path: web::Path<CreateDatasetMetadataPath>,
+    body: web::Json<DatasetMetadata>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start create dataset metadata operation");
+
+    let artifact_id = path.into_inner().artifact_id;
+
+    let dto = CreateDatasetMetadataDto {
+        artifact_id: artifact_id.clone(),
+        metadata: body.into_inner()
+    };
+
+    let input = match CreateDatasetMetadataInput::try_from(dto) {
+        Ok(i) => i,
+        Err(err) => return build_error_response(400, err.to_string())
+    };
+
+    let dataset_metadata_service = match dataset_metadata_service_factory(&data.db).await {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    match dataset_metadata_service.create_metadata(input).await {
+        Ok(_) => (),
+        Err(err) => {
+            let status = match err {
+                DatasetMetadataServiceError::ArtifactNotFound(_) => 404,
+                DatasetMetadataServiceError::DomainServiceError(_) => 400,
+                DatasetMetadataServiceError::DuplicateMetadataError(_) => 409,
+                _ => 500
+            };
+            return build_error_response(status, err.to_string())
+        }
+    };
+
+    build_success_response(None, Some(format!("Successfully created metadata for artifact {}", artifact_id)), None)
+}
@@ -0,0 +1,61 @@
+use crate::bootstrap::state::AppState;
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_client_error_response, build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::{GetDatasetPath, GetDatasetRequest, Headers};
+use actix_web::{get, web, HttpRequest, Responder};
+use shared::logging::SharedLogger;
+use std::collections::HashMap;
+
+#[get("datasets-api/platforms/{platform}/datasets/{dataset_id:.*}")]
+async fn get_dataset(
+    req: HttpRequest,
+    path: web::Path<GetDatasetPath>,
+    query: web::Query<HashMap<String, String>>,
+    body: web::Bytes,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start operation get_dataset");
+
+    // Build the request used by the client
+    let headers = match Headers::try_from(req.headers()) {
+        Ok(h) => h,
+        Err(err) => return build_error_response(400, String::from(err.to_string())),
+    };
+
+    let request = GetDatasetRequest {
+        headers,
+        path: path.into_inner(),
+        query: query.into_inner(),
+        body,
+    };
+
+    // Get the client for the provided platform
+    let client =
+        if let Ok(client) = data.clients.get_dataset_client(&request.path.platform) {
+            client
+        } else {
+            return build_error_response(
+                400,
+                String::from(format!(
+                    "Failed to find client for platform '{}'",
+                    &request.path.platform
+                )),
+            );
+        };
+
+    // Fetch the dataset
+    match client.get_dataset(&request).await {
+        Ok(resp) => {
+            return build_success_response(resp.result, Some(String::from("success")), None)
+        }
+        Err(err) => return build_client_error_response(err),
+    }
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "get_dataset.test.rs"]
+mod get_dataset_test;
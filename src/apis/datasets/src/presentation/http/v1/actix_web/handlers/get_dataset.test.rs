@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod get_dataset_test {
+    use actix_web::{test, App};
+    use client_provider::ClientRegistry;
+    use crate::presentation::http::v1::actix_web::handlers::get_dataset::get_dataset;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    #[actix_web::test]
+    async fn test_get_dataset_unsupported_platform() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await)
+                .service(get_dataset)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/datasets-api/platforms/not-a-platform/datasets/org/data")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response,
+    build_success_response,
+};
+use crate::presentation::http::v1::dto::{DatasetMetadata, GetDatasetMetadataPath};
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::dataset_metadata_service_factory;
+use actix_web::{get, web, Responder};
+use serde_json::to_value;
+use shared::application::services::dataset_metadata_service::DatasetMetadataServiceError;
+use shared::logging::SharedLogger;
+use uuid::Uuid;
+
+#[get("datasets-api/artifacts/{artifact_id}/metadata")]
+async fn get_dataset_metadata(
+    path: web::Path<GetDatasetMetadataPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start get dataset metadata operation");
+
+    let artifact_id = match Uuid::parse_str(&path.artifact_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid artifact id: {}", err.to_string())),
+    };
+
+    let dataset_metadata_service = match dataset_metadata_service_factory(&data.db).await {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let metadata = match dataset_metadata_service.get_metadata(artifact_id).await {
+        Ok(m) => m,
+        Err(DatasetMetadataServiceError::MetadataNotFound(msg)) => return build_error_response(404, msg),
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let dto = match DatasetMetadata::try_from(metadata) {
+        Ok(d) => d,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let resp = match to_value(dto) {
+        Ok(r) => r,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    build_success_response(Some(resp), Some("success".into()), None)
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "get_dataset_metadata.test.rs"]
+mod get_dataset_metadata_test;
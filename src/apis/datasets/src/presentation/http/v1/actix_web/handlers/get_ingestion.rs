@@ -0,0 +1,51 @@
+use crate::bootstrap::{factories::artifact_service_factory, state::AppState};
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::GetArtifactIngestionPath;
+use crate::presentation::http::v1::responses::ArtifactIngestion;
+use actix_web::{get, web, Responder};
+use serde_json::to_value;
+use shared::domain::entities::artifact::ArtifactType;
+use shared::logging::SharedLogger;
+use uuid::Uuid;
+
+#[get("datasets-api/ingestions/{ingestion_id}")]
+async fn get_ingestion(
+    path: web::Path<GetArtifactIngestionPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start get ingestion operation");
+
+    let ingestion_id = match Uuid::parse_str(&path.ingestion_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid ingestion id: {}", err.to_string())),
+    };
+
+    let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let ingestion = match artifact_service.find_ingestion_by_ingestion_id(ingestion_id).await {
+        Ok(Some(i)) => i,
+        Ok(None) => return build_error_response(404, format!("Cannot find any record of ArtifactIngestion '{}'", ingestion_id)),
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    // Only ingestions of datasets are visible through the datasets API
+    match artifact_service.find_artifact_by_artifact_id(ingestion.artifact_id.to_string()).await {
+        Ok(Some(artifact)) if artifact.artifact_type == ArtifactType::Dataset => (),
+        Ok(_) => return build_error_response(404, format!("Cannot find any record of ArtifactIngestion '{}'", ingestion_id)),
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let resp = match to_value(ArtifactIngestion::from(ingestion)) {
+        Ok(r) => r,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    build_success_response(Some(resp), Some("success".into()), None)
+}
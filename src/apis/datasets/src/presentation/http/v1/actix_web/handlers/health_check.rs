@@ -0,0 +1,16 @@
+use actix_web::{get, Responder};
+use shared::logging::SharedLogger;
+use crate::presentation::http::v1::actix_web::helpers::build_success_response;
+
+#[get("/datasets-api/health-check")]
+pub async fn health_check() -> impl Responder {
+    let logger = SharedLogger::new();
+    logger.debug("Health check operation");
+    return build_success_response(None, Some(String::from("success")), None);
+}
+
+
+// Handler tests
+#[cfg(test)]
+#[path = "health_check.test.rs"]
+mod health_check_test;
\ No newline at end of file
@@ -0,0 +1,84 @@
+use crate::application::artifact_inputs::IngestArtifactInput;
+use crate::bootstrap::{factories::artifact_service_factory, state::AppState};
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::{
+    Headers, IngestArtifactBody, IngestDatasetPath, IngestDatasetRequest,
+};
+use crate::presentation::http::v1::responses::ArtifactIngestion;
+use actix_web::{post, web, HttpRequest, Responder};
+use serde_json::to_value;
+use shared::logging::SharedLogger;
+use std::collections::HashMap;
+
+#[post("datasets-api/platforms/{platform}/datasets/{dataset_id:.*}/artifacts")]
+async fn ingest_dataset(
+    req: HttpRequest,
+    path: web::Path<IngestDatasetPath>,
+    query: web::Query<HashMap<String, String>>,
+    body: web::Json<IngestArtifactBody>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start ingest dataset operation");
+
+    // Build the request used by the client
+    let headers = match Headers::try_from(req.headers()) {
+        Ok(h) => h,
+        Err(err) => return build_error_response(400, String::from(err.to_string())),
+    };
+
+    let request = IngestDatasetRequest {
+        headers,
+        path: path.into_inner(),
+        query: query.into_inner(),
+        body: body.into_inner(),
+    };
+
+    // Catch directory traversal attacks. 'dataset_id' may be used by clients to
+    // constuct directories in the shared file system
+    if request.path.dataset_id.contains("..") {
+        return build_error_response(403, String::from("Forbidden"));
+    }
+
+    // Fail-fast: Use the client provider to determine the client for the request platform
+    // has the ability to ingest artifacts. The client will not actually be used here,
+    // we are just using this check to fail fast as the client will be invoked
+    // somewhere else later.
+    if let Err(err) = data.clients.ingest_dataset_client(&request.path.platform) {
+        return build_error_response(400, err.to_string());
+    }
+
+    // Instantiate an artifact service
+    let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    // Convert the request dto into an input
+    let input = match IngestArtifactInput::try_from(request) {
+        Ok(i) => i,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    // Ingest the artifact
+    let ingestion = match artifact_service.submit_artifact_ingestion(input).await {
+        Ok(a) => a,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    // Convert to dto
+    let dto = match to_value(ArtifactIngestion::from(ingestion)) {
+        Ok(v) => v,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    build_success_response(Some(dto), Some("success".into()), None)
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "ingest_dataset.test.rs"]
+mod ingest_dataset_test;
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod ingest_dataset_test {
+    use actix_web::{test, App};
+    use client_provider::ClientRegistry;
+    use crate::presentation::http::v1::actix_web::handlers::ingest_dataset::ingest_dataset;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    fn body() -> serde_json::Value {
+        serde_json::json!({
+            "include_paths": null,
+            "exclude_paths": null,
+            "webhook_url": null,
+            "params": null
+        })
+    }
+
+    #[actix_web::test]
+    async fn test_ingest_dataset_directory_traversal() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::new()).await)
+                .service(ingest_dataset)
+        ).await;
+
+        let req = test::TestRequest::post()
+            .uri("/datasets-api/platforms/huggingface/datasets/org/../../etc/artifacts")
+            .set_json(body())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 403);
+    }
+
+    #[actix_web::test]
+    async fn test_ingest_dataset_unsupported_platform() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::new()).await)
+                .service(ingest_dataset)
+        ).await;
+
+        let req = test::TestRequest::post()
+            .uri("/datasets-api/platforms/not-a-platform/datasets/org/dataset/artifacts")
+            .set_json(body())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+}
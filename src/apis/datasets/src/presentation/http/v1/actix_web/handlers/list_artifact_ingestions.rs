@@ -0,0 +1,56 @@
+use crate::bootstrap::{factories::artifact_service_factory, state::AppState};
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::ListArtifactIngestionsPath;
+use crate::presentation::http::v1::responses::ArtifactIngestion;
+use actix_web::{get, web, Responder};
+use serde_json::to_value;
+use shared::application::services::artifact_service::ArtifactServiceError;
+use shared::domain::entities::artifact::ArtifactType;
+use shared::logging::SharedLogger;
+use uuid::Uuid;
+
+#[get("datasets-api/artifacts/{artifact_id}/ingestions")]
+async fn list_artifact_ingestions(
+    path: web::Path<ListArtifactIngestionsPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start list artifact ingestions operation");
+
+    let artifact_id = match Uuid::parse_str(&path.artifact_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid artifact id: {}", err.to_string())),
+    };
+
+    let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    // Only ingestions of datasets are visible through the datasets API
+    match artifact_service.find_artifact_by_artifact_id(artifact_id.to_string()).await {
+        Ok(Some(artifact)) if artifact.artifact_type == ArtifactType::Dataset => (),
+        Ok(_) => return build_error_response(404, format!("Dataset '{}' not found", artifact_id)),
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let ingestions = match artifact_service.find_ingestions_by_artifact_id(artifact_id).await {
+        Ok(i) => i,
+        Err(ArtifactServiceError::NotFound(msg)) => return build_error_response(404, msg),
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let resp = match to_value(
+        ingestions.into_iter()
+            .map(ArtifactIngestion::from)
+            .collect::<Vec<ArtifactIngestion>>()
+    ) {
+        Ok(r) => r,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    build_success_response(Some(resp), Some("success".into()), None)
+}
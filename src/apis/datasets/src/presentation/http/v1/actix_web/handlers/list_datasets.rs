@@ -0,0 +1,60 @@
+use crate::bootstrap::state::AppState;
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_client_error_response, build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::{Headers, ListDatasetsPath, ListDatasetsRequest};
+use actix_web::{get, web, HttpRequest, Responder};
+use shared::logging::SharedLogger;
+use std::collections::HashMap;
+
+#[get("datasets-api/platforms/{platform}/datasets")]
+async fn list_datasets(
+    req: HttpRequest,
+    path: web::Path<ListDatasetsPath>,
+    query: web::Query<HashMap<String, String>>,
+    body: web::Bytes,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+    logger.debug("Start operation list_datasets");
+    logger.debug(format!("path: {:#?}", path).as_str());
+
+    // Get the client for the provided platform
+    let client = if let Ok(client) = data.clients.list_datasets_client(&path.platform) {
+        client
+    } else {
+        return build_error_response(
+            400,
+            String::from(format!(
+                "Failed to provide client for platform '{}'",
+                &path.platform
+            )),
+        );
+    };
+
+    // Build the request used by the client
+    let headers = match Headers::try_from(req.headers()) {
+        Ok(h) => h,
+        Err(err) => return build_error_response(400, String::from(err.to_string())),
+    };
+
+    let request = ListDatasetsRequest {
+        headers,
+        path: path.into_inner(),
+        query: query.into_inner(),
+        body,
+    };
+
+    // Fetch the list of datasets
+    match client.list_datasets(&request).await {
+        Ok(resp) => {
+            return build_success_response(resp.result, Some(String::from("success")), resp.metadata);
+        }
+        Err(err) => return build_client_error_response(err),
+    }
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "list_datasets.test.rs"]
+mod list_datasets_test;
@@ -0,0 +1,16 @@
+pub mod health_check;
+pub mod index;
+pub mod list_datasets;
+pub mod get_dataset;
+pub mod ingest_dataset;
+pub mod publish_dataset;
+pub mod download_artifact;
+pub mod upload_artifact;
+pub mod get_ingestion;
+pub mod list_artifact_ingestions;
+pub mod create_dataset_metadata;
+pub mod get_dataset_metadata;
+
+// Helpers shared by the handler tests
+#[cfg(test)]
+pub mod test_helpers;
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod publish_dataset_test {
+    use actix_web::{test, App};
+    use client_provider::ClientRegistry;
+    use crate::presentation::http::v1::actix_web::handlers::publish_dataset::publish_dataset;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    fn body(target_platform: &str) -> serde_json::Value {
+        serde_json::json!({
+            "target_platform": target_platform,
+            "webhook_url": null,
+            "options": null
+        })
+    }
+
+    #[actix_web::test]
+    async fn test_publish_dataset_unsupported_platform() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await)
+                .service(publish_dataset)
+        ).await;
+
+        let req = test::TestRequest::post()
+            .uri("/datasets-api/artifacts/0196b5a9-0e6f-7d43-a3d2-4c1a2b3c4d5e/publications")
+            .set_json(body("not-a-platform"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+
+    #[actix_web::test]
+    async fn test_publish_dataset_invalid_artifact_id() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await)
+                .service(publish_dataset)
+        ).await;
+
+        let req = test::TestRequest::post()
+            .uri("/datasets-api/artifacts/not-a-uuid/publications")
+            .set_json(body("huggingface"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+}
@@ -0,0 +1,27 @@
+use actix_web::web;
+use client_provider::ClientRegistry;
+use shared::infra::persistence::mongo::database::{get_db, ClientParams};
+use std::sync::Arc;
+use crate::bootstrap::state::AppState;
+use crate::infra::webhooks::http_webhook_notifier::HttpWebhookNotifier;
+
+/// Builds the state of the app the handler tests run against. Building the
+/// database client does not open a connection, so requests that are handled
+/// before reaching the database do not require a running instance
+pub async fn setup_test_app_state(clients: ClientRegistry) -> web::Data<AppState> {
+    web::Data::new(AppState {
+        db: get_db(ClientParams{
+            username: String::from("myuser"),
+            password: String::from("mypassword"),
+            host:     String::from("127.0.0.1"),
+            port:     String::from("27017"),
+            db:       String::from("testdb"),
+        })
+            .await
+            .expect("Database initialization error"),
+        clients: Arc::new(clients),
+        webhook_notifier: Arc::new(
+            HttpWebhookNotifier::from_env().expect("Webhook notifier initialization error")
+        ),
+    })
+}
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod upload_artifact_test {
+    use actix_web::{test, App, http::header};
+    use bytes::Bytes;
+    use client_provider::ClientRegistry;
+    use crate::presentation::http::v1::actix_web::handlers::upload_artifact::upload_artifact;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    #[actix_web::test]
+    async fn test_upload_artifact_requires_zip() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::new()).await)
+                .service(upload_artifact)
+        ).await;
+
+        let boundary = "----TestBoundary12345";
+        let mut body = Vec::new();
+        body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+        body.extend_from_slice(b"Content-Disposition: form-data; name=\"artifact\"; filename=\"dataset.csv\"\r\n");
+        body.extend_from_slice(b"Content-Type: text/csv\r\n\r\n");
+        body.extend_from_slice(b"a,b,c\r\n1,2,3");
+        body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+        let req = test::TestRequest::post()
+            .uri("/datasets-api/artifacts")
+            .insert_header((
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", boundary),
+            ))
+            .set_payload(Bytes::from(body))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+}
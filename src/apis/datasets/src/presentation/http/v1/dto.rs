@@ -0,0 +1,25 @@
+pub use shared::presentation::http::v1::dto::datasets::{
+    ListDatasetsPath,
+    ListDatasetsRequest,
+    GetDatasetPath,
+    GetDatasetRequest,
+    IngestDatasetPath,
+    IngestDatasetRequest,
+    DownloadDatasetPath,
+    DownloadDatasetRequest,
+    UploadDatasetRequest,
+    DatasetMetadata,
+    CreateDatasetMetadataPath,
+    CreateDatasetMetadata,
+    GetDatasetMetadataPath,
+};
+pub use shared::presentation::http::v1::dto::artifacts::{
+    PublishArtifactPath,
+    PublishArtifactRequest,
+    PublishArtifactBody,
+    IngestArtifactBody,
+    GetArtifactIngestionPath,
+    ListArtifactIngestionsPath,
+};
+pub use shared::presentation::http::v1::responses::ArtifactPublication;
+pub use shared::presentation::http::v1::dto::headers::Headers;
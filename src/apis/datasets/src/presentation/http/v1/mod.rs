@@ -0,0 +1,5 @@
+pub mod dto;
+pub mod responses;
+
+#[cfg(feature = "actix")]
+pub mod actix_web;
@@ -0,0 +1 @@
+pub use shared::presentation::http::v1::responses::ArtifactIngestion;
\ No newline at end of file
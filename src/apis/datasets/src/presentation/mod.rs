@@ -0,0 +1 @@
+pub mod http;
\ No newline at end of file
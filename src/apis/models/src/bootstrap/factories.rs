@@ -2,11 +2,14 @@
 //! with application-level concerns
 use mongodb::Database;
 use shared::application::errors::ApplicationError;
+use crate::application::ports::webhooks::WebhookNotifier;
 use crate::application::ports::repositories::{
     ArtifactRepository,
     ArtifactIngestionRepository,
     ModelMetadataRepository,
+    DatasetMetadataRepository,
     ArtifactPublicationRepository,
+    WebhookDeliveryRepository,
 };
 use crate::application::services::artifact_service::ArtifactService;
 use crate::application::services::model_metadata_service::ModelMetadataService;
@@ -14,9 +17,13 @@ use crate::infra::persistence::mongo::repositories::{
     ArtifactRepository as MongoArtifactRepository,
     ArtifactIngestionRepository as MongoArtifactIngestionRepository,
     ModelMetadataRepository as MongoModelMetadataRepository,
+    DatasetMetadataRepository as MongoDatasetMetadataRepository,
     ArtifactPublicationRepository as MongoArtifactPublicationRepository,
+    WebhookDeliveryRepository as MongoWebhookDeliveryRepository,
 };
 use crate::infra::messaging::rabbitmq::artifact_op_message_publisher::RabbitMQArtifactOpMessagePublisher;
+use client_provider::{ClientProviderError, ClientRegistry, Platform, PlatformClients};
+use mlhub_client::client::MlhubClient;
 use std::sync::Arc;
 
 #[cfg(feature = "mongo")]
@@ -34,18 +41,31 @@ pub fn model_metadata_repo_factory(db: &Database) -> Arc<dyn ModelMetadataReposi
     Arc::new(MongoModelMetadataRepository::new(db))
 }
 
+#[cfg(feature = "mongo")]
+pub fn dataset_metadata_repo_factory(db: &Database) -> Arc<dyn DatasetMetadataRepository> {
+    Arc::new(MongoDatasetMetadataRepository::new(db))
+}
+
 #[cfg(feature = "mongo")]
 pub fn artifact_publication_repo_factory(db: &Database) -> Arc<dyn ArtifactPublicationRepository> {
     Arc::new(MongoArtifactPublicationRepository::new(db))
 }
 
-pub fn artifact_service_factory(db: &Database) -> Result<ArtifactService, ApplicationError> {    
+#[cfg(feature = "mongo")]
+pub fn webhook_delivery_repo_factory(db: &Database) -> Arc<dyn WebhookDeliveryRepository> {
+    Arc::new(MongoWebhookDeliveryRepository::new(db))
+}
+
+pub fn artifact_service_factory(db: &Database, webhook_notifier: Arc<dyn WebhookNotifier>) -> Result<ArtifactService, ApplicationError> {    
     Ok(ArtifactService::new(
         artifact_repo_factory(db),
         artifact_ingestion_repo_factory(db),
         artifact_publication_repo_factory(db),
         model_metadata_repo_factory(db),
-        Arc::new(RabbitMQArtifactOpMessagePublisher {})
+        dataset_metadata_repo_factory(db),
+        Arc::new(RabbitMQArtifactOpMessagePublisher {}),
+        webhook_notifier,
+        webhook_delivery_repo_factory(db),
     ))
 }
 
@@ -55,3 +75,19 @@ pub async fn model_metadata_service_factory(db: &Database) -> Result<ModelMetada
         artifact_repo_factory(db),
     ))
 }
+
+/// Builds the client registry from the environment. The mlhub platform is
+/// backed by the same database as the rest of the service
+pub fn client_registry_factory(db: &Database) -> Result<ClientRegistry, ClientProviderError<'static>> {
+    let mut registry = ClientRegistry::from_env()?;
+
+    registry.register(
+        &Platform::Mlhub.to_string(),
+        PlatformClients::mlhub(Arc::new(MlhubClient::new(
+            artifact_repo_factory(db),
+            model_metadata_repo_factory(db),
+        )))
+    );
+
+    Ok(registry)
+}
\ No newline at end of file
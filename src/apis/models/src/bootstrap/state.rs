@@ -1,6 +1,12 @@
+use crate::application::ports::webhooks::WebhookNotifier;
+use client_provider::ClientRegistry;
 use mongodb::Database;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct AppState {
-    pub db: Database
+    pub db: Database,
+    pub clients: Arc<ClientRegistry>,
+    /// Built once at startup and shared by every request
+    pub webhook_notifier: Arc<dyn WebhookNotifier>,
 }
\ No newline at end of file
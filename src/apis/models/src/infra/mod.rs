@@ -1,3 +1,4 @@
 pub mod web;
 pub mod persistence;
-pub mod messaging;
\ No newline at end of file
+pub mod messaging;
+pub mod webhooks;
\ No newline at end of file
@@ -1,8 +1,11 @@
 use crate::presentation;
 use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::client_registry_factory;
 use crate::infra::persistence::mongo::database::{ClientParams, get_db};
+use crate::infra::webhooks::http_webhook_notifier::HttpWebhookNotifier;
 use actix_web::{App, HttpServer};
 use std::env;
+use std::sync::Arc;
 use actix_web::middleware::Logger;
 
 pub async fn run_server() -> std::io::Result<()> {
@@ -22,20 +25,39 @@ pub async fn run_server() -> std::io::Result<()> {
             .unwrap_or(DEFAULT_PORT)
     );
 
+    // Initialize the database
+    let db = get_db(ClientParams{
+        username: env::var("ARTIFACTS_DB_USERNAME").expect("ARTIFACTS_DB_USERNAME env var not set"),
+        password: env::var("ARTIFACTS_DB_PASSWORD").expect("ARTIFACTS_DB_PASSWORD env var not set"),
+        host: env::var("ARTIFACTS_DB_HOST").expect("ARTIFACTS_DB_HOST env var not set"),
+        port: env::var("ARTIFACTS_DB_PORT").expect("ARTIFACTS_DB_PORT env var not set"),
+        db: env::var("ARTIFACTS_DB_NAME").expect("ARTIFACTS_DB_NAME env var not set"),
+    })
+        .await
+        .map_err(|err| {
+            panic!("Database initialization error: {}", err.to_string().as_str()); 
+        })
+        .expect("Datbase initialization error");
+
+    // Initialize the registry of clients for each platform
+    let clients = client_registry_factory(&db)
+        .map_err(|err| {
+            panic!("Client registry initialization error: {}", err.to_string().as_str());
+        })
+        .expect("Client registry initialization error");
+
+    // Initialize the webhook notifier shared by every request
+    let webhook_notifier = HttpWebhookNotifier::from_env()
+        .map_err(|err| {
+            panic!("Webhook notifier initialization error: {}", err.to_string().as_str());
+        })
+        .expect("Webhook notifier initialization error");
+
     // Initialize AppState
     let state = AppState {
-        db: get_db(ClientParams{
-            username: env::var("ARTIFACTS_DB_USERNAME").expect("ARTIFACTS_DB_USERNAME env var not set"),
-            password: env::var("ARTIFACTS_DB_PASSWORD").expect("ARTIFACTS_DB_PASSWORD env var not set"),
-            host: env::var("ARTIFACTS_DB_HOST").expect("ARTIFACTS_DB_HOST env var not set"),
-            port: env::var("ARTIFACTS_DB_PORT").expect("ARTIFACTS_DB_PORT env var not set"),
-            db: env::var("ARTIFACTS_DB_NAME").expect("ARTIFACTS_DB_NAME env var not set"),
-        })
-            .await
-            .map_err(|err| {
-                panic!("Database initialization error: {}", err.to_string().as_str()); 
-            })
-            .expect("Datbase initialization error")
+        db,
+        clients: Arc::new(clients),
+        webhook_notifier: Arc::new(webhook_notifier),
     };
 
     HttpServer::new(move || {
@@ -46,14 +68,26 @@ pub async fn run_server() -> std::io::Result<()> {
             .service(presentation::http::v1::actix_web::handlers::health_check::health_check)
             .service(presentation::http::v1::actix_web::handlers::get_model::get_model)
             .service(presentation::http::v1::actix_web::handlers::list_models::list_models)
+            .service(presentation::http::v1::actix_web::handlers::search_models::search_models)
             .service(presentation::http::v1::actix_web::handlers::ingest_model::ingest_model)
             .service(presentation::http::v1::actix_web::handlers::discover_models::discover_models)
             .service(presentation::http::v1::actix_web::handlers::publish_model::publish_model)
             .service(presentation::http::v1::actix_web::handlers::list_platforms::list_platforms)
+            .service(presentation::http::v1::actix_web::handlers::get_platform::get_platform)
             .service(presentation::http::v1::actix_web::handlers::download_artifact::download_artifact)
             .service(presentation::http::v1::actix_web::handlers::upload_artifact::upload_artifact)
             .service(presentation::http::v1::actix_web::handlers::create_model_metadata::create_model_metadata)
+            .service(presentation::http::v1::actix_web::handlers::get_model_metadata::get_model_metadata)
+            .service(presentation::http::v1::actix_web::handlers::update_model_metadata::update_model_metadata)
+            .service(presentation::http::v1::actix_web::handlers::patch_model_metadata::patch_model_metadata)
+            .service(presentation::http::v1::actix_web::handlers::delete_model_metadata::delete_model_metadata)
+            .service(presentation::http::v1::actix_web::handlers::list_model_metadata::list_model_metadata)
+            .service(presentation::http::v1::actix_web::handlers::search_model_metadata::search_model_metadata)
             .service(presentation::http::v1::actix_web::handlers::publish_model::publish_model)
+            .service(presentation::http::v1::actix_web::handlers::get_ingestion::get_ingestion)
+            .service(presentation::http::v1::actix_web::handlers::get_publication::get_publication)
+            .service(presentation::http::v1::actix_web::handlers::list_artifact_ingestions::list_artifact_ingestions)
+            .service(presentation::http::v1::actix_web::handlers::list_artifact_publications::list_artifact_publications)
     })
         .bind(addrs)?
         .run()
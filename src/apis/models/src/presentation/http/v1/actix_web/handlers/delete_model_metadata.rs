@@ -0,0 +1,39 @@
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response,
+    build_success_response,
+};
+use crate::presentation::http::v1::dto::DeleteModelMetadataPath;
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::model_metadata_service_factory;
+use actix_web::{delete, web, Responder};
+use shared::application::services::model_metadata_service::ModelMetadataServiceError;
+use shared::logging::SharedLogger;
+use uuid::Uuid;
+
+#[delete("models-api/artifacts/{artifact_id}/metadata")]
+async fn delete_model_metadata(
+    path: web::Path<DeleteModelMetadataPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start delete model metadata operation");
+
+    let artifact_id = match Uuid::parse_str(&path.artifact_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid artifact id: {}", err.to_string())),
+    };
+
+    let model_metadata_service = match model_metadata_service_factory(&data.db).await {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    match model_metadata_service.delete_metadata(artifact_id).await {
+        Ok(_) => (),
+        Err(ModelMetadataServiceError::MetadataNotFound(msg)) => return build_error_response(404, msg),
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    build_success_response(None, Some(format!("Successfully deleted metadata for artifact {}", artifact_id)), None)
+}
@@ -1,3 +1,4 @@
+use crate::bootstrap::state::AppState;
 use crate::presentation::http::v1::actix_web::helpers::{
     build_client_error_response, build_error_response, build_success_response,
 };
@@ -5,8 +6,6 @@ use crate::presentation::http::v1::dto::{
     DiscoverModelsPath, DiscoverModelsRequest, DiscoveryCriteriaBody, Headers,
 };
 use actix_web::{post, web, HttpRequest, Responder};
-use client_provider::ClientProvider;
-use clients::DiscoverModelsClient;
 use shared::logging::SharedLogger;
 use std::collections::HashMap;
 
@@ -16,6 +15,7 @@ async fn discover_models(
     path: web::Path<DiscoverModelsPath>,
     query: web::Query<HashMap<String, String>>,
     body: web::Json<DiscoveryCriteriaBody>,
+    data: web::Data<AppState>,
 ) -> impl Responder {
     let logger = SharedLogger::new();
 
@@ -36,7 +36,7 @@ async fn discover_models(
 
     // Get the client for the provided platform
     let client = if let Ok(client) =
-        ClientProvider::provide_discover_models_client(&request.path.platform)
+        data.clients.discover_models_client(&request.path.platform)
     {
         client
     } else {
@@ -6,6 +6,9 @@ mod download_artifact_test {
     use std::io::Write;
     use shared::infra::persistence::mongo::database::{get_db, ClientParams};
     use crate::bootstrap::state::AppState;
+    use crate::infra::webhooks::http_webhook_notifier::HttpWebhookNotifier;
+    use client_provider::ClientRegistry;
+    use std::sync::Arc;
     use crate::presentation::http::v1::actix_web::handlers::download_artifact::download_artifact;
 
     // #[test]
@@ -23,7 +26,11 @@ mod download_artifact_test {
                 .map_err(|err| {
                     panic!("Database initialization error: {}", err.to_string().as_str());
                 })
-                .expect("Datbase initialization error")
+                .expect("Datbase initialization error"),
+            clients: Arc::new(ClientRegistry::new()),
+            webhook_notifier: Arc::new(
+                HttpWebhookNotifier::from_env().expect("Webhook notifier initialization error")
+            ),
         })
     }
 
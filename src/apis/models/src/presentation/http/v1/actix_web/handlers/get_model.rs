@@ -1,10 +1,10 @@
+use crate::bootstrap::state::AppState;
 use crate::presentation::http::v1::actix_web::helpers::{
     build_client_error_response, build_error_response, build_success_response,
 };
 use crate::presentation::http::v1::dto::{GetModelPath, GetModelRequest, Headers};
 use actix_web::{get, web, HttpRequest, Responder};
-use client_provider::ClientProvider;
-use clients::GetModelClient;
+use serde_json::to_value;
 use shared::logging::SharedLogger;
 use std::collections::HashMap;
 
@@ -14,6 +14,7 @@ async fn get_model(
     path: web::Path<GetModelPath>,
     query: web::Query<HashMap<String, String>>,
     body: web::Bytes,
+    data: web::Data<AppState>,
 ) -> impl Responder {
     let logger = SharedLogger::new();
 
@@ -34,7 +35,7 @@ async fn get_model(
 
     // Get the client for the provided platform
     let client =
-        if let Ok(client) = ClientProvider::provide_get_model_client(&request.path.platform) {
+        if let Ok(client) = data.clients.get_model_client(&request.path.platform) {
             client
         } else {
             return build_error_response(
@@ -49,7 +50,12 @@ async fn get_model(
     // Fetch the list of models
     match client.get_model(&request).await {
         Ok(resp) => {
-            return build_success_response(resp.result, Some(String::from("success")), None)
+            let result = match resp.result.map(to_value).transpose() {
+                Ok(r) => r,
+                Err(err) => return build_error_response(500, err.to_string()),
+            };
+
+            return build_success_response(result, Some(String::from("success")), None);
         }
         Err(err) => return build_client_error_response(err),
     }
@@ -1,9 +1,10 @@
 use actix_web::http::header::AUTHORIZATION;
 use actix_web::test;
-use actix_web::web;
 use actix_web::App;
 use actix_web::HttpResponse;
 use crate::presentation::http::v1::actix_web::handlers::get_model::get_model;
+use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+use client_provider::ClientRegistry;
 use shared::presentation::http::v1::dto::models::GetModelPath;
 use std::collections::HashMap;
 use std::env;
@@ -31,7 +32,7 @@ mod get_model_test {
     #[actix_web::test]
     async fn test_get_model_hugging_face_no_auth_header() {
         // creating application to run test
-        let app = test::init_service(App::new().service(get_model)).await;
+        let app = test::init_service(App::new().app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await).service(get_model)).await;
 
         // creating the request
         let req = test::TestRequest::get()
@@ -53,7 +54,7 @@ mod get_model_test {
     async fn test_get_model_hugging_face_auth_header_with_colon() {
         let _ = env_logger::builder().is_test(true).try_init();
         // creating application to run test
-        let app = test::init_service(App::new().service(get_model)).await;
+        let app = test::init_service(App::new().app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await).service(get_model)).await;
 
         // creating the request
         let req = test::TestRequest::get()
@@ -72,13 +73,13 @@ mod get_model_test {
         let response = test::call_service(&app, req).await;
 
         // ensuring api call executed correctly
-        assert!(response.status().is_server_error());
+        assert!(response.status().is_client_error());
     }
 
     #[actix_web::test]
     async fn test_get_model_hugging_face_auth_header_space_in_front() {
         // creating application to run test
-        let app = test::init_service(App::new().service(get_model)).await;
+        let app = test::init_service(App::new().app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await).service(get_model)).await;
 
         // creating the request
         let req = test::TestRequest::get()
@@ -96,14 +97,14 @@ mod get_model_test {
         // calling the api
         let response = test::call_service(&app, req).await;
 
-        // ensuring call executed correctly
-        assert!(response.status().is_server_error());
+        // surrounding whitespace is trimmed from the header value
+        assert!(response.status().is_success());
     }
 
     #[actix_web::test]
     async fn test_get_model_hugging_face_auth_header_bearer_spelled_wrong() {
         // creating application to run test
-        let app = test::init_service(App::new().service(get_model)).await;
+        let app = test::init_service(App::new().app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await).service(get_model)).await;
 
         // creating the request
         let req = test::TestRequest::get()
@@ -122,13 +123,13 @@ mod get_model_test {
         let response = test::call_service(&app, req).await;
 
         // ensuring call executed correctly
-        assert!(response.status().is_server_error());
+        assert!(response.status().is_client_error());
     }
 
     #[actix_web::test]
     async fn test_get_model_hugging_face_auth_header_bearer_only() {
         // creating application to run test
-        let app = test::init_service(App::new().service(get_model)).await;
+        let app = test::init_service(App::new().app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await).service(get_model)).await;
 
         // creating the request
         let req = test::TestRequest::get()
@@ -144,13 +145,13 @@ mod get_model_test {
         let response = test::call_service(&app, req).await;
 
         // ensuring call executed correctly
-        assert!(response.status().is_server_error());
+        assert!(response.status().is_client_error());
     }
 
     #[actix_web::test]
     async fn test_get_model_hugging_face_with_auth_header_pass() {
         // creating application to run test
-        let app = test::init_service(App::new().service(get_model)).await;
+        let app = test::init_service(App::new().app_data(setup_test_app_state(ClientRegistry::with_builtin_platforms()).await).service(get_model)).await;
 
         // creating the request
         let req = test::TestRequest::get()
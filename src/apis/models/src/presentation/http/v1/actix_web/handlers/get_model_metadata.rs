@@ -0,0 +1,55 @@
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response,
+    build_success_response,
+};
+use crate::presentation::http::v1::dto::{ModelMetadata, GetModelMetadataPath};
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::model_metadata_service_factory;
+use actix_web::{get, web, Responder};
+use serde_json::to_value;
+use shared::application::services::model_metadata_service::ModelMetadataServiceError;
+use shared::logging::SharedLogger;
+use uuid::Uuid;
+
+#[get("models-api/artifacts/{artifact_id}/metadata")]
+async fn get_model_metadata(
+    path: web::Path<GetModelMetadataPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start get model metadata operation");
+
+    let artifact_id = match Uuid::parse_str(&path.artifact_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid artifact id: {}", err.to_string())),
+    };
+
+    let model_metadata_service = match model_metadata_service_factory(&data.db).await {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let metadata = match model_metadata_service.get_metadata(artifact_id).await {
+        Ok(m) => m,
+        Err(ModelMetadataServiceError::MetadataNotFound(msg)) => return build_error_response(404, msg),
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let dto = match ModelMetadata::try_from(metadata) {
+        Ok(d) => d,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let resp = match to_value(dto) {
+        Ok(r) => r,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    build_success_response(Some(resp), Some("success".into()), None)
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "get_model_metadata.test.rs"]
+mod get_model_metadata_test;
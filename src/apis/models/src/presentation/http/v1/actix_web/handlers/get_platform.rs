@@ -0,0 +1,34 @@
+use crate::bootstrap::state::AppState;
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::GetPlatformPath;
+use actix_web::{
+    get, web, Responder
+};
+use serde_json::json;
+
+#[get("models-api/platforms/{platform}")]
+async fn get_platform(
+    path: web::Path<GetPlatformPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let capabilities = match data.clients.capabilities(&path.platform) {
+        Ok(c) => c,
+        Err(_) => return build_error_response(404, format!("Platform '{}' not found", &path.platform))
+    };
+
+    build_success_response(
+        Some(json!({
+            "name": &path.platform,
+            "capabilities": capabilities
+        })),
+        None,
+        None
+    )
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "get_platform.test.rs"]
+mod get_platform_test;
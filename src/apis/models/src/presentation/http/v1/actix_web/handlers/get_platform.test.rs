@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod get_platform_test {
+    use actix_web::{test, App};
+    use client_provider::{ClientRegistry, Platform};
+    use clients::ClientConfig;
+    use serde_json::Value;
+    use crate::presentation::http::v1::actix_web::handlers::get_platform::get_platform;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    fn test_clients() -> ClientRegistry {
+        let mut clients = ClientRegistry::new();
+        clients.register_platform("patra", &Platform::Patra, &ClientConfig::default())
+            .expect("Failed to register platform");
+
+        clients
+    }
+
+    #[actix_web::test]
+    async fn test_get_platform() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(test_clients()).await)
+                .service(get_platform)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/platforms/patra")
+            .to_request();
+
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp["result"]["name"], "patra");
+        assert_eq!(resp["result"]["capabilities"]["discover_models"], true);
+        assert_eq!(resp["result"]["capabilities"]["ingest_model"], false);
+    }
+
+    #[actix_web::test]
+    async fn test_get_unknown_platform() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(test_clients()).await)
+                .service(get_platform)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/platforms/unknown")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 404);
+    }
+}
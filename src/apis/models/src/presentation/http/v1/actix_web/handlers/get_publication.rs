@@ -0,0 +1,48 @@
+use crate::bootstrap::{factories::artifact_service_factory, state::AppState};
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::GetArtifactPublicationPath;
+use crate::presentation::http::v1::responses::ArtifactPublication;
+use actix_web::{get, web, Responder};
+use serde_json::to_value;
+use shared::logging::SharedLogger;
+use uuid::Uuid;
+
+#[get("models-api/publications/{publication_id}")]
+async fn get_publication(
+    path: web::Path<GetArtifactPublicationPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start get publication operation");
+
+    let publication_id = match Uuid::parse_str(&path.publication_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid publication id: {}", err.to_string())),
+    };
+
+    let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let publication = match artifact_service.find_publication_by_publication_id(publication_id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => return build_error_response(404, format!("Cannot find any record of ArtifactPublication '{}'", publication_id)),
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let resp = match to_value(ArtifactPublication::from(publication)) {
+        Ok(r) => r,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    build_success_response(Some(resp), Some("success".into()), None)
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "get_publication.test.rs"]
+mod get_publication_test;
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod get_publication_test {
+    use actix_web::{test, App};
+    use client_provider::ClientRegistry;
+    use serde_json::Value;
+    use crate::presentation::http::v1::actix_web::handlers::get_publication::get_publication;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    #[actix_web::test]
+    async fn test_get_publication_invalid_id() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::new()).await)
+                .service(get_publication)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/publications/not-a-uuid")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 400);
+        let body: Value = test::read_body_json(resp).await;
+        assert!(body["message"].as_str().unwrap().starts_with("Invalid publication id"));
+    }
+}
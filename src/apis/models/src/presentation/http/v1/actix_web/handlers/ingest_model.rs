@@ -8,7 +8,6 @@ use crate::presentation::http::v1::dto::{
 };
 use crate::presentation::http::v1::responses::ArtifactIngestion;
 use actix_web::{post, web, HttpRequest, Responder};
-use client_provider::ClientProvider;
 use serde_json::to_value;
 use shared::logging::SharedLogger;
 use std::collections::HashMap;
@@ -48,12 +47,12 @@ async fn ingest_model(
     // has the ability to ingest artifacts. The client will not actually be used here,
     // we are just using this check to fail fast as the client will be invoked
     // somewhere else later.
-    if let Err(err) = ClientProvider::provide_ingest_model_client(&request.path.platform) {
+    if let Err(err) = data.clients.ingest_model_client(&request.path.platform) {
         return build_error_response(400, err.to_string());
     }
 
     // Instantiate an artifact service
-    let artifact_service = match artifact_service_factory(&data.db) {
+    let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
         Ok(s) => s,
         Err(err) => return build_error_response(500, err.to_string()),
     };
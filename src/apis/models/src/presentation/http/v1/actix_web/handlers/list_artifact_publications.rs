@@ -0,0 +1,53 @@
+use crate::bootstrap::{factories::artifact_service_factory, state::AppState};
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response, build_success_response,
+};
+use crate::presentation::http::v1::dto::ListArtifactPublicationsPath;
+use crate::presentation::http::v1::responses::ArtifactPublication;
+use actix_web::{get, web, Responder};
+use serde_json::to_value;
+use shared::application::services::artifact_service::ArtifactServiceError;
+use shared::logging::SharedLogger;
+use uuid::Uuid;
+
+#[get("models-api/artifacts/{artifact_id}/publications")]
+async fn list_artifact_publications(
+    path: web::Path<ListArtifactPublicationsPath>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start list artifact publications operation");
+
+    let artifact_id = match Uuid::parse_str(&path.artifact_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid artifact id: {}", err.to_string())),
+    };
+
+    let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let publications = match artifact_service.find_publications_by_artifact_id(artifact_id).await {
+        Ok(p) => p,
+        Err(ArtifactServiceError::NotFound(msg)) => return build_error_response(404, msg),
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    let resp = match to_value(
+        publications.into_iter()
+            .map(ArtifactPublication::from)
+            .collect::<Vec<ArtifactPublication>>()
+    ) {
+        Ok(r) => r,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    build_success_response(Some(resp), Some("success".into()), None)
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "list_artifact_publications.test.rs"]
+mod list_artifact_publications_test;
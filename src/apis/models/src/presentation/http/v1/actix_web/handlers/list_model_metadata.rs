@@ -0,0 +1,62 @@
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response,
+    build_success_response,
+    build_model_metadata_items,
+};
+use crate::presentation::http::v1::dto::ListModelMetadataQuery;
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::model_metadata_service_factory;
+use crate::application::errors::ApplicationError;
+use actix_web::{get, web, Responder};
+use serde_json::json;
+use shared::application::inputs::filtering::ListAll;
+use shared::application::services::model_metadata_service::ModelMetadataServiceError;
+use shared::logging::SharedLogger;
+
+#[get("models-api/metadata")]
+async fn list_model_metadata(
+    query: web::Query<ListModelMetadataQuery>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start list model metadata operation");
+
+    let input = match ListAll::try_from(query.into_inner()) {
+        Ok(i) => i,
+        Err(err) => return build_error_response(400, err.to_string())
+    };
+
+    let model_metadata_service = match model_metadata_service_factory(&data.db).await {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let fields = input.fields.clone();
+    let (limit, offset) = (input.limit, input.offset);
+
+    let metadata = match model_metadata_service.list_metadata(input).await {
+        Ok(m) => m,
+        // Conversion errors are caused by invalid fields in the query
+        Err(ModelMetadataServiceError::RepoError(ApplicationError::ConvesionError(msg))) => return build_error_response(400, msg),
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let items = match build_model_metadata_items(metadata, &fields) {
+        Ok(i) => i,
+        Err(err) => return build_error_response(500, err)
+    };
+
+    let metadata = json!({
+        "page": offset / limit + 1,
+        "page_size": limit,
+        "count": items.len()
+    });
+
+    build_success_response(Some(json!(items)), Some("success".into()), Some(metadata))
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "list_model_metadata.test.rs"]
+mod list_model_metadata_test;
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod list_model_metadata_test {
+    use actix_web::{test, App};
+    use client_provider::ClientRegistry;
+    use crate::presentation::http::v1::actix_web::handlers::list_model_metadata::list_model_metadata;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    #[actix_web::test]
+    async fn test_list_model_metadata_invalid_page_size() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(ClientRegistry::new()).await)
+                .service(list_model_metadata)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/metadata?page_size=0")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+}
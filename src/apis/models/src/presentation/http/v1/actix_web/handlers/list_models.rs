@@ -1,10 +1,10 @@
+use crate::bootstrap::state::AppState;
 use crate::presentation::http::v1::actix_web::helpers::{
     build_client_error_response, build_error_response, build_success_response,
 };
 use crate::presentation::http::v1::dto::{Headers, ListModelsPath, ListModelsRequest};
 use actix_web::{get, web, HttpRequest, Responder};
-use client_provider::ClientProvider;
-use clients::ListModelsClient;
+use serde_json::to_value;
 use shared::logging::SharedLogger;
 use std::collections::HashMap;
 
@@ -14,13 +14,14 @@ async fn list_models(
     path: web::Path<ListModelsPath>,
     query: web::Query<HashMap<String, String>>,
     body: web::Bytes,
+    data: web::Data<AppState>,
 ) -> impl Responder {
     let logger = SharedLogger::new();
     logger.debug("Start operation list_models");
     logger.debug(format!("path: {:#?}", path).as_str());
 
     // Get the client for the provided platform
-    let client = if let Ok(client) = ClientProvider::provide_list_models_client(&path.platform) {
+    let client = if let Ok(client) = data.clients.list_models_client(&path.platform) {
         client
     } else {
         return build_error_response(
@@ -48,8 +49,18 @@ async fn list_models(
     // Fetch the list of models
     match client.list_models(&request).await {
         Ok(resp) => {
-            return build_success_response(resp.result, Some(String::from("success")), None);
+            let result = match resp.result.map(to_value).transpose() {
+                Ok(r) => r,
+                Err(err) => return build_error_response(500, err.to_string()),
+            };
+
+            return build_success_response(result, Some(String::from("success")), resp.metadata);
         }
         Err(err) => return build_client_error_response(err),
     }
 }
+
+// Handler tests
+#[cfg(test)]
+#[path = "list_models.test.rs"]
+mod list_models_test;
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod list_models_test {
+    use actix_web::{test, App};
+    use async_trait::async_trait;
+    use client_provider::{ClientRegistry, PlatformClients};
+    use clients::{ClientError, ClientJsonResponse, ListModelsClient};
+    use serde_json::{json, Value};
+    use shared::presentation::http::v1::dto::models::ListModelsRequest;
+    use shared::presentation::http::v1::responses::ModelSummary;
+    use std::sync::Arc;
+    use crate::presentation::http::v1::actix_web::handlers::list_models::list_models;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    struct MockListModelsClient;
+
+    #[async_trait]
+    impl ListModelsClient for MockListModelsClient {
+        type Data = Vec<ModelSummary>;
+        type Metadata = Value;
+
+        async fn list_models(&self, _request: &ListModelsRequest) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+            Ok(ClientJsonResponse::new(Some(200), None, Some(vec![ModelSummary {
+                id: String::from("mock-model"),
+                platform: String::from("mock"),
+                name: String::from("Mock Model"),
+                ..Default::default()
+            }]), None))
+        }
+    }
+
+    fn test_clients() -> ClientRegistry {
+        let mut clients = ClientRegistry::new();
+        clients.register("mock", PlatformClients {
+            list_models: Some(Arc::new(MockListModelsClient)),
+            ..Default::default()
+        });
+
+        clients
+    }
+
+    #[actix_web::test]
+    async fn test_list_models_registered_client() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(test_clients()).await)
+                .service(list_models)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/platforms/mock/models")
+            .to_request();
+
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(resp["result"][0]["id"], "mock-model");
+        assert_eq!(resp["result"][0]["platform"], "mock");
+        assert_eq!(resp["result"][0]["tags"], json!([]));
+    }
+}
@@ -1,15 +1,21 @@
+use crate::bootstrap::state::AppState;
 use crate::presentation::http::v1::actix_web::helpers::build_success_response;
-use client_provider::Platform;
 use actix_web::{
-    get, Responder
+    get, web, Responder
 };
-use serde_json::Value;
+use serde_json::{json, Value};
 
 #[get("models-api/platforms")]
-async fn list_platforms() -> impl Responder {
+async fn list_platforms(data: web::Data<AppState>) -> impl Responder {
     let mut platforms = Vec::new();
-    for platform in Platform::list_all() {
-        platforms.push(Value::String(platform.to_string()))
+    for platform in data.clients.platforms() {
+        // Every name returned by the registry is registered
+        if let Ok(capabilities) = data.clients.capabilities(&platform) {
+            platforms.push(json!({
+                "name": platform,
+                "capabilities": capabilities
+            }))
+        }
     }
     build_success_response(Some(Value::Array(platforms)), None, None)
-}
\ No newline at end of file
+}
@@ -4,8 +4,24 @@ pub mod get_model;
 pub mod health_check;
 pub mod index;
 pub mod list_models;
+pub mod search_models;
 pub mod publish_model;
 pub mod list_platforms;
 pub mod upload_artifact;
 pub mod download_artifact;
-pub mod create_model_metadata;
\ No newline at end of file
+pub mod create_model_metadata;
+pub mod get_ingestion;
+pub mod get_publication;
+pub mod list_artifact_ingestions;
+pub mod list_artifact_publications;
+pub mod get_model_metadata;
+pub mod update_model_metadata;
+pub mod patch_model_metadata;
+pub mod delete_model_metadata;
+pub mod list_model_metadata;
+pub mod search_model_metadata;
+pub mod get_platform;
+
+// Helpers shared by the handler tests
+#[cfg(test)]
+pub mod test_helpers;
@@ -0,0 +1,82 @@
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response,
+    build_success_response,
+};
+use crate::presentation::http::v1::dto::{
+    ModelMetadata,
+    UpdateModelMetadataPath,
+    CreateModelMetadata as CreateModelMetadataDto
+};
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::model_metadata_service_factory;
+use crate::application::model_metadata_inputs::CreateModelMetadata as CreateModelMetadataInput;
+use actix_web::{patch, web, Responder};
+use serde_json::{from_value, to_value, Value};
+use shared::application::services::model_metadata_service::ModelMetadataServiceError;
+use shared::logging::SharedLogger;
+use shared::presentation::http::v1::dto::patch::merge_patch;
+use uuid::Uuid;
+
+#[patch("models-api/artifacts/{artifact_id}/metadata")]
+async fn patch_model_metadata(
+    path: web::Path<UpdateModelMetadataPath>,
+    body: web::Json<Value>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start patch model metadata operation");
+
+    let artifact_id = match Uuid::parse_str(&path.artifact_id) {
+        Ok(id) => id,
+        Err(err) => return build_error_response(400, format!("Invalid artifact id: {}", err.to_string())),
+    };
+
+    let model_metadata_service = match model_metadata_service_factory(&data.db).await {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    // Fetch the current metadata to apply the patch to
+    let current = match model_metadata_service.get_metadata(artifact_id).await {
+        Ok(m) => m,
+        Err(ModelMetadataServiceError::MetadataNotFound(msg)) => return build_error_response(404, msg),
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let current = match ModelMetadata::try_from(current) {
+        Ok(d) => d,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    let mut merged = match to_value(current) {
+        Ok(v) => v,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    merge_patch(&mut merged, &body.into_inner());
+
+    // The merged document must still be valid metadata
+    let metadata: ModelMetadata = match from_value(merged) {
+        Ok(m) => m,
+        Err(err) => return build_error_response(400, format!("Invalid metadata: {}", err.to_string()))
+    };
+
+    let input = match CreateModelMetadataInput::try_from(CreateModelMetadataDto {
+        artifact_id: artifact_id.to_string(),
+        metadata
+    }) {
+        Ok(i) => i,
+        Err(err) => return build_error_response(400, err.to_string())
+    };
+
+    match model_metadata_service.update_metadata(input).await {
+        Ok(_) => (),
+        Err(ModelMetadataServiceError::MetadataNotFound(msg)) => return build_error_response(404, msg),
+        Err(ModelMetadataServiceError::ArtifactNotFound(msg)) => return build_error_response(404, msg),
+        Err(ModelMetadataServiceError::DomainServiceError(err)) => return build_error_response(400, err.to_string()),
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    build_success_response(None, Some(format!("Successfully updated metadata for artifact {}", artifact_id)), None)
+}
@@ -7,7 +7,6 @@ use crate::presentation::http::v1::actix_web::helpers::{
 use crate::presentation::http::v1::dto::{Headers, PublishArtifactPath, PublishArtifactBody, PublishArtifactRequest};
 use crate::presentation::http::v1::dto::ArtifactPublication as ArtifactPublicationDto;
 use crate::application::artifact_publication_inputs::PublishArtifactInput;
-use client_provider::ClientProvider;
 use actix_web::{post, web, HttpRequest, Responder};
 use shared::logging::SharedLogger;
 use std::collections::HashMap;
@@ -42,11 +41,11 @@ async fn publish_model(
     // has the ability to publish artifacts. The client will not actually be used here,
     // we are just using this check to fail fast as the client will be invoked
     // somewhere else later.
-    if let Err(err) = ClientProvider::provide_publish_model_client(&request.body.target_platform) {
+    if let Err(err) = data.clients.publish_model_client(&request.body.target_platform) {
         return build_error_response(400, err.to_string());
     }
 
-    let artifact_service = match artifact_service_factory(&data.db) {
+    let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
         Ok(s) => s,
         Err(err) => return build_error_response(500, err.to_string())
     };
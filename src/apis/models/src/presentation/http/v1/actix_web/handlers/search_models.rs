@@ -0,0 +1,168 @@
+use crate::bootstrap::state::AppState;
+use crate::presentation::http::v1::actix_web::helpers::{build_error_response, build_success_response};
+use crate::presentation::http::v1::dto::{Headers, ListModelsPath, ListModelsRequest};
+use actix_web::rt::time::timeout;
+use actix_web::{get, web, Responder};
+use client_provider::DynListModelsClient;
+use clients::ClientError;
+use futures::stream::{self, StreamExt};
+use serde_json::{json, to_value, Value};
+use shared::logging::SharedLogger;
+use shared::presentation::http::v1::responses::ModelSummary;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How long each platform has to respond before it is reported as timed out
+const DEFAULT_PLATFORM_TIMEOUT_SECS: u64 = 10;
+const MAX_PLATFORM_TIMEOUT_SECS: u64 = 60;
+
+/// Query parameter overriding the per-platform timeout. It is not forwarded to
+/// the platforms
+const TIMEOUT_QUERY_PARAM: &str = "timeout_secs";
+const SEARCH_QUERY_PARAM: &str = "search";
+
+/// Maximum number of models returned per platform. The limit is forwarded to
+/// the platforms so that they do not list every model
+const LIMIT_QUERY_PARAM: &str = "limit";
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+
+/// Maximum number of platforms searched at the same time
+const MAX_CONCURRENT_SEARCHES: usize = 8;
+
+/// Why a platform's models are missing from a federated search
+enum PlatformFailure {
+    TimedOut,
+    Failed(ClientError),
+}
+
+/// Searches the models of every platform that supports listing models. A
+/// platform that fails or times out is reported in the metadata of the response
+/// rather than failing the search. Credentials are not forwarded to the
+/// platforms, so only public models are searched
+#[get("models-api/models")]
+async fn search_models(
+    query: web::Query<HashMap<String, String>>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+    logger.debug("Start operation search_models");
+
+    let mut query = query.into_inner();
+
+    let timeout_secs = match query.remove(TIMEOUT_QUERY_PARAM) {
+        Some(value) => match value.parse::<u64>() {
+            Ok(secs) if secs > 0 && secs <= MAX_PLATFORM_TIMEOUT_SECS => secs,
+            _ => return build_error_response(400, format!("Query parameter '{}' must be a number of seconds between 1 and {}", TIMEOUT_QUERY_PARAM, MAX_PLATFORM_TIMEOUT_SECS)),
+        },
+        None => DEFAULT_PLATFORM_TIMEOUT_SECS,
+    };
+
+    let limit = match query.get(LIMIT_QUERY_PARAM).map(|value| value.parse::<usize>()) {
+        Some(Ok(limit)) if limit > 0 && limit <= MAX_LIMIT => limit,
+        Some(_) => return build_error_response(400, format!("Query parameter '{}' must be a number between 1 and {}", LIMIT_QUERY_PARAM, MAX_LIMIT)),
+        None => DEFAULT_LIMIT,
+    };
+    query.insert(String::from(LIMIT_QUERY_PARAM), limit.to_string());
+
+    let search = query.get(SEARCH_QUERY_PARAM)
+        .map(|search| search.trim().to_lowercase())
+        .filter(|search| !search.is_empty());
+
+    // Fan out to every platform, a bounded number at a time. Results keep
+    // the order of the platforms
+    let platforms = data.clients.list_models_clients();
+    let searches = platforms.iter()
+        .map(|(platform, client)| search_platform(platform, client.clone(), &query, Duration::from_secs(timeout_secs)));
+    let results: Vec<_> = stream::iter(searches)
+        .buffered(MAX_CONCURRENT_SEARCHES)
+        .collect()
+        .await;
+
+    let mut models: Vec<ModelSummary> = Vec::new();
+    let mut searched_platforms: Vec<String> = Vec::new();
+    let mut failed_platforms: Vec<Value> = Vec::new();
+
+    for ((platform, _), result) in platforms.iter().zip(results) {
+        let summaries = match result {
+            Ok(s) => s,
+            Err(PlatformFailure::TimedOut) => {
+                failed_platforms.push(json!({
+                    "platform": platform,
+                    "status": 504,
+                    "message": format!("Platform did not respond within the {}s timeout", timeout_secs),
+                }));
+                continue
+            },
+            Err(PlatformFailure::Failed(err)) => {
+                failed_platforms.push(json!({
+                    "platform": platform,
+                    "status": err.status_code(),
+                    "message": err.to_string(),
+                }));
+                continue
+            },
+        };
+
+        searched_platforms.push(platform.clone());
+
+        // Not every platform supports searching or limits, so matches are
+        // filtered and limited again locally. A model served by more than one
+        // registered platform (ex. a mirror) is returned once per platform, as
+        // the platform is needed to fetch or ingest it
+        models.extend(summaries.into_iter()
+            .filter(|summary| search.as_ref().is_none_or(|search| matches_search(summary, search)))
+            .take(limit));
+    }
+
+    let metadata = json!({
+        "count": models.len(),
+        "limit": limit,
+        "platforms": searched_platforms,
+        "failed_platforms": failed_platforms,
+    });
+
+    let result = match to_value(models) {
+        Ok(r) => r,
+        Err(err) => return build_error_response(500, err.to_string()),
+    };
+
+    build_success_response(Some(result), Some(String::from("success")), Some(metadata))
+}
+
+/// Lists the models of a single platform within the timeout
+async fn search_platform(
+    platform: &str,
+    client: Arc<DynListModelsClient>,
+    query: &HashMap<String, String>,
+    timeout_after: Duration,
+) -> Result<Vec<ModelSummary>, PlatformFailure> {
+    let request = ListModelsRequest {
+        headers: Headers::new(Vec::new()),
+        path: ListModelsPath { platform: platform.to_string() },
+        query: query.clone(),
+        body: Default::default(),
+    };
+
+    match timeout(timeout_after, client.list_models(&request)).await {
+        Ok(Ok(resp)) => Ok(resp.result.unwrap_or_default()),
+        Ok(Err(err)) => Err(PlatformFailure::Failed(err)),
+        Err(_) => Err(PlatformFailure::TimedOut),
+    }
+}
+
+/// Whether the search term appears in the id, name, description or tags of a
+/// model. The search term must already be lowercase
+fn matches_search(summary: &ModelSummary, search: &str) -> bool {
+    [Some(&summary.id), Some(&summary.name), summary.description.as_ref()]
+        .into_iter()
+        .flatten()
+        .chain(summary.tags.iter())
+        .any(|value| value.to_lowercase().contains(search))
+}
+
+// Handler tests
+#[cfg(test)]
+#[path = "search_models.test.rs"]
+mod search_models_test;
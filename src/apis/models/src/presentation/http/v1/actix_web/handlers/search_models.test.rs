@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod search_models_test {
+    use actix_web::{test, App};
+    use async_trait::async_trait;
+    use client_provider::{ClientRegistry, PlatformClients};
+    use clients::{ClientError, ClientJsonResponse, ListModelsClient};
+    use serde_json::{json, Value};
+    use shared::presentation::http::v1::dto::models::ListModelsRequest;
+    use shared::presentation::http::v1::responses::ModelSummary;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use crate::presentation::http::v1::actix_web::handlers::search_models::search_models;
+    use crate::presentation::http::v1::actix_web::handlers::test_helpers::setup_test_app_state;
+
+    /// Lists the provided models after an optional delay, or fails if it has
+    /// no models
+    struct MockListModelsClient {
+        models: Option<Vec<&'static str>>,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl ListModelsClient for MockListModelsClient {
+        type Data = Vec<ModelSummary>;
+        type Metadata = Value;
+
+        async fn list_models(&self, request: &ListModelsRequest) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+            // Credentials and the timeout are never forwarded to the platforms
+            assert!(request.headers.into_inner().is_empty());
+            assert!(!request.query.contains_key("timeout_secs"));
+
+            // The limit is always forwarded, but it is ignored here like some
+            // platforms do
+            assert!(request.query.contains_key("limit"));
+
+            actix_web::rt::time::sleep(self.delay).await;
+
+            let models = self.models.clone().ok_or(ClientError::Unavailable(String::from("Platform is down")))?;
+
+            Ok(ClientJsonResponse::new(Some(200), None, Some(models.into_iter()
+                .map(|id| ModelSummary {
+                    id: String::from(id),
+                    // Clients label models with the name the platform is registered under
+                    platform: request.path.platform.clone(),
+                    name: String::from(id),
+                    ..Default::default()
+                })
+                .collect()), None))
+        }
+    }
+
+    fn register(clients: &mut ClientRegistry, name: &str, client: MockListModelsClient) {
+        clients.register(name, PlatformClients {
+            list_models: Some(Arc::new(client)),
+            ..Default::default()
+        });
+    }
+
+    fn test_clients() -> ClientRegistry {
+        let mut clients = ClientRegistry::new();
+        register(&mut clients, "huggingface", MockListModelsClient {
+            models: Some(vec!["org/bert-base", "org/resnet"]),
+            delay: Duration::ZERO,
+        });
+        // A mirror of the same platform lists the same model
+        register(&mut clients, "hf-mirror", MockListModelsClient {
+            models: Some(vec!["org/bert-base"]),
+            delay: Duration::ZERO,
+        });
+        register(&mut clients, "patra", MockListModelsClient {
+            models: Some(vec!["bert-tiny"]),
+            delay: Duration::ZERO,
+        });
+        register(&mut clients, "broken", MockListModelsClient {
+            models: None,
+            delay: Duration::ZERO,
+        });
+        register(&mut clients, "slow", MockListModelsClient {
+            models: Some(vec!["bert-slow"]),
+            delay: Duration::from_secs(5),
+        });
+
+        clients
+    }
+
+    #[actix_web::test]
+    async fn test_search_models_across_platforms() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(test_clients()).await)
+                .service(search_models)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/models?search=BERT&timeout_secs=1")
+            .insert_header(("Authorization", "Bearer hf_token"))
+            .to_request();
+
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+        let models: Vec<(Value, Value)> = resp["result"].as_array().unwrap().iter()
+            .map(|model| (model["platform"].clone(), model["id"].clone()))
+            .collect();
+        assert_eq!(models, vec![
+            (json!("hf-mirror"), json!("org/bert-base")),
+            (json!("huggingface"), json!("org/bert-base")),
+            (json!("patra"), json!("bert-tiny")),
+        ]);
+
+        assert_eq!(resp["metadata"]["count"], 3);
+        assert_eq!(resp["metadata"]["platforms"], json!(["hf-mirror", "huggingface", "patra"]));
+        assert_eq!(resp["metadata"]["failed_platforms"], json!([
+            { "platform": "broken", "status": 503, "message": ClientError::Unavailable(String::from("Platform is down")).to_string() },
+            { "platform": "slow", "status": 504, "message": "Platform did not respond within the 1s timeout" },
+        ]));
+    }
+
+    #[actix_web::test]
+    async fn test_search_models_same_id_on_two_platforms() {
+        let mut clients = ClientRegistry::new();
+        register(&mut clients, "huggingface", MockListModelsClient {
+            models: Some(vec!["org/bert-base"]),
+            delay: Duration::ZERO,
+        });
+        register(&mut clients, "hf-mirror", MockListModelsClient {
+            models: Some(vec!["org/bert-base"]),
+            delay: Duration::ZERO,
+        });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(clients).await)
+                .service(search_models)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/models")
+            .to_request();
+
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+        // The model is returned once for each platform that serves it
+        let models: Vec<(Value, Value)> = resp["result"].as_array().unwrap().iter()
+            .map(|model| (model["platform"].clone(), model["id"].clone()))
+            .collect();
+        assert_eq!(models, vec![
+            (json!("hf-mirror"), json!("org/bert-base")),
+            (json!("huggingface"), json!("org/bert-base")),
+        ]);
+        assert_eq!(resp["metadata"]["count"], 2);
+    }
+
+    #[actix_web::test]
+    async fn test_search_models_limit() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(test_clients()).await)
+                .service(search_models)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/models?limit=1&timeout_secs=1")
+            .to_request();
+
+        let resp: Value = test::call_and_read_body_json(&app, req).await;
+
+        // Each platform returns at most the limit
+        let models: Vec<(Value, Value)> = resp["result"].as_array().unwrap().iter()
+            .map(|model| (model["platform"].clone(), model["id"].clone()))
+            .collect();
+        assert_eq!(models, vec![
+            (json!("hf-mirror"), json!("org/bert-base")),
+            (json!("huggingface"), json!("org/bert-base")),
+            (json!("patra"), json!("bert-tiny")),
+        ]);
+        assert_eq!(resp["metadata"]["limit"], 1);
+
+        for limit in ["0", "101", "ten"] {
+            let req = test::TestRequest::get()
+                .uri(&format!("/models-api/models?limit={}", limit))
+                .to_request();
+
+            let resp = test::call_service(&app, req).await;
+
+            assert_eq!(resp.status(), 400);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_search_models_invalid_timeout() {
+        let app = test::init_service(
+            App::new()
+                .app_data(setup_test_app_state(test_clients()).await)
+                .service(search_models)
+        ).await;
+
+        let req = test::TestRequest::get()
+            .uri("/models-api/models?timeout_secs=0")
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 400);
+    }
+}
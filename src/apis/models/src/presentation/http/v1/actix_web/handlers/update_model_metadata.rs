@@ -0,0 +1,53 @@
+use crate::presentation::http::v1::actix_web::helpers::{
+    build_error_response,
+    build_success_response,
+};
+use crate::presentation::http::v1::dto::{
+    ModelMetadata,
+    UpdateModelMetadataPath,
+    CreateModelMetadata as CreateModelMetadataDto
+};
+use crate::bootstrap::state::AppState;
+use crate::bootstrap::factories::model_metadata_service_factory;
+use crate::application::model_metadata_inputs::CreateModelMetadata as CreateModelMetadataInput;
+use actix_web::{put, web, Responder};
+use shared::application::services::model_metadata_service::ModelMetadataServiceError;
+use shared::logging::SharedLogger;
+
+#[put("models-api/artifacts/{artifact_id}/metadata")]
+async fn update_model_metadata(
+    path: web::Path<UpdateModelMetadataPath>,
+    body: web::Json<ModelMetadata>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let logger = SharedLogger::new();
+
+    logger.debug("Start update model metadata operation");
+
+    let artifact_id = path.into_inner().artifact_id;
+
+    let dto = CreateModelMetadataDto {
+        artifact_id: artifact_id.clone(),
+        metadata: body.into_inner()
+    };
+
+    let input = match CreateModelMetadataInput::try_from(dto) {
+        Ok(i) => i,
+        Err(err) => return build_error_response(400, err.to_string())
+    };
+
+    let model_metadata_service = match model_metadata_service_factory(&data.db).await {
+        Ok(s) => s,
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    match model_metadata_service.update_metadata(input).await {
+        Ok(_) => (),
+        Err(ModelMetadataServiceError::MetadataNotFound(msg)) => return build_error_response(404, msg),
+        Err(ModelMetadataServiceError::ArtifactNotFound(msg)) => return build_error_response(404, msg),
+        Err(ModelMetadataServiceError::DomainServiceError(err)) => return build_error_response(400, err.to_string()),
+        Err(err) => return build_error_response(500, err.to_string())
+    };
+
+    build_success_response(None, Some(format!("Successfully updated metadata for artifact {}", artifact_id)), None)
+}
@@ -37,7 +37,7 @@ async fn upload_artifact(
         }
 
         // Instantiate an artifact service
-        let artifact_service = match artifact_service_factory(&data.db) {
+        let artifact_service = match artifact_service_factory(&data.db, data.webhook_notifier.clone()) {
             Ok(s) => s,
             Err(err) => return build_error_response(500, err.to_string()),
         };
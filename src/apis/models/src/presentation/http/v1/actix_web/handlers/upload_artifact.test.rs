@@ -4,6 +4,9 @@ mod upload_artifact_test {
     use bytes::Bytes;
     use shared::infra::persistence::mongo::database::{get_db, ClientParams};
     use crate::bootstrap::state::AppState;
+    use crate::infra::webhooks::http_webhook_notifier::HttpWebhookNotifier;
+    use client_provider::ClientRegistry;
+    use std::sync::Arc;
     use crate::presentation::http::v1::actix_web::handlers::upload_artifact::upload_artifact;
 
     // #[test]
@@ -21,7 +24,11 @@ mod upload_artifact_test {
                 .map_err(|err| {
                     panic!("Database initialization error: {}", err.to_string().as_str());
                 })
-                .expect("Datbase initialization error")
+                .expect("Datbase initialization error"),
+            clients: Arc::new(ClientRegistry::new()),
+            webhook_notifier: Arc::new(
+                HttpWebhookNotifier::from_env().expect("Webhook notifier initialization error")
+            ),
         })
     }
 
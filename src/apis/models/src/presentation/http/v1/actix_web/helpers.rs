@@ -1,11 +1,15 @@
 use actix_web::HttpResponse;
+use actix_web::http::header::{HeaderValue, RETRY_AFTER};
 use clients::ClientError;
 use shared::presentation::http::v1::actix_web::helpers::{
     build_error_response as error,
     build_success_response as success
 };
 use crate::config::VERSION;
-use serde_json::Value;
+use crate::domain::entities::model_metadata::ModelMetadata;
+use crate::presentation::http::v1::dto::ModelMetadata as ModelMetadataDto;
+use serde_json::{to_value, Map, Value};
+use uuid::Uuid;
 
 pub fn build_client_error_response(err: ClientError) -> HttpResponse {
     let status_code = err.status_code();
@@ -15,6 +19,14 @@ pub fn build_client_error_response(err: ClientError) -> HttpResponse {
         ClientError::Unauthorized { msg, scope: _ } => build_error_response(status_code, msg),
         ClientError::Forbidden { msg, scope: _ } => build_error_response(status_code, msg),
         ClientError::NotFound { msg, scope: _ } => build_error_response(status_code, msg),
+        ClientError::RateLimited { msg, retry_after, scope: _ } => {
+            let mut response = build_error_response(status_code, msg);
+            // Tell the caller when the target platform will accept requests again
+            if let Some(value) = retry_after.and_then(|value| HeaderValue::from_str(&value).ok()) {
+                response.headers_mut().insert(RETRY_AFTER, value);
+            }
+            response
+        },
         ClientError::Unavailable(msg) => build_error_response(status_code, msg),
         ClientError::MissingInvalidCredentials(msg) => build_error_response(status_code, msg),
         ClientError::Unimplemented => build_error_response(status_code, "Unimplemented".into()),
@@ -33,4 +45,31 @@ pub fn build_success_response(result: Option<Value>, message: Option<String>, me
     };
 
     success(result,  message, Some(String::from(VERSION)), meta)
+}
+
+/// Converts listed model metadata into response items. When fields are
+/// provided, only those fields are included in each item. The artifact id is
+/// always included
+pub fn build_model_metadata_items(metadata: Vec<(Uuid, ModelMetadata)>, fields: &[String]) -> Result<Vec<Value>, String> {
+    let mut items: Vec<Value> = Vec::with_capacity(metadata.len());
+    for (artifact_id, m) in metadata {
+        let dto = ModelMetadataDto::try_from(m)
+            .map_err(|err| err.to_string())?;
+
+        let mut item = match to_value(dto).map_err(|err| err.to_string())? {
+            Value::Object(map) => map,
+            _ => Map::new()
+        };
+
+        if !fields.is_empty() {
+            // Projected fields may be nested (ex. inference_hardware.memory_gb)
+            // so only the top level field is used to select the value
+            item.retain(|key, _| fields.iter().any(|field| field.split('.').next() == Some(key.as_str())));
+        }
+
+        item.insert(String::from("artifact_id"), Value::String(artifact_id.to_string()));
+        items.push(Value::Object(item));
+    }
+
+    Ok(items)
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 pub use shared::presentation::http::v1::dto::models::{
     ListModelsPath,
     ListModelsRequest,
+    GetPlatformPath,
     GetModelPath,
     GetModelRequest,
     DiscoverModelsPath,
@@ -13,13 +14,22 @@ pub use shared::presentation::http::v1::dto::models::{
     UploadModelRequest,
     ModelMetadata,
     CreateModelMetadataPath,
-    CreateModelMetadata
+    CreateModelMetadata,
+    GetModelMetadataPath,
+    UpdateModelMetadataPath,
+    DeleteModelMetadataPath,
+    ListModelMetadataQuery,
 };
+pub use shared::presentation::http::v1::dto::filtering::ListAll;
 pub use shared::presentation::http::v1::dto::artifacts::{
     PublishArtifactPath,
     PublishArtifactRequest,
     PublishArtifactBody,
     IngestArtifactBody,
+    GetArtifactIngestionPath,
+    GetArtifactPublicationPath,
+    ListArtifactIngestionsPath,
+    ListArtifactPublicationsPath,
 };
 pub use shared::presentation::http::v1::responses::ArtifactPublication;
 pub use shared::presentation::http::v1::dto::headers::Headers;
\ No newline at end of file
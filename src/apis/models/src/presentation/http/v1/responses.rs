@@ -1 +1 @@
-pub use shared::presentation::http::v1::responses::ArtifactIngestion;
\ No newline at end of file
+pub use shared::presentation::http::v1::responses::{ArtifactIngestion, ArtifactPublication};
\ No newline at end of file
@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
 use amqprs::{
     channel::{
         BasicAckArguments, 
@@ -21,26 +22,31 @@ use amqprs::{
 };
 use tokio;
 use uuid::Uuid;
-use client_provider::ClientProvider;
+use client_provider::ClientRegistry;
+use clients::IngestionResult;
+use serde::de::DeserializeOwned;
 use shared::constants::ARTIFACT_INGEST_DIR_NAME;
 use shared::domain::entities::artifact_ingestion::{ArtifactIngestionFailureReason, ArtifactIngestionStatus};
 use shared::domain::entities::artifact::ArtifactType;
 use shared::constants::{ARTIFACT_INGESTION_EXCHANGE, ARTIFACT_INGESTION_QUEUE, ARTIFACT_INGESTION_ROUTING_KEY};
 use shared::presentation::http::v1::dto::models::IngestModelRequest;
 use shared::infra::system::Env;
-// use shared::datasets::presentation::http::v1::dto::IngestDatasetRequest;
+use shared::presentation::http::v1::dto::datasets::IngestDatasetRequest;
 use shared::infra::messaging::messages::IngestArtifactMessage;
 use async_trait::async_trait;
 use shared::application::services::artifact_service::ArtifactService;
 use std::env;
-use artifact_ingester::bootstrap::artifact_service_factory;
+use std::sync::Arc;
+use artifact_ingester::bootstrap::{artifact_service_factory, client_registry_factory};
 use artifact_ingester::database::{get_db, ClientParams};
 use shared::infra::fs::archiver::Archiver;
+use shared::infra::webhooks::http_webhook_notifier::HttpWebhookNotifier;
 
 struct ArtifactIngesterConsumer {
     artifact_service: ArtifactService,
     artifacts_work_dir: PathBuf,
     artifacts_cache_dir: PathBuf,
+    clients: Arc<ClientRegistry>,
 }
 
 #[async_trait]
@@ -79,148 +85,184 @@ impl AsyncConsumer for ArtifactIngesterConsumer {
         // Set the download path based on whether this is a model or a dataset
         let download_path = self.artifacts_work_dir.join(artifact.id.to_string());
 
-        // Ingest the artifact
-        match artifact.artifact_type {
-            ArtifactType::Model => {
-                // Get the correct client to do the model ingestion
-                match ClientProvider::provide_ingest_model_client(&request.platform) {
-                    Ok(client) => {
-                        // Update the ingestion to Downloading
-                        self.artifact_service.change_ingestion_status_by_ingestion_id(
-                            ingestion_id.clone(),
-                            ArtifactIngestionStatus::Downloading,
-                            Some("Download in progress".into())
-                        )
-                            .await
-                            .map_err(|err| {
-                                panic!("Error updating ingestion status: {}", err.to_string())
-                            }).unwrap();
-                        
-                        // Deserialize the client request
-                        let client_request: IngestModelRequest = serde_json::from_slice(&request.serialized_client_request)
-                            .expect("Failed deserializing the client request");
-                        
-                        // Ingest the model
-                        match client.ingest_model(&client_request, download_path.clone()).await {
-                            Ok(_) => {
-                                // Update ingestion to Downloaded
-                                self.artifact_service.change_ingestion_status_by_ingestion_id(
-                                    ingestion_id.clone(),
-                                    ArtifactIngestionStatus::Downloaded,
-                                    Some("Download complete".into())
-                                )
-                                    .await
-                                    .map_err(|err| {
-                                        panic!("Error updating ingestion status: {}", err.to_string())
-                                    }).unwrap();
-                                
-                                // Update ingestion to Archiving
-                                self.artifact_service.change_ingestion_status_by_ingestion_id(
-                                    ingestion_id.clone(),
-                                    ArtifactIngestionStatus::Archiving,
-                                    Some("Archiving started".into())
-                                )
-                                    .await
-                                    .map_err(|err| {
-                                        panic!("Error updating ingestion status: {}", err.to_string())
-                                    }).unwrap();
-                                
-                                // Archive the artifact files with compression
-                                let maybe_artifact_path = Archiver::zip(
-                                    &download_path,
-                                    &PathBuf::from(&self.artifacts_cache_dir).join(artifact.id.clone().to_string()),
-                                    None,
-                                    // This is the base path, this path will be stripped from every file
-                                    // and directory that is written
-                                    Some(
-                                        self.artifacts_work_dir.join(artifact.id.clone().to_string())
-                                            .to_string_lossy()
-                                            .into_owned()
-                                            .as_str()
-                                    ),
-                                );
-
-                                // Get the artifact path
-                                let artifact_path = match maybe_artifact_path {
-                                    Ok(p) => p,
-                                    Err(err) => {
-                                        self.artifact_service.change_ingestion_status_by_ingestion_id(
-                                            ingestion_id.clone(),
-                                            ArtifactIngestionStatus::Failed(ArtifactIngestionFailureReason::FailedToArchive),
-                                            Some(err.to_string())
-                                        )
-                                            .await
-                                            .map_err(|err| {
-                                                panic!("Error updating ingestion status: {}", err.to_string())
-                                            }).unwrap();
-                                        return 
-                                    }
-                                };
-                                
-                                // Update ingestion to Archived
-                                self.artifact_service.change_ingestion_status_by_ingestion_id(
-                                    ingestion_id.clone(),
-                                    ArtifactIngestionStatus::Archived,
-                                    Some("Successfully ingested".into())
-                                )
-                                    .await
-                                    .map_err(|err| {
-                                        panic!("Error updating ingestion status: {}", err.to_string())
-                                    }).unwrap();
-
-                                // Clean up the ingestion workdir
-                                std::fs::remove_dir_all(&download_path)
-                                    .expect(format!("Error removing files at path {}", &download_path.to_string_lossy().to_string()).as_str());
-                                
-                                // Get the updated ingestion
-                                let ref mut ingestion = self.artifact_service.find_ingestion_by_ingestion_id(ingestion_id)
-                                    .await
-                                    .expect("Error fetching ingestion")
-                                    .expect("Ingestion should exist but does not");
-                                
-                                // Set the path to the artifact on the Artifact itself
-                                self.artifact_service.finish_artifact_ingestion(artifact_path, artifact, ingestion)
-                                    .await
-                                    .map_err(|err| panic!("Error finishing artifact ingestion: {}", err.to_string()))
-                                    .unwrap();
-                            },
-                            Err(err) => {
-                                self.artifact_service.change_ingestion_status_by_ingestion_id(
-                                    ingestion_id.clone(),
-                                    ArtifactIngestionStatus::Failed(ArtifactIngestionFailureReason::FailedToDownload),
-                                    Some(err.to_string())
-                                )
-                                    .await
-                                    .map_err(|err| {
-                                        panic!("Error updating ingestion status: {}", err.to_string())
-                                    }).unwrap();
-
-                                eprintln!("{}", err.to_string());
-                                nack(&channel, &deliver, None, None).await;
-                                return;
-                            }
-                        };
-                    },
-                    Err(err) => {
-                        eprintln!("Client provider error in consumer '{}': {}", &deliver.consumer_tag(), err.to_string());
-                        nack(&channel, &deliver, None, None).await;
-                        return;
-                    }
-                };
-            },
-            // Ingest the dataset
-            ArtifactType::Dataset => {
-                eprintln!("Artifact ingestion not yet available for datasets");
+        // Ingest the artifact files. Both models and datasets go through the
+        // same ingestion lifecycle and only differ in the client that does the
+        // downloading
+        let ingestion_result = match self.ingest(&artifact.artifact_type, &request, ingestion_id, &download_path).await {
+            Ok(result) => result,
+            Err(err) => {
+                // The message is not requeued, so the ingestion is marked as
+                // failed rather than being left in its current status
+                self.artifact_service.change_ingestion_status_by_ingestion_id(
+                    ingestion_id.clone(),
+                    ArtifactIngestionStatus::Failed(ArtifactIngestionFailureReason::FailedToDownload),
+                    Some(err.clone())
+                )
+                    .await
+                    .map_err(|err| {
+                        panic!("Error updating ingestion status: {}", err.to_string())
+                    }).unwrap();
+
+                eprintln!("Ingestion error in consumer '{}': {}", &deliver.consumer_tag(), err);
+                remove_work_dir(&download_path);
                 nack(&channel, &deliver, None, None).await;
+                return;
+            }
+        };
+
+        // Update ingestion to Downloaded
+        self.artifact_service.change_ingestion_status_by_ingestion_id(
+            ingestion_id.clone(),
+            ArtifactIngestionStatus::Downloaded,
+            Some("Download complete".into())
+        )
+            .await
+            .map_err(|err| {
+                panic!("Error updating ingestion status: {}", err.to_string())
+            }).unwrap();
+
+        // Record the commit the requested revision resolved to
+        if let Some(resolved_revision) = ingestion_result.resolved_revision {
+            self.artifact_service.record_ingestion_resolved_revision(ingestion_id.clone(), resolved_revision)
+                .await
+                .map_err(|err| {
+                    panic!("Error recording resolved revision: {}", err.to_string())
+                }).unwrap();
+        }
+        
+        // Update ingestion to Archiving
+        self.artifact_service.change_ingestion_status_by_ingestion_id(
+            ingestion_id.clone(),
+            ArtifactIngestionStatus::Archiving,
+            Some("Archiving started".into())
+        )
+            .await
+            .map_err(|err| {
+                panic!("Error updating ingestion status: {}", err.to_string())
+            }).unwrap();
+        
+        // Archive the artifact files with compression
+        let maybe_artifact_path = Archiver::zip(
+            &download_path,
+            &PathBuf::from(&self.artifacts_cache_dir).join(artifact.id.clone().to_string()),
+            None,
+            // This is the base path, this path will be stripped from every file
+            // and directory that is written
+            Some(
+                self.artifacts_work_dir.join(artifact.id.clone().to_string())
+                    .to_string_lossy()
+                    .into_owned()
+                    .as_str()
+            ),
+        );
+
+        // Get the artifact path
+        let artifact_path = match maybe_artifact_path {
+            Ok(p) => p,
+            Err(err) => {
+                self.artifact_service.change_ingestion_status_by_ingestion_id(
+                    ingestion_id.clone(),
+                    ArtifactIngestionStatus::Failed(ArtifactIngestionFailureReason::FailedToArchive),
+                    Some(err.to_string())
+                )
+                    .await
+                    .map_err(|err| {
+                        panic!("Error updating ingestion status: {}", err.to_string())
+                    }).unwrap();
+                remove_work_dir(&download_path);
                 return 
             }
         };
+        
+        // Update ingestion to Archived
+        self.artifact_service.change_ingestion_status_by_ingestion_id(
+            ingestion_id.clone(),
+            ArtifactIngestionStatus::Archived,
+            Some("Successfully ingested".into())
+        )
+            .await
+            .map_err(|err| {
+                panic!("Error updating ingestion status: {}", err.to_string())
+            }).unwrap();
+
+        // Clean up the ingestion workdir
+        std::fs::remove_dir_all(&download_path)
+            .expect(format!("Error removing files at path {}", &download_path.to_string_lossy().to_string()).as_str());
+        
+        // Get the updated ingestion
+        let ref mut ingestion = self.artifact_service.find_ingestion_by_ingestion_id(ingestion_id)
+            .await
+            .expect("Error fetching ingestion")
+            .expect("Ingestion should exist but does not");
+        
+        // Set the path to the artifact on the Artifact itself
+        self.artifact_service.finish_artifact_ingestion(artifact_path, artifact, ingestion)
+            .await
+            .map_err(|err| panic!("Error finishing artifact ingestion: {}", err.to_string()))
+            .unwrap();
             
         // Acknowledge the message
         ack(&channel, &deliver, None).await;
     }
 }
 
+impl ArtifactIngesterConsumer {
+    /// Downloads the artifact files with the ingestion client of the artifact's
+    /// type. Errors with the message recorded on the failed ingestion
+    async fn ingest(
+        &self,
+        artifact_type: &ArtifactType,
+        request: &IngestArtifactMessage,
+        ingestion_id: Uuid,
+        download_path: &Path
+    ) -> Result<IngestionResult, String> {
+        match artifact_type {
+            ArtifactType::Model => {
+                let client = self.clients.ingest_model_client(&request.platform)
+                    .map_err(|err| format!("Client provider error: {}", err))?;
+                let client_request: IngestModelRequest = self.start_download(ingestion_id, request).await?;
+                client.ingest_model(&client_request, download_path.to_path_buf()).await
+                    .map_err(|err| err.to_string())
+            },
+            ArtifactType::Dataset => {
+                let client = self.clients.ingest_dataset_client(&request.platform)
+                    .map_err(|err| format!("Client provider error: {}", err))?;
+                let client_request: IngestDatasetRequest = self.start_download(ingestion_id, request).await?;
+                client.ingest_dataset(&client_request, download_path.to_path_buf()).await
+                    .map_err(|err| err.to_string())
+            }
+        }
+    }
+
+    /// Updates the ingestion to Downloading and deserializes the request that
+    /// is passed to the ingestion client
+    async fn start_download<T: DeserializeOwned>(&self, ingestion_id: Uuid, request: &IngestArtifactMessage) -> Result<T, String> {
+        self.artifact_service.change_ingestion_status_by_ingestion_id(
+            ingestion_id,
+            ArtifactIngestionStatus::Downloading,
+            Some("Download in progress".into())
+        )
+            .await
+            .map_err(|err| {
+                panic!("Error updating ingestion status: {}", err.to_string())
+            }).unwrap();
+
+        serde_json::from_slice(&request.serialized_client_request)
+            .map_err(|err| format!("Failed deserializing the client request: {}", err))
+    }
+}
+
+/// Removes the files downloaded for an ingestion. A failed ingestion is never
+/// resumed, as every ingestion downloads into the work dir of a new artifact,
+/// so its partial files would otherwise be left on the volume
+fn remove_work_dir(path: &Path) {
+    if let Err(err) = std::fs::remove_dir_all(path) {
+        if err.kind() != ErrorKind::NotFound {
+            eprintln!("Error removing files at path {}: {}", path.to_string_lossy(), err.to_string());
+        }
+    }
+}
+
 async fn connect_to_broker(args: &OpenConnectionArguments, max_connection_attempts: i8) -> Connection {
     println!("Attempting to connect to broker");
     
@@ -348,10 +390,18 @@ async fn main() -> () {
     
     let environment = Env::new().expect("Env could not be initialized");
 
+    let webhook_notifier = HttpWebhookNotifier::from_env()
+        .expect("failed to initialize webhook notifier");
+
+    // Registry of the clients for each platform. Built once so the clients
+    // are shared by every message
+    let clients = client_registry_factory().expect("failed to initialize client registry");
+
     let consumer = ArtifactIngesterConsumer {
-        artifact_service: artifact_service_factory(&db).expect("failed to initialize artifact service"),
+        artifact_service: artifact_service_factory(&db, Arc::new(webhook_notifier)).expect("failed to initialize artifact service"),
         artifacts_work_dir: PathBuf::from(&environment.shared_data_dir).join(ARTIFACT_INGEST_DIR_NAME),
-        artifacts_cache_dir: PathBuf::from(&environment.artifacts_cache_dir)
+        artifacts_cache_dir: PathBuf::from(&environment.artifacts_cache_dir),
+        clients: Arc::new(clients),
     };
      
     let args = BasicConsumeArguments::default()
@@ -7,15 +7,21 @@ use shared::application::ports::repositories::{
     ArtifactIngestionRepository,
     ArtifactPublicationRepository,
     ModelMetadataRepository,
+    DatasetMetadataRepository,
+    WebhookDeliveryRepository,
 };
+use shared::application::ports::webhooks::WebhookNotifier;
 use shared::application::services::artifact_service::ArtifactService;
 use shared::infra::persistence::mongo::repositories::{
     ArtifactRepository as MongoArtifactRepository,
     ArtifactIngestionRepository as MongoArtifactIngestionRepository,
     ArtifactPublicationRepository as MongoArtifactPublicationRepository,
     ModelMetadataRepository as MongoModelMetadataRepository,
+    DatasetMetadataRepository as MongoDatasetMetadataRepository,
+    WebhookDeliveryRepository as MongoWebhookDeliveryRepository,
 };
 use shared::infra::messaging::rabbitmq::artifact_op_message_publisher::RabbitMQArtifactOpMessagePublisher;
+use client_provider::{ClientProviderError, ClientRegistry};
 use std::sync::Arc;
 
 pub fn artifact_repo_factory(db: &Database) -> Arc<dyn ArtifactRepository> {
@@ -34,12 +40,29 @@ pub fn model_metadata_repo_factory(db: &Database) -> Arc<dyn ModelMetadataReposi
     Arc::new(MongoModelMetadataRepository::new(db))
 }
 
-pub fn artifact_service_factory(db: &Database) -> Result<ArtifactService, ApplicationError> {    
+pub fn dataset_metadata_repo_factory(db: &Database) -> Arc<dyn DatasetMetadataRepository> {
+    Arc::new(MongoDatasetMetadataRepository::new(db))
+}
+
+pub fn webhook_delivery_repo_factory(db: &Database) -> Arc<dyn WebhookDeliveryRepository> {
+    Arc::new(MongoWebhookDeliveryRepository::new(db))
+}
+
+pub fn artifact_service_factory(db: &Database, webhook_notifier: Arc<dyn WebhookNotifier>) -> Result<ArtifactService, ApplicationError> {    
     Ok(ArtifactService::new(
         artifact_repo_factory(db),
         artifact_ingestion_repo_factory(db),
         artifact_publication_repo_factory(db),
         model_metadata_repo_factory(db),
-        Arc::new(RabbitMQArtifactOpMessagePublisher {})
+        dataset_metadata_repo_factory(db),
+        Arc::new(RabbitMQArtifactOpMessagePublisher {}),
+        webhook_notifier,
+        webhook_delivery_repo_factory(db),
     ))
-}
\ No newline at end of file
+}
+
+/// Builds the registry of the clients for each platform from the built-in
+/// platforms and the CLIENT_REGISTRY_CONFIG file
+pub fn client_registry_factory() -> Result<ClientRegistry, ClientProviderError<'static>> {
+    ClientRegistry::from_env()
+}
@@ -21,9 +21,9 @@ use amqprs::{
 };
 use tokio;
 use uuid::Uuid;
-use client_provider::ClientProvider;
+use client_provider::ClientRegistry;
 use shared::domain::entities::artifact_publication::{ArtifactPublicationFailureReason, ArtifactPublicationStatus};
-use shared::domain::entities::artifact::ArtifactType;
+use shared::domain::entities::artifact::{Artifact, ArtifactType};
 use shared::constants::{ARTIFACT_PUBLICATION_EXCHANGE, ARTIFACT_PUBLICATION_QUEUE, ARTIFACT_PUBLICATION_ROUTING_KEY};
 use shared::presentation::http::v1::dto::artifacts::PublishArtifactRequest;
 use shared::infra::system::Env;
@@ -33,14 +33,17 @@ use shared::infra::messaging::messages::PublishArtifactMessage;
 use async_trait::async_trait;
 use shared::application::services::artifact_service::ArtifactService;
 use std::env;
-use artifact_publisher::bootstrap::artifact_service_factory;
+use std::sync::Arc;
+use artifact_publisher::bootstrap::{artifact_service_factory, client_registry_factory};
 use artifact_publisher::database::{get_db, ClientParams};
 use shared::infra::fs::archiver::Archiver;
-use clients::{ClientError, PublishModelClient, PublishModelMetadataClient};
+use shared::infra::webhooks::http_webhook_notifier::HttpWebhookNotifier;
+use clients::ClientError;
 
 struct ArtifactPublisherConsumer {
     artifact_service: ArtifactService,
     publications_work_dir: PathBuf,
+    clients: Arc<ClientRegistry>,
 }
 
 #[async_trait]
@@ -91,27 +94,24 @@ impl AsyncConsumer for ArtifactPublisherConsumer {
         match artifact.artifact_type {
             ArtifactType::Model => {
                 // Fetch metadata associated with the model
-                let metadata = self.artifact_service.find_metadata_by_artifact_id(
-                    &publication.artifact_id
-                ).await
-                    .expect(format!("Failed to fetch metadata for artifact '{}'", &artifact.id.to_string()).as_str());
+                let metadata = match self.artifact_service.find_metadata_by_artifact_id(&publication.artifact_id).await {
+                    Ok(m) => m,
+                    Err(err) => {
+                        let msg = format!("Failed to fetch metadata for artifact '{}': {}", &artifact.id, err.to_string());
+                        self.fail_publication(&publication_id, ArtifactPublicationFailureReason::InternalError(msg)).await;
+                        ack(&channel, &deliver, None).await;
+                        return;
+                    }
+                };
 
                 // Update artifact publication to Pending
-                self.artifact_service.change_publication_status_by_publication_id(
-                    publication.id.clone(),
-                    ArtifactPublicationStatus::Pending,
-                    Some("Publication pending".into())
-                )
-                .await
-                .map_err(|err| {
-                    panic!("Error updating publication status: {}", err.to_string())
-                }).unwrap();
+                self.update_publication_status(&publication_id, ArtifactPublicationStatus::Pending, "Publication pending").await;
                 
                 // Check whether at least one of the publish_model_client or the 
                 // publish_metadata_client exists
                 let (maybe_publish_model_client, maybe_publish_metadata_client) = {
-                    let maybe_model = ClientProvider::provide_publish_model_client(&publication.target_platform);
-                    let maybe_meta  = ClientProvider::provide_publish_metadata_client(&publication.target_platform);
+                    let maybe_model = self.clients.publish_model_client(&publication.target_platform);
+                    let maybe_meta  = self.clients.publish_metadata_client(&publication.target_platform);
                 
                     match (maybe_model, maybe_meta) {
                         (Err(_), Err(_)) => panic!(
@@ -126,122 +126,60 @@ impl AsyncConsumer for ArtifactPublisherConsumer {
                 // Extract the artifact files and publish those files to the target
                 // platform
                 if let Some(client) = maybe_publish_model_client {
-                    // Update publication status to Extracting
-                    self.artifact_service.change_publication_status_by_publication_id(
-                        publication_id.clone(),
-                        ArtifactPublicationStatus::Extracting,
-                        Some("Extracting artifact files".into())
-                    )
-                        .await
-                        .map_err(|err| {
-                            panic!("Error updating publication status: {}", err.to_string())
-                        }).unwrap();
-                    
-                    // Path to which the files should be extracted
-                    let extracted_artifact_path = self.publications_work_dir.clone()
-                        .join(PathBuf::from(publication.id.to_string().clone()));
-                    
-                    // Extract the archived artifact files
-                    let _ = Archiver::unzip(
-                        &artifact_path,
-                        &extracted_artifact_path,
-                        None,
-                    ).map_err(|err| panic!("Error extracting artifact {}: {}", artifact.id.to_string(), err.to_string()));
-                    
-                    // Update publication status to Extracted
-                    self.artifact_service.change_publication_status_by_publication_id(
-                        publication_id.clone(),
-                        ArtifactPublicationStatus::Extracted,
-                        Some("Successfully extracted artifact file(s)".into())
-                    )
-                        .await
-                        .map_err(|err| {
-                            panic!("Error updating publication status: {}", err.to_string())
-                        }).unwrap();
-
-                    // Update publication status to PublishingArtifact
-                    self.artifact_service.change_publication_status_by_publication_id(
-                        publication_id.clone(),
-                        ArtifactPublicationStatus::PublishingArtifact,
-                        Some("Started publishing artifact".into())
-                    )
-                        .await
-                        .map_err(|err| {
-                            panic!("Error updating publication status: {}", err.to_string())
-                        }).unwrap();
-                    
-                    // Publish the model files to the target platform
-                    match client.publish_model(&extracted_artifact_path, &artifact, &metadata, &client_request).await {
-                        Ok(_) => {            
-                            // Update publication status to PublishedArtifact
-                            self.artifact_service.change_publication_status_by_publication_id(
-                                publication_id.clone(),
-                                ArtifactPublicationStatus::PublishedArtifact,
-                                Some("Successfully published artifact".into())
-                            )
-                                .await
-                                .map_err(|err| {
-                                    panic!("Error updating publication status: {}", err.to_string())
-                                }).unwrap();
+                    let published = match self.extract_artifact_files(&publication_id, &artifact, &artifact_path).await {
+                        Ok(extracted_artifact_path) => {
+                            // Publish the model files to the target platform
+                            let result = client.publish_model(&extracted_artifact_path, &artifact, &metadata, &client_request).await;
+                            self.finish_publishing_artifact_files(&publication_id, &extracted_artifact_path, result.map(|_| ())).await
                         },
+                        Err(err) => Err(err)
+                    };
+
+                    match published {
+                        Ok(_) => {},
                         // Do nothing if getting an unimplemented error. This is because
                         // we have already guaranteed that either there is a publish model
                         // client, or a publish model metadata client and a platform client
                         // only needs to implement one of those.
-                        Err(ClientError::Unimplemented)  => {
-                            println!("What?");
+                        Err(PublishFilesError::Publish(ClientError::Unimplemented)) => {},
+                        // Artifacts that cannot be extracted will never be published
+                        Err(err @ PublishFilesError::Extract(_)) => {
+                            self.fail_publication(&publication_id, err.into()).await;
+                            ack(&channel, &deliver, None).await;
+                            return;
                         },
                         // All other errors are considered failure conditions. Handle them
                         // accordingly
                         Err(err) => {
-                            println!("Failed: {}", err.to_string());
-                            self.artifact_service.change_publication_status_by_publication_id(
-                                publication_id.clone(),
-                                ArtifactPublicationStatus::Failed(ArtifactPublicationFailureReason::FailedToPublishArtifact(err.to_string())),
-                                Some(err.to_string())
-                            )
-                                .await
-                                .map_err(|err| {
-                                    panic!("Error updating publication status: {}", err.to_string())
-                                }).unwrap();
-
-                            eprintln!("{}", err.to_string());
+                            self.fail_publication(&publication_id, err.into()).await;
                             nack(&channel, &deliver, None, None).await;
                             return;
                         }
                     };
-
-                    // Clean up the extracted_artifact_path
-                    std::fs::remove_dir_all(&extracted_artifact_path)
-                        .expect(format!("Error cleaning up extracted artifact at path {}", &extracted_artifact_path.to_string_lossy().to_string()).as_str());
                 }
 
                 // Publish the model metadata to the target platform
                 if let Some(client) = maybe_publish_metadata_client {
                     // Update publication status to PublishingMetadata
-                    self.artifact_service.change_publication_status_by_publication_id(
-                        publication_id.clone(),
-                        ArtifactPublicationStatus::PublishingMetadata,
-                        Some("Artifact published successfully".into())
-                    )
-                        .await
-                        .map_err(|err| {
-                            panic!("Error updating publication status: {}", err.to_string())
-                        }).unwrap();
+                    self.update_publication_status(&publication_id, ArtifactPublicationStatus::PublishingMetadata, "Artifact published successfully").await;
                     
-                    // Publish the model files to the target platform
+                    // Publish the model metadata to the target platform
                     match client.publish_model_metadata(&metadata, &client_request).await {
-                        Ok(_) => {
+                        Ok(result) => {
+                            // Record the ids the target platform assigned to the metadata
+                            if !result.published_ids.is_empty() {
+                                self.artifact_service.record_publication_published_ids(
+                                    publication_id.clone(),
+                                    result.published_ids
+                                )
+                                    .await
+                                    .map_err(|err| {
+                                        panic!("Error recording published ids: {}", err.to_string())
+                                    }).unwrap();
+                            }
+
                             // Update publication status to PublishedMetadata
-                            self.artifact_service.change_publication_status_by_publication_id(
-                                publication_id.clone(),
-                                ArtifactPublicationStatus::PublishedMetadata,
-                                Some("Metadata published successfully".into())
-                            )
-                                .await
-                                .map_err(|err| {
-                                    panic!("Error updating publication status: {}", err.to_string())
-                                }).unwrap();
+                            self.update_publication_status(&publication_id, ArtifactPublicationStatus::PublishedMetadata, "Metadata published successfully").await;
                         },
                         // Do nothing if getting an unimplemented error. This is because
                         // we have already guaranteed that either there is a publish model
@@ -251,17 +189,7 @@ impl AsyncConsumer for ArtifactPublisherConsumer {
                         // All other errors are considered failure conditions. Handle them
                         // accordingly
                         Err(err) => {
-                            self.artifact_service.change_publication_status_by_publication_id(
-                                publication_id.clone(),
-                                ArtifactPublicationStatus::Failed(ArtifactPublicationFailureReason::FailedToPublishArtifact(err.to_string())),
-                                Some(err.to_string())
-                            )
-                                .await
-                                .map_err(|err| {
-                                    panic!("Error updating publication status: {}", err.to_string())
-                                }).unwrap();
-
-                            eprintln!("{}", err.to_string());
+                            self.fail_publication(&publication_id, ArtifactPublicationFailureReason::FailedToPublishMetadata(err.to_string())).await;
                             nack(&channel, &deliver, None, None).await;
                             return;
                         }
@@ -270,29 +198,151 @@ impl AsyncConsumer for ArtifactPublisherConsumer {
             },
             // Publish the dataset
             ArtifactType::Dataset => {
-                // let client = ClientProvider::provide_publish_dataset_client(&request.platform)
-                //     .map_err(|err| {
-                //         eprintln!("{}", err);
-                //     });
-                eprintln!("Artifact publication not yet available for datasets");
-                nack(&channel, &deliver, None, None).await;
-                return 
+                // Fetch metadata associated with the dataset
+                let metadata = match self.artifact_service.find_dataset_metadata_by_artifact_id(&publication.artifact_id).await {
+                    Ok(m) => m,
+                    Err(err) => {
+                        let msg = format!("Failed to fetch metadata for artifact '{}': {}", &artifact.id, err.to_string());
+                        self.fail_publication(&publication_id, ArtifactPublicationFailureReason::InternalError(msg)).await;
+                        ack(&channel, &deliver, None).await;
+                        return;
+                    }
+                };
+
+                // Update artifact publication to Pending
+                self.update_publication_status(&publication_id, ArtifactPublicationStatus::Pending, "Publication pending").await;
+
+                // Datasets have no metadata-only publication, so the platform must
+                // support publishing the dataset files
+                let client = match self.clients.publish_dataset_client(&publication.target_platform) {
+                    Ok(c) => c,
+                    Err(err) => {
+                        self.fail_publication(&publication_id, ArtifactPublicationFailureReason::PlatformError(err.to_string())).await;
+                        ack(&channel, &deliver, None).await;
+                        return;
+                    }
+                };
+
+                let published = match self.extract_artifact_files(&publication_id, &artifact, &artifact_path).await {
+                    Ok(extracted_artifact_path) => {
+                        // Publish the dataset files to the target platform
+                        let result = client.publish_dataset(&extracted_artifact_path, &artifact, &metadata, &client_request).await;
+                        self.finish_publishing_artifact_files(&publication_id, &extracted_artifact_path, result.map(|_| ())).await
+                    },
+                    Err(err) => Err(err)
+                };
+
+                match published {
+                    Ok(_) => {},
+                    // Artifacts that cannot be extracted will never be published
+                    Err(err @ PublishFilesError::Extract(_)) => {
+                        self.fail_publication(&publication_id, err.into()).await;
+                        ack(&channel, &deliver, None).await;
+                        return;
+                    },
+                    Err(err) => {
+                        self.fail_publication(&publication_id, err.into()).await;
+                        nack(&channel, &deliver, None, None).await;
+                        return;
+                    }
+                };
             }
         };
 
         // Update publication status to Finsihed
+        self.update_publication_status(&publication_id, ArtifactPublicationStatus::Finished, "Successfully published").await;
+
+        // Acknowledge the message
+        ack(&channel, &deliver, None).await;
+    }
+}
+
+/// Reasons the files of an artifact could not be published
+enum PublishFilesError {
+    Extract(String),
+    Publish(ClientError),
+}
+
+impl From<PublishFilesError> for ArtifactPublicationFailureReason {
+    fn from(err: PublishFilesError) -> Self {
+        match err {
+            PublishFilesError::Extract(msg) => Self::FailedToExtract(msg),
+            PublishFilesError::Publish(err) => Self::FailedToPublishArtifact(err.to_string()),
+        }
+    }
+}
+
+impl ArtifactPublisherConsumer {
+    async fn update_publication_status(&self, publication_id: &Uuid, status: ArtifactPublicationStatus, message: &str) {
         self.artifact_service.change_publication_status_by_publication_id(
             publication_id.clone(),
-            ArtifactPublicationStatus::Finished,
-            Some("Successfully published".into())
+            status,
+            Some(message.into())
         )
             .await
             .map_err(|err| {
                 panic!("Error updating publication status: {}", err.to_string())
             }).unwrap();
+    }
 
-        // Acknowledge the message
-        ack(&channel, &deliver, None).await;
+    async fn fail_publication(&self, publication_id: &Uuid, reason: ArtifactPublicationFailureReason) {
+        let msg = match &reason {
+            ArtifactPublicationFailureReason::FailedToQueue(msg)
+            | ArtifactPublicationFailureReason::FailedToExtract(msg)
+            | ArtifactPublicationFailureReason::FailedToPublishArtifact(msg)
+            | ArtifactPublicationFailureReason::FailedToPublishMetadata(msg)
+            | ArtifactPublicationFailureReason::InternalError(msg)
+            | ArtifactPublicationFailureReason::PlatformError(msg) => msg.clone()
+        };
+
+        eprintln!("{}", &msg);
+        self.update_publication_status(publication_id, ArtifactPublicationStatus::Failed(reason), &msg).await;
+    }
+
+    /// Extracts the archived artifact files into the work directory of the
+    /// publication and returns the path of the extracted files
+    async fn extract_artifact_files(
+        &self,
+        publication_id: &Uuid,
+        artifact: &Artifact,
+        artifact_path: &PathBuf
+    ) -> Result<PathBuf, PublishFilesError> {
+        self.update_publication_status(publication_id, ArtifactPublicationStatus::Extracting, "Extracting artifact files").await;
+
+        // Path to which the files should be extracted
+        let extracted_artifact_path = self.publications_work_dir.clone()
+            .join(PathBuf::from(publication_id.to_string()));
+
+        // Extract the archived artifact files
+        if let Err(err) = Archiver::unzip(artifact_path, &extracted_artifact_path, None) {
+            let _ = std::fs::remove_dir_all(&extracted_artifact_path);
+            return Err(PublishFilesError::Extract(format!("Error extracting artifact {}: {}", artifact.id, err.to_string())))
+        }
+
+        self.update_publication_status(publication_id, ArtifactPublicationStatus::Extracted, "Successfully extracted artifact file(s)").await;
+        self.update_publication_status(publication_id, ArtifactPublicationStatus::PublishingArtifact, "Started publishing artifact").await;
+
+        Ok(extracted_artifact_path)
+    }
+
+    /// Removes the extracted artifact files whether or not they were published
+    /// and records the publication of the files
+    async fn finish_publishing_artifact_files(
+        &self,
+        publication_id: &Uuid,
+        extracted_artifact_path: &PathBuf,
+        published: Result<(), ClientError>
+    ) -> Result<(), PublishFilesError> {
+        // Clean up the extracted_artifact_path
+        if let Err(err) = std::fs::remove_dir_all(extracted_artifact_path) {
+            eprintln!("Error cleaning up extracted artifact at path {}: {}", extracted_artifact_path.to_string_lossy(), err.to_string());
+        }
+
+        published.map_err(PublishFilesError::Publish)?;
+
+        self.update_publication_status(publication_id, ArtifactPublicationStatus::PublishedArtifact, "Successfully published artifact").await;
+
+        Ok(())
     }
 }
 
@@ -423,10 +473,18 @@ async fn main() -> () {
     
     let environment = Env::new().expect("Env could not be initialized");
 
+    let webhook_notifier = HttpWebhookNotifier::from_env()
+        .expect("failed to initialize webhook notifier");
+
+    // Registry of the clients for each platform. Built once so the clients
+    // are shared by every message
+    let clients = client_registry_factory().expect("failed to initialize client registry");
+
     let consumer = ArtifactPublisherConsumer {
-        artifact_service: artifact_service_factory(&db).expect("failed to initialize artifact service"),
+        artifact_service: artifact_service_factory(&db, Arc::new(webhook_notifier)).expect("failed to initialize artifact service"),
         publications_work_dir: PathBuf::from(&environment.shared_data_dir).join(ARTIFACT_PUBLICATION_DIR_NAME),
         // artifacts_cache_dir: PathBuf::from(&environment.artifacts_cache_dir)
+        clients: Arc::new(clients),
     };
      
     let args = BasicConsumeArguments::default()
@@ -5,6 +5,12 @@ pub enum ClientProviderError<'a> {
     #[error("Platform '{0}' not found or does not have '{1}' functionality")]
     NotFound(&'a str, &'a str),
 
+    #[error("Platform '{0}' is not registered")]
+    NotRegistered(&'a str),
+
     #[error("Could not parse platform name: {0}")]
-    ParseError(String)
+    ParseError(String),
+
+    #[error("Failed to initialize client: {0}")]
+    InitializationError(String)
 }
\ No newline at end of file
@@ -1,9 +1,9 @@
 mod provider;
 mod errors;
 mod platform;
-
-pub(crate) mod clients;
+mod registry;
 
 pub use provider::*;
 pub use errors::*;
 pub use platform::*;
+pub use registry::*;
@@ -17,11 +17,14 @@ pub enum Platform {
     Git,
     /// This variant corresponds to the Patra client
     #[strum(serialize="patra")]
-    Patra
+    Patra,
+    /// This variant corresponds to the artifacts stored in MLHub itself
+    #[strum(serialize="mlhub")]
+    Mlhub
 }
 
 impl Platform {
     pub fn list_all() -> Vec<Self> {
-        return vec![Self::HuggingFace, Self::Git, Self::Github, Self::Patra]
-    } 
+        return vec![Self::HuggingFace, Self::Git, Self::Github, Self::Patra, Self::Mlhub]
+    }
 }
\ No newline at end of file
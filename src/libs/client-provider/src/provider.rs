@@ -1,30 +1,35 @@
 use crate::errors::ClientProviderError;
-use crate::platform::Platform;
-use std::str::FromStr;
-use huggingface_client::client::HuggingFaceClient;
-use github_lfs_client::client::GithubLfsClient;
-use git_lfs_client::client::GitLfsClient;
-use patra_client::client::PatraClient;
-use crate::clients::{
-    ListModelsClient,
-    GetModelClient,
-    DiscoverModelsClient,
-    PublishModelClient,
-    IngestModelClient,
-    IngestDatasetClient,
-    PublishModelMetadataClient,
+use crate::registry::{
+    ClientRegistry,
+    Capabilities,
+    DynListModelsClient,
+    DynGetModelClient,
+    DynDiscoverModelsClient,
+    DynIngestModelClient,
+    DynPublishModelClient,
+    DynPublishModelMetadataClient,
+    DynListDatasetsClient,
+    DynGetDatasetClient,
+    DynIngestDatasetClient,
+    DynPublishDatasetClient,
 };
+use once_cell::sync::OnceCell;
+use std::sync::Arc;
+
+/// The registry used by the `ClientProvider`. Initialized from the environment
+/// the first time a client is provided
+static DEFAULT_REGISTRY: OnceCell<ClientRegistry> = OnceCell::new();
 
 /// A provider for managing clients mapped to their respective platforms.
 ///
-/// This struct maintains a registry of model and clients, allowing retrieval 
-/// of the appropriate client based on the specified `Platform`.
+/// This struct provides clients from a process-wide `ClientRegistry` built from
+/// the environment. Services that manage their own registry (ex. through their
+/// app state) should use the `ClientRegistry` directly instead.
 ///
 /// # Example
 ///
 /// ```rust
 /// use client_provider::ClientProvider;
-/// use client_provider::Platform;
 ///
 /// let client = ClientProvider::provide_list_models_client("huggingface");
 /// ```
@@ -34,68 +39,52 @@ pub struct ClientProvider {}
 
 /// Inherent implementation of ModelsClientProvider
 impl ClientProvider {
-    pub fn provide_list_models_client(platform_name: &str) -> Result<ListModelsClient, ClientProviderError> {
-        let platform = resolve_platform(platform_name)?;
-        match platform {
-            Platform::HuggingFace => Ok(ListModelsClient::HuggingFace(HuggingFaceClient::new())),
-            Platform::Patra => Ok(ListModelsClient::Patra(PatraClient::new())),
-            _ => Err(ClientProviderError::NotFound(platform_name, "listing"))
-        }
+    pub fn provide_list_models_client(platform_name: &str) -> Result<Arc<DynListModelsClient>, ClientProviderError> {
+        default_registry()?.list_models_client(platform_name)
+    }
+
+    pub fn provide_get_model_client(platform_name: &str) -> Result<Arc<DynGetModelClient>, ClientProviderError> {
+        default_registry()?.get_model_client(platform_name)
+    }
+
+    pub fn provide_discover_models_client(platform_name: &str) -> Result<Arc<DynDiscoverModelsClient>, ClientProviderError> {
+        default_registry()?.discover_models_client(platform_name)
     }
 
-    pub fn provide_get_model_client(platform_name: &str) -> Result<GetModelClient, ClientProviderError> {
-        let platform = resolve_platform(platform_name)?;
-        match platform {
-            Platform::HuggingFace => Ok(GetModelClient::HuggingFace(HuggingFaceClient::new())),
-            Platform::Patra => Ok(GetModelClient::Patra(PatraClient::new())),
-            _ => Err(ClientProviderError::NotFound(platform_name, "fetching"))
-        }
+    pub fn provide_ingest_model_client(platform_name: &str) -> Result<Arc<DynIngestModelClient>, ClientProviderError> {
+        default_registry()?.ingest_model_client(platform_name)
     }
 
-    pub fn provide_discover_models_client(platform_name: &str) -> Result<DiscoverModelsClient, ClientProviderError> {
-        let platform = resolve_platform(platform_name)?;
-        match platform {
-            Platform::Patra => Ok(DiscoverModelsClient::Patra(PatraClient::new())),
-            _ => Err(ClientProviderError::NotFound(platform_name, "discovery"))
-        }
+    pub fn provide_publish_model_client(platform_name: &str) -> Result<Arc<DynPublishModelClient>, ClientProviderError> {
+        default_registry()?.publish_model_client(platform_name)
     }
 
-    pub fn provide_ingest_model_client(platform_name: &str) -> Result<IngestModelClient, ClientProviderError> {
-        let platform = resolve_platform(platform_name)?;
-        match platform {
-            Platform::Git => Ok(IngestModelClient::Git(GitLfsClient::new())),
-            Platform::Github => Ok(IngestModelClient::Github(GithubLfsClient::new())),
-            Platform::HuggingFace => Ok(IngestModelClient::HuggingFace(HuggingFaceClient::new())),
-            _ => Err(ClientProviderError::NotFound(platform_name, "model ingesting"))
-        }
+    pub fn provide_publish_metadata_client(platform_name: &str) -> Result<Arc<DynPublishModelMetadataClient>, ClientProviderError> {
+        default_registry()?.publish_metadata_client(platform_name)
     }
 
-    pub fn provide_publish_model_client(platform_name: &str) -> Result<PublishModelClient, ClientProviderError> {
-        let platform = resolve_platform(platform_name)?;
-        match platform {
-            Platform::HuggingFace => Ok(PublishModelClient::HuggingFace(HuggingFaceClient::new())),
-            _ => Err(ClientProviderError::NotFound(platform_name, "model publishing"))
-        }
+    pub fn provide_list_datasets_client(platform_name: &str) -> Result<Arc<DynListDatasetsClient>, ClientProviderError> {
+        default_registry()?.list_datasets_client(platform_name)
     }
 
-    pub fn provide_publish_metadata_client(platform_name: &str) -> Result<PublishModelMetadataClient, ClientProviderError> {
-        let platform = resolve_platform(platform_name)?;
-        match platform {
-            Platform::Patra => Ok(PublishModelMetadataClient::Patra(PatraClient::new())),
-            _ => Err(ClientProviderError::NotFound(platform_name, "model publishing"))
-        }
+    pub fn provide_get_dataset_client(platform_name: &str) -> Result<Arc<DynGetDatasetClient>, ClientProviderError> {
+        default_registry()?.get_dataset_client(platform_name)
     }
 
-    pub fn provide_ingest_dataset_client(platform_name: &str) -> Result<IngestDatasetClient, ClientProviderError> {
-        let platform = resolve_platform(platform_name)?;
-        match platform {
-            _ => Err(ClientProviderError::NotFound(platform_name, "dataset ingesting"))
-        }
+    pub fn provide_ingest_dataset_client(platform_name: &str) -> Result<Arc<DynIngestDatasetClient>, ClientProviderError> {
+        default_registry()?.ingest_dataset_client(platform_name)
+    }
+
+    pub fn provide_publish_dataset_client(platform_name: &str) -> Result<Arc<DynPublishDatasetClient>, ClientProviderError> {
+        default_registry()?.publish_dataset_client(platform_name)
+    }
+
+    /// Returns the operations supported by the provided platform
+    pub fn provide_capabilities(platform_name: &str) -> Result<Capabilities, ClientProviderError> {
+        default_registry()?.capabilities(platform_name)
     }
 }
 
-fn resolve_platform(platform_name: &str) -> Result<Platform, ClientProviderError> {
-    Platform::from_str(platform_name)
-        .map_err(|err| ClientProviderError::ParseError(err.to_string()))
-        .map(|p| p)
-}
\ No newline at end of file
+fn default_registry() -> Result<&'static ClientRegistry, ClientProviderError<'static>> {
+    DEFAULT_REGISTRY.get_or_try_init(ClientRegistry::from_env)
+}
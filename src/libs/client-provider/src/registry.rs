@@ -0,0 +1,329 @@
+use crate::errors::ClientProviderError;
+use crate::platform::Platform;
+use clients::{
+    ClientConfig,
+    ListModelsClient,
+    GetModelClient,
+    DiscoverModelsClient,
+    PublishModelClient,
+    IngestModelClient,
+    IngestDatasetClient,
+    ListDatasetsClient,
+    GetDatasetClient,
+    PublishDatasetClient,
+    PublishModelMetadataClient,
+};
+use huggingface_client::client::HuggingFaceClient;
+use github_lfs_client::client::GithubLfsClient;
+use git_lfs_client::client::GitLfsClient;
+use patra_client::client::PatraClient;
+use mlhub_client::client::MlhubClient;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shared::logging::GlobalLogger;
+use shared::presentation::http::v1::responses::ModelSummary;
+use std::collections::HashMap;
+use std::env;
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub type DynListModelsClient = dyn ListModelsClient<Data = Vec<ModelSummary>, Metadata = Value>;
+pub type DynGetModelClient = dyn GetModelClient<Data = ModelSummary, Metadata = Value>;
+pub type DynDiscoverModelsClient = dyn DiscoverModelsClient<Data = Value, Metadata = Value>;
+pub type DynIngestModelClient = dyn IngestModelClient;
+pub type DynPublishModelClient = dyn PublishModelClient<Data = Value, Metadata = Value>;
+pub type DynPublishModelMetadataClient = dyn PublishModelMetadataClient;
+pub type DynListDatasetsClient = dyn ListDatasetsClient<Data = Value, Metadata = Value>;
+pub type DynGetDatasetClient = dyn GetDatasetClient<Data = Value, Metadata = Value>;
+pub type DynIngestDatasetClient = dyn IngestDatasetClient;
+pub type DynPublishDatasetClient = dyn PublishDatasetClient<Data = Value, Metadata = Value>;
+
+/// The clients registered for a platform. A platform has a capability if and
+/// only if a client is registered for it
+#[derive(Default, Clone)]
+pub struct PlatformClients {
+    pub list_models: Option<Arc<DynListModelsClient>>,
+    pub get_model: Option<Arc<DynGetModelClient>>,
+    pub discover_models: Option<Arc<DynDiscoverModelsClient>>,
+    pub ingest_model: Option<Arc<DynIngestModelClient>>,
+    pub publish_model: Option<Arc<DynPublishModelClient>>,
+    pub publish_model_metadata: Option<Arc<DynPublishModelMetadataClient>>,
+    pub list_datasets: Option<Arc<DynListDatasetsClient>>,
+    pub get_dataset: Option<Arc<DynGetDatasetClient>>,
+    pub ingest_dataset: Option<Arc<DynIngestDatasetClient>>,
+    pub publish_dataset: Option<Arc<DynPublishDatasetClient>>,
+}
+
+impl PlatformClients {
+    /// Builds the clients of a built-in platform using the provided config
+    pub fn builtin(platform: &Platform, config: &ClientConfig) -> Result<Self, String> {
+        match platform {
+            Platform::HuggingFace => {
+                let client = Arc::new(HuggingFaceClient::with_config(config).map_err(|err| err.to_string())?);
+                Ok(Self {
+                    list_models: Some(client.clone()),
+                    get_model: Some(client.clone()),
+                    ingest_model: Some(client.clone()),
+                    publish_model: Some(client.clone()),
+                    list_datasets: Some(client.clone()),
+                    get_dataset: Some(client.clone()),
+                    ingest_dataset: Some(client.clone()),
+                    publish_dataset: Some(client),
+                    ..Default::default()
+                })
+            },
+            Platform::Github => {
+                let client = Arc::new(GithubLfsClient::with_config(config));
+                Ok(Self {
+                    ingest_model: Some(client.clone()),
+                    ingest_dataset: Some(client),
+                    ..Default::default()
+                })
+            },
+            Platform::Git => {
+                let client = Arc::new(GitLfsClient::with_config(config));
+                Ok(Self {
+                    ingest_model: Some(client.clone()),
+                    ingest_dataset: Some(client),
+                    ..Default::default()
+                })
+            },
+            Platform::Patra => {
+                let client = Arc::new(PatraClient::with_config(config).map_err(|err| err.to_string())?);
+                Ok(Self {
+                    list_models: Some(client.clone()),
+                    get_model: Some(client.clone()),
+                    discover_models: Some(client.clone()),
+                    publish_model_metadata: Some(client),
+                    ..Default::default()
+                })
+            },
+            // The mlhub platform reads from the database of the service that
+            // registers it, so it can only be built from that service's repositories
+            Platform::Mlhub => Err(String::from("The mlhub platform must be registered with the repositories of the service. See PlatformClients::mlhub")),
+        }
+    }
+
+    /// Builds the clients of the mlhub platform from an existing client. Allows
+    /// services to back the platform with the repositories they already use
+    pub fn mlhub(client: Arc<MlhubClient>) -> Self {
+        Self {
+            list_models: Some(client.clone()),
+            get_model: Some(client.clone()),
+            discover_models: Some(client),
+            ..Default::default()
+        }
+    }
+}
+
+/// The operations a platform supports
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    pub list_models: bool,
+    pub get_model: bool,
+    pub discover_models: bool,
+    pub ingest_model: bool,
+    pub publish_model: bool,
+    pub publish_model_metadata: bool,
+    pub list_datasets: bool,
+    pub get_dataset: bool,
+    pub ingest_dataset: bool,
+    pub publish_dataset: bool,
+}
+
+impl From<&PlatformClients> for Capabilities {
+    fn from(value: &PlatformClients) -> Self {
+        Self {
+            list_models: value.list_models.is_some(),
+            get_model: value.get_model.is_some(),
+            discover_models: value.discover_models.is_some(),
+            ingest_model: value.ingest_model.is_some(),
+            publish_model: value.publish_model.is_some(),
+            publish_model_metadata: value.publish_model_metadata.is_some(),
+            list_datasets: value.list_datasets.is_some(),
+            get_dataset: value.get_dataset.is_some(),
+            ingest_dataset: value.ingest_dataset.is_some(),
+            publish_dataset: value.publish_dataset.is_some(),
+        }
+    }
+}
+
+/// A platform registered from the registry config file
+#[derive(Deserialize, Debug)]
+pub struct PlatformRegistration {
+    /// Name used for the "platform" path parameter
+    pub name: String,
+    /// The built-in platform whose clients are used (ex. huggingface)
+    pub platform: String,
+    #[serde(default)]
+    pub config: ClientConfig,
+}
+
+#[derive(Deserialize, Debug)]
+struct RegistryConfig {
+    platforms: Vec<PlatformRegistration>,
+}
+
+/// A runtime registry of the clients available for each platform. Platforms
+/// are registered by name so the same built-in platform can be registered more
+/// than once with different configs (ex. an on-prem Hugging Face mirror)
+#[derive(Default, Clone)]
+pub struct ClientRegistry {
+    platforms: HashMap<String, PlatformClients>,
+}
+
+impl ClientRegistry {
+    /// Env var containing the path to a json file of platforms to register in
+    /// addition to the built-in platforms
+    pub const CONFIG_ENV_VAR: &str = "CLIENT_REGISTRY_CONFIG";
+
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry containing every built-in remote platform with its
+    /// default config. Platforms that fail to initialize are not registered.
+    /// The mlhub platform is left to the services that have a database
+    pub fn with_builtin_platforms() -> Self {
+        let mut registry = Self::new();
+        for platform in Platform::list_all().into_iter().filter(|platform| *platform != Platform::Mlhub) {
+            if let Err(err) = registry.register_platform(&platform.to_string(), &platform, &ClientConfig::default()) {
+                GlobalLogger::warn(format!("Platform '{}' not registered: {}", platform, err).as_str());
+            }
+        }
+
+        registry
+    }
+
+    /// Creates a registry containing the built-in platforms along with the
+    /// platforms in the file referenced by the CLIENT_REGISTRY_CONFIG env var.
+    /// Platforms in the file replace built-in platforms with the same name
+    pub fn from_env() -> Result<Self, ClientProviderError<'static>> {
+        let mut registry = Self::with_builtin_platforms();
+
+        let path = match env::var(Self::CONFIG_ENV_VAR) {
+            Ok(p) => p,
+            Err(_) => return Ok(registry)
+        };
+
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|err| ClientProviderError::InitializationError(format!("Failed to read registry config '{}': {}", &path, err)))?;
+
+        registry.register_all(&contents)?;
+
+        Ok(registry)
+    }
+
+    /// Registers every platform in a json registry config
+    pub fn register_all(&mut self, config: &str) -> Result<&mut Self, ClientProviderError<'static>> {
+        let config: RegistryConfig = serde_json::from_str(config)
+            .map_err(|err| ClientProviderError::InitializationError(format!("Invalid registry config: {}", err)))?;
+
+        for registration in config.platforms {
+            let platform = Platform::from_str(&registration.platform)
+                .map_err(|err| ClientProviderError::ParseError(format!("{}: '{}'", err, &registration.platform)))?;
+
+            self.register_platform(&registration.name, &platform, &registration.config)
+                .map_err(|err| ClientProviderError::InitializationError(err))?;
+        }
+
+        Ok(self)
+    }
+
+    /// Registers clients under a platform name, replacing any clients already
+    /// registered under that name
+    pub fn register(&mut self, name: &str, clients: PlatformClients) -> &mut Self {
+        self.platforms.insert(name.to_string(), clients);
+        self
+    }
+
+    /// Registers the clients of a built-in platform under a platform name
+    pub fn register_platform(&mut self, name: &str, platform: &Platform, config: &ClientConfig) -> Result<&mut Self, String> {
+        let clients = PlatformClients::builtin(platform, config)?;
+        Ok(self.register(name, clients))
+    }
+
+    /// Names of the registered platforms in alphabetical order
+    pub fn platforms(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.platforms.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn capabilities<'a>(&self, name: &'a str) -> Result<Capabilities, ClientProviderError<'a>> {
+        Ok(Capabilities::from(self.platform(name)?))
+    }
+
+    pub fn list_models_client<'a>(&self, name: &'a str) -> Result<Arc<DynListModelsClient>, ClientProviderError<'a>> {
+        self.platform(name)?.list_models.clone()
+            .ok_or(ClientProviderError::NotFound(name, "listing"))
+    }
+
+    /// The list models client of every platform that supports listing, keyed
+    /// by platform name in alphabetical order
+    pub fn list_models_clients(&self) -> Vec<(String, Arc<DynListModelsClient>)> {
+        self.platforms()
+            .into_iter()
+            .filter_map(|name| {
+                let client = self.platforms.get(&name)?.list_models.clone()?;
+                Some((name, client))
+            })
+            .collect()
+    }
+
+    pub fn get_model_client<'a>(&self, name: &'a str) -> Result<Arc<DynGetModelClient>, ClientProviderError<'a>> {
+        self.platform(name)?.get_model.clone()
+            .ok_or(ClientProviderError::NotFound(name, "fetching"))
+    }
+
+    pub fn discover_models_client<'a>(&self, name: &'a str) -> Result<Arc<DynDiscoverModelsClient>, ClientProviderError<'a>> {
+        self.platform(name)?.discover_models.clone()
+            .ok_or(ClientProviderError::NotFound(name, "discovery"))
+    }
+
+    pub fn ingest_model_client<'a>(&self, name: &'a str) -> Result<Arc<DynIngestModelClient>, ClientProviderError<'a>> {
+        self.platform(name)?.ingest_model.clone()
+            .ok_or(ClientProviderError::NotFound(name, "model ingesting"))
+    }
+
+    pub fn publish_model_client<'a>(&self, name: &'a str) -> Result<Arc<DynPublishModelClient>, ClientProviderError<'a>> {
+        self.platform(name)?.publish_model.clone()
+            .ok_or(ClientProviderError::NotFound(name, "model publishing"))
+    }
+
+    pub fn publish_metadata_client<'a>(&self, name: &'a str) -> Result<Arc<DynPublishModelMetadataClient>, ClientProviderError<'a>> {
+        self.platform(name)?.publish_model_metadata.clone()
+            .ok_or(ClientProviderError::NotFound(name, "metadata publishing"))
+    }
+
+    pub fn list_datasets_client<'a>(&self, name: &'a str) -> Result<Arc<DynListDatasetsClient>, ClientProviderError<'a>> {
+        self.platform(name)?.list_datasets.clone()
+            .ok_or(ClientProviderError::NotFound(name, "dataset listing"))
+    }
+
+    pub fn get_dataset_client<'a>(&self, name: &'a str) -> Result<Arc<DynGetDatasetClient>, ClientProviderError<'a>> {
+        self.platform(name)?.get_dataset.clone()
+            .ok_or(ClientProviderError::NotFound(name, "dataset fetching"))
+    }
+
+    pub fn ingest_dataset_client<'a>(&self, name: &'a str) -> Result<Arc<DynIngestDatasetClient>, ClientProviderError<'a>> {
+        self.platform(name)?.ingest_dataset.clone()
+            .ok_or(ClientProviderError::NotFound(name, "dataset ingesting"))
+    }
+
+    pub fn publish_dataset_client<'a>(&self, name: &'a str) -> Result<Arc<DynPublishDatasetClient>, ClientProviderError<'a>> {
+        self.platform(name)?.publish_dataset.clone()
+            .ok_or(ClientProviderError::NotFound(name, "dataset publishing"))
+    }
+
+    fn platform<'a>(&self, name: &'a str) -> Result<&PlatformClients, ClientProviderError<'a>> {
+        self.platforms.get(name)
+            .ok_or(ClientProviderError::NotRegistered(name))
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "registry.test.rs"]
+mod registry_test;
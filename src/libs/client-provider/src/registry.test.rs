@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod registry_test {
+    use std::sync::Arc;
+    use clients::{ClientConfig, ClientError, ClientJsonResponse, ListModelsClient};
+    use serde_json::Value;
+    use shared::presentation::http::v1::dto::models::ListModelsRequest;
+    use shared::presentation::http::v1::responses::ModelSummary;
+    use crate::errors::ClientProviderError;
+    use crate::platform::Platform;
+    use crate::registry::{ClientRegistry, PlatformClients};
+
+    struct MockListModelsClient;
+
+    #[async_trait::async_trait]
+    impl ListModelsClient for MockListModelsClient {
+        type Data = Vec<ModelSummary>;
+        type Metadata = Value;
+
+        async fn list_models(&self, _request: &ListModelsRequest) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+            Ok(ClientJsonResponse::new(Some(200), None, Some(Vec::new()), None))
+        }
+    }
+
+    #[test]
+    fn test_capabilities_match_registered_clients() {
+        let mut registry = ClientRegistry::new();
+        registry.register_platform("patra", &Platform::Patra, &ClientConfig::default()).unwrap();
+        registry.register_platform("git", &Platform::Git, &ClientConfig::default()).unwrap();
+
+        let patra = registry.capabilities("patra").unwrap();
+        assert!(patra.list_models);
+        assert!(patra.publish_model_metadata);
+        assert!(!patra.ingest_model);
+
+        let git = registry.capabilities("git").unwrap();
+        assert!(git.ingest_model);
+        assert!(git.ingest_dataset);
+        assert!(!git.list_models);
+
+        assert!(matches!(
+            registry.list_models_client("git"),
+            Err(ClientProviderError::NotFound("git", _))
+        ));
+        assert!(matches!(
+            registry.ingest_model_client("patra"),
+            Err(ClientProviderError::NotFound("patra", _))
+        ));
+    }
+
+    #[test]
+    fn test_unregistered_platform() {
+        let registry = ClientRegistry::new();
+
+        assert!(matches!(
+            registry.capabilities("unknown"),
+            Err(ClientProviderError::NotRegistered("unknown"))
+        ));
+    }
+
+    #[test]
+    fn test_register_mock_client() {
+        let mut registry = ClientRegistry::new();
+        registry.register("mock", PlatformClients {
+            list_models: Some(Arc::new(MockListModelsClient)),
+            ..Default::default()
+        });
+
+        assert_eq!(registry.platforms(), vec![String::from("mock")]);
+        assert!(registry.capabilities("mock").unwrap().list_models);
+        assert!(registry.list_models_client("mock").is_ok());
+    }
+
+    #[test]
+    fn test_register_all_from_config() {
+        let mut registry = ClientRegistry::new();
+        registry.register_all(r#"{
+            "platforms": [
+                {
+                    "name": "hf-mirror",
+                    "platform": "huggingface",
+                    "config": { "base_url": "https://hf.example.org", "timeout_secs": 30 }
+                },
+                { "name": "onprem-git", "platform": "git" }
+            ]
+        }"#).unwrap();
+
+        assert_eq!(registry.platforms(), vec![String::from("hf-mirror"), String::from("onprem-git")]);
+        assert!(registry.capabilities("hf-mirror").unwrap().list_models);
+        assert!(registry.capabilities("hf-mirror").unwrap().publish_dataset);
+        assert!(!registry.capabilities("onprem-git").unwrap().publish_dataset);
+        assert!(registry.capabilities("onprem-git").unwrap().ingest_model);
+    }
+
+    #[test]
+    fn test_register_all_unknown_platform() {
+        let mut registry = ClientRegistry::new();
+        let result = registry.register_all(r#"{ "platforms": [{ "name": "x", "platform": "unknown" }] }"#);
+
+        assert!(matches!(result, Err(ClientProviderError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_list_models_clients() {
+        let mut registry = ClientRegistry::new();
+        registry.register_platform("git", &Platform::Git, &ClientConfig::default()).unwrap();
+        registry.register("mock", PlatformClients {
+            list_models: Some(Arc::new(MockListModelsClient)),
+            ..Default::default()
+        });
+        registry.register_platform("hf-mirror", &Platform::HuggingFace, &ClientConfig::default()).unwrap();
+
+        let names: Vec<String> = registry.list_models_clients()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        // Platforms that cannot list models are left out
+        assert_eq!(names, vec!["hf-mirror", "mock"]);
+    }
+
+    #[test]
+    fn test_builtin_platforms_leave_out_mlhub() {
+        let registry = ClientRegistry::with_builtin_platforms();
+
+        assert_eq!(registry.platforms(), vec!["git", "github", "huggingface", "patra"]);
+        assert!(PlatformClients::builtin(&Platform::Mlhub, &ClientConfig::default()).is_err());
+    }
+}
@@ -11,7 +11,8 @@ use async_trait;
 // clients needs to implement should come from this module. Removing the 'pub'
 // keyword below will break this modules api for consumers
 pub use crate::errors::ClientError;
-pub use crate::responses::ClientJsonResponse;
+pub use crate::responses::{ClientJsonResponse, IngestionResult, PublicationResult};
+pub use crate::config::ClientConfig;
 
 #[async_trait::async_trait]
 pub trait ListModelsClient: Send + Sync {
@@ -35,7 +36,7 @@ pub trait GetModelClient: Send + Sync {
 
 #[async_trait::async_trait]
 pub trait IngestModelClient: Send + Sync {
-    async fn ingest_model(&self, _request: &models::IngestModelRequest, _ingest_path: PathBuf) -> Result<(), ClientError> {
+    async fn ingest_model(&self, _request: &models::IngestModelRequest, _ingest_path: PathBuf) -> Result<IngestionResult, ClientError> {
         return Err(ClientError::Unimplemented);
     }
 }
@@ -68,10 +69,7 @@ pub trait PublishModelClient: Send + Sync {
 
 #[async_trait::async_trait]
 pub trait PublishModelMetadataClient: Send + Sync {
-    type Data: Serialize;
-    type Metadata: Serialize;
-
-    async fn publish_model_metadata(&self, _metadata: &entities::model_metadata::ModelMetadata, _request: &artifacts::PublishArtifactRequest) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+    async fn publish_model_metadata(&self, _metadata: &entities::model_metadata::ModelMetadata, _request: &artifacts::PublishArtifactRequest) -> Result<PublicationResult, ClientError> {
         return Err(ClientError::Unimplemented);
     }
 }
@@ -98,7 +96,7 @@ pub trait GetDatasetClient: Send + Sync {
 
 #[async_trait::async_trait]
 pub trait IngestDatasetClient: Send + Sync {
-    async fn ingest_dataset(&self, _request: &datasets::IngestDatasetRequest,  _ingest_path: PathBuf) -> Result<(), ClientError> {
+    async fn ingest_dataset(&self, _request: &datasets::IngestDatasetRequest,  _ingest_path: PathBuf) -> Result<IngestionResult, ClientError> {
         return Err(ClientError::Unimplemented);
     }
 }
@@ -108,7 +106,13 @@ pub trait PublishDatasetClient: Send + Sync {
     type Data: Serialize;
     type Metadata: Serialize;
 
-    async fn publish_dataset(&self, _request: &datasets::PublishDatasetRequest) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+    async fn publish_dataset(
+        &self,
+        _extracted_artifact_path: &PathBuf,
+        _artifact: &entities::artifact::Artifact,
+        _metadata: &entities::dataset_metadata::DatasetMetadata,
+        _request: &artifacts::PublishArtifactRequest
+    ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
         return Err(ClientError::Unimplemented);
     }
 }
@@ -0,0 +1,39 @@
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+use crate::errors::{ClientError, ClientErrorScope};
+
+/// Configuration used to construct a client. Every field is optional and
+/// clients fall back to their defaults for anything that is not set
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ClientConfig {
+    /// Base url of the remote platform. Allows pointing a client at a mirror
+    /// or on-prem deployment of the platform
+    pub base_url: Option<String>,
+    /// Timeout in seconds for requests made to the remote platform
+    pub timeout_secs: Option<u64>,
+    /// Access token used when a request does not provide its own credentials.
+    /// Clients decide which operations may fall back to it. The Hugging Face
+    /// client only uses it to list public models and datasets
+    pub access_token: Option<String>,
+    /// Names of the SSH deploy keys, mounted in the directory set by
+    /// GIT_SSH_KEYS_DIR, that requests may authenticate with. Keys are only
+    /// used with the configured base url
+    pub ssh_key_secrets: Option<Vec<String>>,
+}
+
+impl ClientConfig {
+    /// Builds the http client used to call the remote platform
+    pub fn http_client(&self) -> Result<Client, ClientError> {
+        let mut builder = Client::builder();
+        if let Some(timeout) = self.timeout_secs {
+            builder = builder.timeout(Duration::from_secs(timeout));
+        }
+
+        builder.build()
+            .map_err(|err| ClientError::Internal {
+                msg: format!("Failed to build http client: {}", err),
+                scope: ClientErrorScope::Client,
+            })
+    }
+}
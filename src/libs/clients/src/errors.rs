@@ -1,5 +1,7 @@
+use serde_json::{from_str, Value};
 use thiserror::Error;
 use shared::infra::fs::git::GitError;
+use shared::presentation::http::v1::dto::headers::AuthorizationHeaderError;
 
 #[derive(Debug)]
 pub enum ClientErrorScope {
@@ -39,6 +41,16 @@ pub enum ClientError {
         scope: ClientErrorScope
     },
 
+    // The target service is rejecting requests because too many have been made.
+    // Carries the value of the Retry-After header (seconds or an http date) so
+    // it can be passed on to the caller
+    #[error("{scope:?} error (Rate Limited): {msg}")]
+    RateLimited {
+        msg: String,
+        retry_after: Option<String>,
+        scope: ClientErrorScope
+    },
+
     #[error("Unimplemented")]
     Unimplemented,
 
@@ -59,23 +71,58 @@ impl ClientError {
             ClientError::Unauthorized { .. } => 401,
             ClientError::Forbidden { .. } => 403,
             ClientError::NotFound { .. } => 404,
+            ClientError::RateLimited { .. } => 429,
             ClientError::Internal { .. } => 500,
             ClientError::Unavailable(_) => 503,
             ClientError::Unimplemented => 500,
             ClientError::MissingInvalidCredentials(_) => 400,
         }
     }
+
+    /// Maps an unsuccessful http status returned by a target service onto the
+    /// matching error. Errors are scoped to the server since the request was
+    /// rejected upstream
+    pub fn from_upstream_status(status: u16, msg: String, retry_after: Option<String>) -> Self {
+        let scope = ClientErrorScope::Server;
+        match status {
+            401 => ClientError::Unauthorized { msg, scope },
+            403 => ClientError::Forbidden { msg, scope },
+            404 => ClientError::NotFound { msg, scope },
+            429 => ClientError::RateLimited { msg, retry_after, scope },
+            503 => ClientError::Unavailable(msg),
+            400..=499 => ClientError::BadRequest { msg, scope },
+            _ => ClientError::Internal { msg, scope },
+        }
+    }
+}
+
+/// The error message of a json error body returned by a target service.
+/// Ex. {"error": "Repository not found"}
+pub fn error_message_from_body(body: &str) -> Option<String> {
+    let value: Value = from_str(body.trim()).ok()?;
+    ["error", "message", "detail"].iter()
+        .find_map(|key| value.get(*key).and_then(|message| message.as_str()))
+        .map(String::from)
 }
 
 impl From<GitError> for ClientError {
     fn from(value: GitError) -> Self {
         match value {
             GitError::SystemError(err) => ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client },
+            GitError::Auth(msg) => ClientError::MissingInvalidCredentials(msg),
             err => ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server },
         }
     }
 }
 
+/// A malformed authorization header is a problem with the credentials the
+/// caller provided rather than an internal error
+impl From<AuthorizationHeaderError> for ClientError {
+    fn from(value: AuthorizationHeaderError) -> Self {
+        ClientError::MissingInvalidCredentials(format!("Invalid Authorization header: {}", value))
+    }
+}
+
 impl From<&u16> for ClientError {
     fn from(value: &u16) -> Self {
         match value {
@@ -83,9 +130,15 @@ impl From<&u16> for ClientError {
             401 => ClientError::Unauthorized { msg: value.to_string(), scope: ClientErrorScope::Server },
             403 => ClientError::Forbidden { msg: value.to_string(), scope: ClientErrorScope::Server },
             404 => ClientError::NotFound { msg: value.to_string(), scope: ClientErrorScope::Server },
+            429 => ClientError::RateLimited { msg: value.to_string(), retry_after: None, scope: ClientErrorScope::Server },
             500 => ClientError::Internal { msg: value.to_string(), scope: ClientErrorScope::Server },
             503 => ClientError::Unavailable(value.to_string()),
             _ => ClientError::Internal { msg: format!("Invalid http status used in client: {}", value), scope: ClientErrorScope::Client }
         }
     }
-}
\ No newline at end of file
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "errors.test.rs"]
+mod errors_test;
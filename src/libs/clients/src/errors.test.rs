@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod errors_test {
+    use crate::errors::{error_message_from_body, ClientError, ClientErrorScope};
+
+    #[test]
+    fn test_from_upstream_status() {
+        let error = |status: u16| ClientError::from_upstream_status(status, String::from("upstream"), None);
+
+        assert!(matches!(error(401), ClientError::Unauthorized { scope: ClientErrorScope::Server, .. }));
+        assert!(matches!(error(403), ClientError::Forbidden { scope: ClientErrorScope::Server, .. }));
+        assert!(matches!(error(404), ClientError::NotFound { scope: ClientErrorScope::Server, .. }));
+        assert!(matches!(error(422), ClientError::BadRequest { scope: ClientErrorScope::Server, .. }));
+        assert!(matches!(error(502), ClientError::Internal { scope: ClientErrorScope::Server, .. }));
+        assert!(matches!(error(503), ClientError::Unavailable(_)));
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        let error = ClientError::from_upstream_status(429, String::from("slow down"), Some(String::from("30")));
+
+        assert_eq!(error.status_code(), 429);
+        assert!(matches!(
+            error,
+            ClientError::RateLimited { retry_after: Some(ref retry_after), scope: ClientErrorScope::Server, .. } if retry_after == "30"
+        ));
+    }
+
+    #[test]
+    fn test_error_message_from_body() {
+        assert_eq!(error_message_from_body(r#"{"error": "Repository not found"}"#), Some(String::from("Repository not found")));
+        assert_eq!(error_message_from_body(r#"{"detail": "Not authenticated"}"#), Some(String::from("Not authenticated")));
+        assert_eq!(error_message_from_body("<html>Bad Gateway</html>"), None);
+    }
+}
@@ -1,6 +1,7 @@
 pub mod responses;
 mod errors;
 mod clients;
+mod config;
 
 pub use errors::*;
 pub use clients::*;
\ No newline at end of file
@@ -2,6 +2,7 @@
 // clients needs to implement should come from this module. Removing the 'pub'
 // keyword below will break this modules api for consumers
 pub use crate::errors::ClientError;
+use std::collections::HashMap;
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -21,4 +22,32 @@ impl <Data: Serialize, Metadata: Serialize>ClientJsonResponse<Data, Metadata> {
             metadata
         }
     }
-}
\ No newline at end of file
+}
+
+/// The outcome of ingesting an artifact from a platform
+#[derive(Debug, Default, Clone)]
+pub struct IngestionResult {
+    /// The commit sha the requested revision resolved to, if the platform
+    /// versions its artifacts
+    pub resolved_revision: Option<String>,
+}
+
+impl IngestionResult {
+    pub fn new(resolved_revision: Option<String>) -> Self {
+        Self { resolved_revision }
+    }
+}
+
+/// The outcome of publishing an artifact or its metadata to a platform
+#[derive(Debug, Default, Clone)]
+pub struct PublicationResult {
+    /// Ids the platform assigned to what was published, keyed by the kind of
+    /// id. Ex. {"model_card_id": "1234"}
+    pub published_ids: HashMap<String, String>,
+}
+
+impl PublicationResult {
+    pub fn new(published_ids: HashMap<String, String>) -> Self {
+        Self { published_ids }
+    }
+}
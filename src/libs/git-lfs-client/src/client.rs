@@ -1,9 +1,10 @@
 use async_trait;
-use clients::{ClientError, ClientErrorScope, IngestDatasetClient, IngestModelClient};
+use clients::{ClientConfig, ClientError, ClientErrorScope, IngestDatasetClient, IngestModelClient, IngestionResult};
 use shared::infra::fs::git::{
-    SyncGitRepository, SyncGitRepositoryImpl, SyncLfsRepositoryParams,
+    GitAuth, SyncGitRepository, SyncGitRepositoryImpl, SyncLfsRepositoryParams,
 };
 use shared::presentation::http::v1::actix_web::helpers::param_to_string;
+use shared::presentation::http::v1::dto::Parameters;
 use shared::presentation::http::v1::dto::datasets::IngestDatasetRequest;
 use shared::logging::SharedLogger;
 use shared::presentation::http::v1::dto::models::IngestModelRequest;
@@ -11,6 +12,9 @@ use std::path::PathBuf;
 
 #[derive(Debug)]
 pub struct GitLfsClient {
+    base_url: Option<String>,
+    access_token: Option<String>,
+    ssh_key_secrets: Vec<String>,
     _logger: SharedLogger,
 }
 
@@ -22,16 +26,23 @@ impl IngestModelClient for GitLfsClient {
         &self,
         request: &IngestModelRequest,
         target_path: PathBuf,
-    ) -> Result<(), ClientError> {
-        // Get the authorization token from the request
-        let access_token = request.headers.get_first_value("Authorization");
+    ) -> Result<IngestionResult, ClientError> {
+        // Get the credentials git authenticates with from the request
+        let auth = GitAuth::from_request(
+            &request.headers,
+            request.body.params.as_ref(),
+            self.configured_token(request.body.params.as_ref()),
+            self.configured_ssh_keys(request.body.params.as_ref()),
+        )?;
 
-        // Get the remote base url from the request
+        // Get the remote base url from the request. Falls back to the base url
+        // the client was configured with
         let remote_base_url = param_to_string(request.body.params.clone(), "remote_base_url")
             .map_err(|err| ClientError::BadRequest {
                 msg: err.to_string(),
                 scope: ClientErrorScope::Client,
             })?
+            .or(self.base_url.clone())
             .ok_or(ClientError::BadRequest {
                 msg: "Parameter 'remote_base_url' missing from the request".into(),
                 scope: ClientErrorScope::Client,
@@ -47,17 +58,18 @@ impl IngestModelClient for GitLfsClient {
             }
         })?;
 
-        self.sync_lfs_repo(SyncLfsRepositoryParams {
+        let repo = self.sync_lfs_repo(SyncLfsRepositoryParams {
             name: request.path.model_id.clone(),
             remote_base_url,
             target_dir: target_path.to_string_lossy().to_string(),
-            branch,
-            access_token: access_token.clone(),
+            // A revision in the request body takes precedence over the branch param
+            revision: request.body.revision.clone().or(branch),
+            auth,
             include_paths: request.body.include_paths.clone(),
             exclude_paths: request.body.exclude_paths.clone(),
         })?;
 
-        Ok(())
+        Ok(IngestionResult::new(Some(repo.repo.head_revision()?)))
     }
 }
 
@@ -67,16 +79,23 @@ impl IngestDatasetClient for GitLfsClient {
         &self,
         request: &IngestDatasetRequest,
         target_path: PathBuf,
-    ) -> Result<(), ClientError> {
-        // Get the authorization token from the request
-        let access_token = request.headers.get_first_value("Authorization");
+    ) -> Result<IngestionResult, ClientError> {
+        // Get the credentials git authenticates with from the request
+        let auth = GitAuth::from_request(
+            &request.headers,
+            request.body.params.as_ref(),
+            self.configured_token(request.body.params.as_ref()),
+            self.configured_ssh_keys(request.body.params.as_ref()),
+        )?;
 
-        // Get the remote base url from the request
+        // Get the remote base url from the request. Falls back to the base url
+        // the client was configured with
         let remote_base_url = param_to_string(request.body.params.clone(), "remote_base_url")
             .map_err(|err| ClientError::BadRequest {
                 msg: err.to_string(),
                 scope: ClientErrorScope::Client,
             })?
+            .or(self.base_url.clone())
             .ok_or(ClientError::BadRequest {
                 msg: "Parameter 'remote_base_url' missing from the request".into(),
                 scope: ClientErrorScope::Client,
@@ -92,24 +111,53 @@ impl IngestDatasetClient for GitLfsClient {
             }
         })?;
 
-        self.sync_lfs_repo(SyncLfsRepositoryParams {
+        let repo = self.sync_lfs_repo(SyncLfsRepositoryParams {
             name: request.path.dataset_id.clone(),
             remote_base_url,
             target_dir: target_path.to_string_lossy().to_string(),
-            branch,
-            access_token: access_token.clone(),
+            // A revision in the request body takes precedence over the branch param
+            revision: request.body.revision.clone().or(branch),
+            auth,
             include_paths: request.body.include_paths.clone(),
             exclude_paths: request.body.exclude_paths.clone(),
         })?;
 
-        Ok(())
+        Ok(IngestionResult::new(Some(repo.repo.head_revision()?)))
     }
 }
 
 impl GitLfsClient {
     pub fn new() -> Self {
+        Self::with_config(&ClientConfig::default())
+    }
+
+    pub fn with_config(config: &ClientConfig) -> Self {
         Self {
+            base_url: config.base_url.clone(),
+            access_token: config.access_token.clone(),
+            ssh_key_secrets: config.ssh_key_secrets.clone().unwrap_or_default(),
             _logger: SharedLogger::new(),
         }
     }
+
+    /// The token the client was configured with. It is only ever sent to the
+    /// configured host, so a request that sets its own remote must bring its
+    /// own credentials
+    fn configured_token(&self, params: Option<&Parameters>) -> Option<String> {
+        self.access_token.clone()
+            .filter(|_| Self::uses_configured_remote(params))
+    }
+
+    /// The SSH deploy keys the client was configured with. Like the token,
+    /// they are only used with the configured host
+    fn configured_ssh_keys(&self, params: Option<&Parameters>) -> &[String] {
+        match Self::uses_configured_remote(params) {
+            true => &self.ssh_key_secrets,
+            false => &[],
+        }
+    }
+
+    fn uses_configured_remote(params: Option<&Parameters>) -> bool {
+        params.and_then(|params| params.get("remote_base_url")).is_none()
+    }
 }
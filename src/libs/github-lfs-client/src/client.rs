@@ -1,7 +1,7 @@
 use async_trait;
-use clients::{ClientError, ClientErrorScope, IngestDatasetClient, IngestModelClient};
+use clients::{ClientConfig, ClientError, ClientErrorScope, IngestDatasetClient, IngestModelClient, IngestionResult};
 use shared::infra::fs::git::{
-    SyncGitRepository, SyncGitRepositoryImpl, SyncLfsRepositoryParams,
+    GitAuth, SyncGitRepository, SyncGitRepositoryImpl, SyncLfsRepositoryParams,
 };
 use shared::presentation::http::v1::actix_web::helpers::param_to_string;
 use shared::presentation::http::v1::dto::datasets::IngestDatasetRequest;
@@ -11,6 +11,9 @@ use std::path::PathBuf;
 
 #[derive(Debug)]
 pub struct GithubLfsClient {
+    base_url: String,
+    access_token: Option<String>,
+    ssh_key_secrets: Vec<String>,
     _logger: SharedLogger,
 }
 
@@ -22,9 +25,9 @@ impl IngestModelClient for GithubLfsClient {
         &self,
         request: &IngestModelRequest,
         target_path: PathBuf,
-    ) -> Result<(), ClientError> {
-        // Get the authorization token from the request
-        let access_token = request.headers.get_first_value("Authorization");
+    ) -> Result<IngestionResult, ClientError> {
+        // Get the credentials git authenticates with from the request
+        let auth = GitAuth::from_request(&request.headers, request.body.params.as_ref(), self.access_token.clone(), &self.ssh_key_secrets)?;
 
         // Get the branch from the request
         let branch = param_to_string(request.body.params.clone(), "branch").map_err(|_| {
@@ -34,17 +37,18 @@ impl IngestModelClient for GithubLfsClient {
             }
         })?;
 
-        self.sync_lfs_repo(SyncLfsRepositoryParams {
+        let repo = self.sync_lfs_repo(SyncLfsRepositoryParams {
             name: request.path.model_id.clone(),
-            remote_base_url: String::from("https://github.com"),
+            remote_base_url: self.remote_base_url(&auth),
             target_dir: target_path.to_string_lossy().to_string(),
-            branch,
-            access_token: access_token.clone(),
+            // A revision in the request body takes precedence over the branch param
+            revision: request.body.revision.clone().or(branch),
+            auth,
             include_paths: request.body.include_paths.clone(),
             exclude_paths: request.body.exclude_paths.clone(),
         })?;
 
-        Ok(())
+        Ok(IngestionResult::new(Some(repo.repo.head_revision()?)))
     }
 }
 
@@ -54,9 +58,9 @@ impl IngestDatasetClient for GithubLfsClient {
         &self,
         request: &IngestDatasetRequest,
         target_path: PathBuf,
-    ) -> Result<(), ClientError> {
-        // Get the authorization token from the request
-        let access_token = request.headers.get_first_value("Authorization");
+    ) -> Result<IngestionResult, ClientError> {
+        // Get the credentials git authenticates with from the request
+        let auth = GitAuth::from_request(&request.headers, request.body.params.as_ref(), self.access_token.clone(), &self.ssh_key_secrets)?;
 
         // Get the branch from the request
         let branch = param_to_string(request.body.params.clone(), "branch").map_err(|_| {
@@ -66,24 +70,46 @@ impl IngestDatasetClient for GithubLfsClient {
             }
         })?;
 
-        self.sync_lfs_repo(SyncLfsRepositoryParams {
+        let repo = self.sync_lfs_repo(SyncLfsRepositoryParams {
             name: request.path.dataset_id.clone(),
-            remote_base_url: String::from("https://github.com"),
+            remote_base_url: self.remote_base_url(&auth),
             target_dir: target_path.to_string_lossy().to_string(),
-            branch,
-            access_token: access_token.clone(),
+            // A revision in the request body takes precedence over the branch param
+            revision: request.body.revision.clone().or(branch),
+            auth,
             include_paths: request.body.include_paths.clone(),
             exclude_paths: request.body.exclude_paths.clone(),
         })?;
 
-        Ok(())
+        Ok(IngestionResult::new(Some(repo.repo.head_revision()?)))
     }
 }
 
 impl GithubLfsClient {
+    const BASE_URL: &str = "https://github.com";
+
     pub fn new() -> Self {
+        Self::with_config(&ClientConfig::default())
+    }
+
+    pub fn with_config(config: &ClientConfig) -> Self {
         Self {
+            base_url: config.base_url.clone()
+                .unwrap_or(String::from(Self::BASE_URL))
+                .trim_end_matches("/")
+                .to_string(),
+            access_token: config.access_token.clone(),
+            ssh_key_secrets: config.ssh_key_secrets.clone().unwrap_or_default(),
             _logger: SharedLogger::new(),
         }
     }
+
+    /// Deploy keys only authenticate ssh remotes, so repositories are cloned
+    /// over ssh rather than https when authenticating with one
+    fn remote_base_url(&self, auth: &GitAuth) -> String {
+        match (auth, self.base_url.strip_prefix("https://")) {
+            (GitAuth::SshKey { .. }, Some(host)) => format!("ssh://git@{}", host),
+            _ => self.base_url.clone(),
+        }
+    }
 }
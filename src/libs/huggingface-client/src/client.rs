@@ -1,66 +1,69 @@
 use crate::constants;
+use crate::dataset_card::build_dataset_card;
+use crate::download::{DownloadMethod, DownloadRepoParams, HubDownloader, RepoType};
+use crate::model_card::build_model_card;
+use crate::upload::{tag_exists_error, HubUploader, UploadFolderParams};
 use crate::requests::{ListDatasetsQueryParameters, ListModelsQueryParameters};
-use crate::utils::deserialize_response_body;
+use crate::responses::{DatasetInfo, ModelInfo};
+use crate::utils::{
+    build_pagination_metadata, deserialize_response_body, gated_repo_error, parse_bool_param,
+    parse_next_cursor, response_error,
+};
 use async_trait;
 use clients::{
-    ClientError, ClientErrorScope, ClientJsonResponse, GetDatasetClient,
-    GetModelClient, IngestDatasetClient, IngestModelClient, ListDatasetsClient,
-    ListModelsClient, PublishDatasetClient, PublishModelClient, PublishModelMetadataClient
+    ClientConfig, ClientError, ClientErrorScope, ClientJsonResponse, GetDatasetClient,
+    GetModelClient, IngestDatasetClient, IngestModelClient, IngestionResult, ListDatasetsClient,
+    ListModelsClient, PublicationResult, PublishDatasetClient, PublishModelClient, PublishModelMetadataClient
 };
-use reqwest::header::{HeaderMap, HeaderValue, HeaderName};
-use reqwest::{Client as ReqwestClient, StatusCode};
-use serde_json::{Map, Value};
+use reqwest::{Client as ReqwestClient, RequestBuilder, StatusCode};
+use serde_json::{json, to_value, Map, Value};
 use shared::infra::fs::git::{
-    SyncGitRepository, SyncGitRepositoryImpl, SyncLfsRepositoryParams,
+    GitAuth, SyncGitRepository, SyncGitRepositoryImpl, SyncLfsRepositoryParams,
 };
 use shared::presentation::http::v1::actix_web::helpers::param_to_string;
 use shared::presentation::http::v1::dto::artifacts::PublishArtifactRequest;
-use shared::presentation::http::v1::dto::headers::{AuthorizationHeaderError, Headers};
+use shared::presentation::http::v1::dto::Parameters;
+use shared::presentation::http::v1::dto::headers::Headers;
 use shared::presentation::http::v1::dto::datasets::{
     GetDatasetRequest, IngestDatasetRequest, ListDatasetsRequest,
-    PublishDatasetRequest
 };
 use shared::domain::entities::{
     artifact::Artifact,
+    dataset_metadata::DatasetMetadata,
     model_metadata::ModelMetadata
 };
 use shared::logging::SharedLogger;
+use shared::presentation::http::v1::responses::ModelSummary;
 use shared::presentation::http::v1::dto::models::{
     GetModelRequest, IngestModelRequest, ListModelsRequest,
 };
-use std::path::PathBuf;
+use std::env;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::process::Command;
-
-struct HuggingFaceHeaders(Headers);
-
-impl TryFrom<&HuggingFaceHeaders> for reqwest::header::HeaderMap {
-    type Error = AuthorizationHeaderError;
 
-    fn try_from(value: &HuggingFaceHeaders) -> Result<Self, Self::Error> {
-        let mut header_map = HeaderMap::new();
-        for (key, value) in value.0.into_inner().iter() {
-            let header_name = HeaderName::try_from(key.as_str())
-                .map_err(|err| AuthorizationHeaderError::HeaderNameError(err.to_string()))?;
-
-            let header_value = HeaderValue::from_str(value.as_str())
-                .map_err(|err| AuthorizationHeaderError::HeaderNameError(err.to_string()))?;
-            
-            header_map.insert(header_name, header_value);
-        }
-        Ok(header_map)
-    }
+/// A publication of an extracted artifact to a repo on the Hub
+struct PublishRepoParams<'a> {
+    repo_type: RepoType,
+    repo_id: &'a str,
+    source_dir: &'a Path,
+    /// Contents of the README.md committed when the artifact does not have one
+    card: String,
+    /// Tag applied to the commit
+    version: Option<String>,
+    request: &'a PublishArtifactRequest,
 }
 
 #[derive(Debug)]
 pub struct HuggingFaceClient {
     client: ReqwestClient,
+    base_url: String,
+    access_token: Option<String>,
     logger: SharedLogger,
 }
 
 #[async_trait::async_trait]
 impl ListModelsClient for HuggingFaceClient {
-    type Data = Value;
+    type Data = Vec<ModelSummary>;
     type Metadata = Value;
 
     async fn list_models(
@@ -74,6 +77,8 @@ impl ListModelsClient for HuggingFaceClient {
             None => Ok(10),
         };
 
+        let limit = limit.unwrap_or(10);
+
         // Build the query parameters
         let query_params = Some(ListModelsQueryParameters {
             search: request.query.get("search").cloned(),
@@ -81,29 +86,38 @@ impl ListModelsClient for HuggingFaceClient {
             filter: request.query.get("filter").cloned(),
             sort: request.query.get("sort").cloned(),
             direction: request.query.get("direction").cloned(),
-            limit: Some(limit.unwrap_or(10)),
-            full: None,
-            config: None,
+            limit: Some(limit),
+            full: parse_bool_param(&request.query, "full")?,
+            config: parse_bool_param(&request.query, "config")?,
+            cursor: request.query.get("cursor").cloned(),
         });
 
         // Construct the url for the request
-        let url = Self::format_url("models");
+        let url = self.format_url("models");
 
         self.logger.debug(format!("Request url: {}", url).as_str());
         self.logger.debug(format!("Query Params: {:#?}", &query_params).as_str());
 
         // Make a GET request to Hugging Face to fetch the models
-        let result = self.client.get(url).query(&query_params).send().await;
+        let (access_token, public_only) = self.listing_token(&request.headers)?;
+        let result = self.authorize(self.client.get(url), &access_token)
+            .query(&query_params)
+            .send()
+            .await;
 
         match result {
             Ok(response) => {
-                let body = deserialize_response_body(response).await?;
+                let next_cursor = parse_next_cursor(response.headers());
+                let mut models: Vec<ModelInfo> = deserialize_response_body(response).await?;
+                if public_only {
+                    models.retain(|model| model.private != Some(true));
+                }
 
                 Ok(ClientJsonResponse::new(
                     Some(200),
                     Some(String::from("success")),
-                    Some(body),
-                    Some(Value::Object(Map::new())),
+                    Some(models.into_iter().map(|model| model.into_summary(&request.path.platform)).collect()),
+                    Some(build_pagination_metadata(limit, next_cursor)),
                 ))
             }
 
@@ -120,7 +134,7 @@ impl ListModelsClient for HuggingFaceClient {
 
 #[async_trait::async_trait]
 impl GetModelClient for HuggingFaceClient {
-    type Data = Value;
+    type Data = ModelSummary;
     type Metadata = Value;
 
     async fn get_model(
@@ -128,43 +142,24 @@ impl GetModelClient for HuggingFaceClient {
         request: &GetModelRequest,
     ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError>
     {
-        if let Err(my_error) =
-            request.headers.validate_authorization_header(None)
-        {
-            return Err(ClientError::Internal {
-                msg: my_error.to_string(),
-                scope: ClientErrorScope::Server,
-            });
-        }
-        let fail_message = String::from_str("failed to convert to header map")
-            .expect("won't fail");
-        let headers = match HeaderMap::try_from(&HuggingFaceHeaders(request.headers.clone())) {
-            Ok(header_map) => header_map,
-            Err(_) => {
-                return Err(ClientError::Internal {
-                    msg: fail_message,
-                    scope: ClientErrorScope::Server,
-                })
-            }
-        };
+        // Private and gated models are only visible with a token
+        let access_token = self.access_token(&request.headers)?;
 
         let result = self
-            .client
-            .get(Self::format_url(
+            .authorize(self.client.get(self.format_url(
                 format!("{}/{}", "models", request.path.model_id).as_str(),
-            ))
-            .headers(headers)
+            )), &access_token)
             .send()
             .await;
 
         match result {
             Ok(response) => {
-                let body = deserialize_response_body(response).await?;
+                let model: ModelInfo = deserialize_response_body(response).await?;
 
                 Ok(ClientJsonResponse::new(
                     Some(200),
                     Some(String::from("success")),
-                    Some(body),
+                    Some(model.into_summary(&request.path.platform)),
                     Some(Value::Object(Map::new())),
                 ))
             }
@@ -185,10 +180,9 @@ impl IngestModelClient for HuggingFaceClient {
         &self,
         request: &IngestModelRequest,
         target_path: PathBuf,
-    ) -> Result<(), ClientError> {
+    ) -> Result<IngestionResult, ClientError> {
         // Get the authorization token from the request
-        let access_token = request.headers.get_first_value("Authroization")
-            .map(|t| t.replace("Bearer ", ""));
+        let access_token = self.access_token(&request.headers)?;
 
         let branch = param_to_string(request.body.params.clone(), "branch")
             .map_err(|err| ClientError::Internal {
@@ -196,21 +190,53 @@ impl IngestModelClient for HuggingFaceClient {
                 scope: ClientErrorScope::Server,
             })?;
 
-        self.sync_lfs_repo(SyncLfsRepositoryParams {
+        // A revision in the request body takes precedence over the branch param
+        let revision = request.body.revision.clone().or(branch);
+
+        let downloader = self.downloader(access_token.clone());
+        let gated = self.check_access(&downloader, RepoType::Model, &request.path.model_id).await?;
+
+        if Self::download_method(&request.body.params)? == DownloadMethod::Http {
+            // Download every file from the commit the revision currently points
+            // to so that the files are consistent with the recorded revision
+            let sha = downloader.resolve_revision(
+                RepoType::Model,
+                &request.path.model_id,
+                revision.as_deref().unwrap_or(constants::DEFAULT_REVISION)
+            ).await?;
+
+            downloader.download_repo(DownloadRepoParams {
+                repo_type: RepoType::Model,
+                repo_id: &request.path.model_id,
+                revision: &sha,
+                target_dir: &target_path,
+                include_paths: request.body.include_paths.as_deref().unwrap_or_default(),
+                exclude_paths: request.body.exclude_paths.as_deref().unwrap_or_default(),
+            })
+                .await?;
+
+            return Ok(IngestionResult::new(Some(sha)))
+        }
+
+        let repo = self.sync_lfs_repo(SyncLfsRepositoryParams {
             name: request.path.model_id.clone(),
-            remote_base_url: String::from(constants::HUGGING_FACE_BASE_URL),
+            remote_base_url: self.base_url.clone(),
             target_dir: target_path.to_string_lossy().to_string(),
-            branch,
-            access_token: access_token.clone(),
+            revision,
+            auth: access_token.clone().map(GitAuth::Bearer).unwrap_or_default(),
             include_paths: request.body.include_paths.clone(),
             exclude_paths: request.body.exclude_paths.clone(),
         })
-        .map_err(|err| ClientError::Internal {
-            msg: err.to_string(),
-            scope: ClientErrorScope::Server,
+        .map_err(|err| match gated {
+            // Git does not report why the Hub refused the request
+            true => gated_repo_error(&err.to_string()),
+            false => ClientError::Internal {
+                msg: err.to_string(),
+                scope: ClientErrorScope::Server,
+            },
         })?;
 
-        Ok(())
+        Ok(IngestionResult::new(Some(repo.repo.head_revision()?)))
     }
 }
 
@@ -230,6 +256,8 @@ impl ListDatasetsClient for HuggingFaceClient {
             None => Ok(10),
         };
 
+        let limit = limit.unwrap_or(10);
+
         // Build the query parameters
         let query_params = Some(ListDatasetsQueryParameters {
             search: request.query.get("search").cloned(),
@@ -237,27 +265,32 @@ impl ListDatasetsClient for HuggingFaceClient {
             filter: request.query.get("filter").cloned(),
             sort: request.query.get("sort").cloned(),
             direction: request.query.get("direction").cloned(),
-            limit: Some(limit.unwrap_or(10)),
-            full: None,
+            limit: Some(limit),
+            full: parse_bool_param(&request.query, "full")?,
+            cursor: request.query.get("cursor").cloned(),
         });
 
         // Make a GET request to Hugging Face to fetch the datasets
+        let (access_token, public_only) = self.listing_token(&request.headers)?;
         let result = self
-            .client
-            .get(Self::format_url("datasets"))
+            .authorize(self.client.get(self.format_url("datasets")), &access_token)
             .query(&query_params)
             .send()
             .await;
 
         match result {
             Ok(response) => {
-                let body = deserialize_response_body(response).await?;
+                let next_cursor = parse_next_cursor(response.headers());
+                let mut datasets: Vec<DatasetInfo> = deserialize_response_body(response).await?;
+                if public_only {
+                    datasets.retain(|dataset| dataset.private != Some(true));
+                }
 
                 Ok(ClientJsonResponse::new(
                     Some(200),
                     Some(String::from("success")),
-                    Some(body),
-                    Some(Value::Object(Map::new())),
+                    Some(to_dataset_value(datasets)?),
+                    Some(build_pagination_metadata(limit as i64, next_cursor)),
                 ))
             }
 
@@ -283,22 +316,22 @@ impl GetDatasetClient for HuggingFaceClient {
         request: &GetDatasetRequest,
     ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError>
     {
+        let access_token = self.access_token(&request.headers)?;
         let result = self
-            .client
-            .get(Self::format_url(
+            .authorize(self.client.get(self.format_url(
                 format!("{}/{}", "datasets", request.path.dataset_id).as_str(),
-            ))
+            )), &access_token)
             .send()
             .await;
 
         match result {
             Ok(response) => {
-                let body = deserialize_response_body(response).await?;
+                let dataset: DatasetInfo = deserialize_response_body(response).await?;
 
                 Ok(ClientJsonResponse::new(
                     Some(200),
                     Some(String::from("success")),
-                    Some(body),
+                    Some(to_dataset_value(dataset)?),
                     Some(Value::Object(Map::new())),
                 ))
             }
@@ -320,10 +353,9 @@ impl IngestDatasetClient for HuggingFaceClient {
         &self,
         request: &IngestDatasetRequest,
         target_path: PathBuf,
-    ) -> Result<(), ClientError> {
+    ) -> Result<IngestionResult, ClientError> {
         // Get the authorization token from the request
-        let access_token = request.headers.get_first_value("authorization")
-            .map(|t| t.replace("Bearer ", ""));
+        let access_token = self.access_token(&request.headers)?;
 
         let branch = param_to_string(request.body.params.clone(), "branch")
             .map_err(|err| ClientError::BadRequest {
@@ -331,17 +363,51 @@ impl IngestDatasetClient for HuggingFaceClient {
                 scope: ClientErrorScope::Client,
             })?;
 
-        self.sync_lfs_repo(SyncLfsRepositoryParams {
+        // A revision in the request body takes precedence over the branch param
+        let revision = request.body.revision.clone().or(branch);
+
+        let downloader = self.downloader(access_token.clone());
+        let gated = self.check_access(&downloader, RepoType::Dataset, &request.path.dataset_id).await?;
+
+        if Self::download_method(&request.body.params)? == DownloadMethod::Http {
+            // Download every file from the commit the revision currently points
+            // to so that the files are consistent with the recorded revision
+            let sha = downloader.resolve_revision(
+                RepoType::Dataset,
+                &request.path.dataset_id,
+                revision.as_deref().unwrap_or(constants::DEFAULT_REVISION)
+            ).await?;
+
+            downloader.download_repo(DownloadRepoParams {
+                repo_type: RepoType::Dataset,
+                repo_id: &request.path.dataset_id,
+                revision: &sha,
+                target_dir: &target_path,
+                include_paths: request.body.include_paths.as_deref().unwrap_or_default(),
+                exclude_paths: request.body.exclude_paths.as_deref().unwrap_or_default(),
+            })
+                .await?;
+
+            return Ok(IngestionResult::new(Some(sha)))
+        }
+
+        let repo = self.sync_lfs_repo(SyncLfsRepositoryParams {
             name: request.path.dataset_id.clone(),
-            remote_base_url: String::from(constants::HUGGING_FACE_BASE_URL),
+            // Dataset repositories are namespaced under /datasets on Hugging Face
+            remote_base_url: format!("{}/datasets", &self.base_url),
             target_dir: target_path.to_string_lossy().to_string(),
-            branch,
-            access_token: access_token.clone(),
+            revision,
+            auth: access_token.clone().map(GitAuth::Bearer).unwrap_or_default(),
             include_paths: request.body.include_paths.clone(),
             exclude_paths: request.body.exclude_paths.clone(),
+        })
+        .map_err(|err| match gated {
+            // Git does not report why the Hub refused the request
+            true => gated_repo_error(&err.to_string()),
+            false => ClientError::from(err),
         })?;
 
-        Ok(())
+        Ok(IngestionResult::new(Some(repo.repo.head_revision()?)))
     }
 }
 
@@ -357,205 +423,297 @@ impl PublishModelClient for HuggingFaceClient {
             None => return Err(ClientError::BadRequest { msg: "Model metadata must contain a name in order to publish to huggingface".into(), scope: ClientErrorScope::Client })
         };
 
-        // Get the access token from the headers
-        let access_token = match request.headers.get_first_value("authorization") {
-            Some(t) => t.replace("Bearer ", ""),
-            None => return Err(ClientError::BadRequest { msg: "Missing Authorization header".into(), scope: ClientErrorScope::Client })
-        };
-        
-        // Check that the repo on huggingface exists
-        let base_url = Self::format_url("models");
-        let maybe_response = self.client.get(format!("{}/{}", &base_url, &model_name))
-            .header("Authorization", format!("Bearer {}", &access_token))
-            .send()
-            .await;
-        
-        let response = match maybe_response {
-            Ok(r) => r,
-            Err(err) => {
-                return Err(ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client })
-            }
-        };
-        
-        // Return an error if the repo doesn't exist or there is some remote
-        // internal error
-        match response.status() {
-            StatusCode::NOT_FOUND => return Err(ClientError::NotFound { msg: format!("Repo for user/model '{}' does not exist. Repo must exist before attempting to publish to it.", &model_name), scope: ClientErrorScope::Client }),
-            StatusCode::INTERNAL_SERVER_ERROR
-            | StatusCode::SERVICE_UNAVAILABLE => return Err(ClientError::Internal { msg: format!("Internal error with remote server when attempting to very if repo already exists for model {}", &model_name), scope: ClientErrorScope::Server }),
-            _ => {}
+        self.publish_repo(PublishRepoParams {
+            repo_type: RepoType::Model,
+            repo_id: &model_name,
+            source_dir: extracted_artifact_path,
+            card: build_model_card(metadata),
+            version: metadata.version.clone(),
+            request,
+        }).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PublishModelMetadataClient for HuggingFaceClient {
+    async fn publish_model_metadata(&self, _metadata: &ModelMetadata, _result: &PublishArtifactRequest) -> Result<PublicationResult, ClientError> {
+        return Ok(PublicationResult::default())
+    }
+}
+
+#[async_trait::async_trait]
+impl PublishDatasetClient for HuggingFaceClient {
+    type Data = Value;
+    type Metadata = Value;
+
+    async fn publish_dataset(&self, extracted_artifact_path: &PathBuf, _artifact: &Artifact, metadata: &DatasetMetadata, request: &PublishArtifactRequest) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+        // Get the repo/dataset name from the metadata
+        let dataset_name = match metadata.name.clone() {
+            Some(n) => n,
+            None => return Err(ClientError::BadRequest { msg: "Dataset metadata must contain a name in order to publish to huggingface".into(), scope: ClientErrorScope::Client })
         };
-        
-        // Pull the large files wil git lfs then remove the existing .git directory
-        if extracted_artifact_path.join(".git").is_dir() {
-            std::fs::remove_dir_all(extracted_artifact_path.join(".git"))
-                .map_err(|err| ClientError::Internal { msg: format!("Error removing .git directory: {}", err.to_string()), scope: ClientErrorScope::Client })?;
+
+        self.publish_repo(PublishRepoParams {
+            repo_type: RepoType::Dataset,
+            repo_id: &dataset_name,
+            source_dir: extracted_artifact_path,
+            card: build_dataset_card(metadata),
+            version: metadata.version.clone(),
+            request,
+        }).await
+    }
+}
+
+impl SyncGitRepository for HuggingFaceClient {}
+
+impl HuggingFaceClient {
+    pub fn new() -> Result<Self, ClientError> {
+        Self::with_config(&ClientConfig::default())
+    }
+
+    /// Builds a client from the provided config. The base url is resolved from
+    /// the config, then the HF_ENDPOINT env var, and finally defaults to the
+    /// public Hugging Face hub
+    pub fn with_config(config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self {
+            client: config.http_client()?,
+            base_url: Self::resolve_base_url(
+                config.base_url.clone(),
+                env::var(constants::HUGGING_FACE_ENDPOINT_ENV_VAR).ok()
+            ),
+            access_token: config.access_token.clone(),
+            logger: SharedLogger::new(),
+        })
+    }
+
+    /// The configured base url, then the endpoint, then the public Hugging
+    /// Face hub. Empty urls are ignored
+    pub(crate) fn resolve_base_url(configured: Option<String>, endpoint: Option<String>) -> String {
+        configured.filter(|url| !url.trim().is_empty())
+            .or(endpoint.filter(|url| !url.trim().is_empty()))
+            .unwrap_or(String::from(constants::HUGGING_FACE_BASE_URL))
+            .trim_end_matches("/")
+            .to_string()
+    }
+
+    /// The download method selected by the "download_method" ingestion param.
+    /// Defaults to git
+    fn download_method(params: &Option<Parameters>) -> Result<DownloadMethod, ClientError> {
+        let method = param_to_string(params.clone(), "download_method")
+            .map_err(|err| ClientError::BadRequest {
+                msg: format!("Bad request: {}", err.to_string()),
+                scope: ClientErrorScope::Client,
+            })?;
+
+        match method {
+            Some(method) => DownloadMethod::from_str(&method),
+            None => Ok(DownloadMethod::Git),
         }
+    }
 
-        // Get the huggingface username from the model name
-        let hf_username = model_name.split("/").collect::<Vec<&str>>()[0];
-        
-        // Construct remote name. Contains the auth token
-        let origin = PathBuf::new()
-            .join(constants::HUGGING_FACE_BASE_URL.replace("//huggingface", format!("//{}:{}@huggingface", &hf_username, &access_token).as_str()))
-            .join(&model_name)
-            .to_string_lossy()
-            .to_string();
-        
-        // Initialize git repo, add all changes, commit, then add remote
-        let init_output = Command::new("sh")
-            .current_dir(&extracted_artifact_path)
-            .arg("-c")
-            .arg(format!("set -e; git init && git add -A && git -c user.name=\"MLHub HugginFace Client\" -c user.email=\"hf.client@mlhub\" commit -m \"MLHub HuggingFace Client: initial commit\" && git remote add origin {}", &origin))
-            .output()
-            .map_err(|err| ClientError::Internal { msg: format!("Error initializing, commiting, and adding origin: {}", err.to_string()), scope: ClientErrorScope::Client })?;
-
-        // Check that the operation was successful
-        match init_output.status.code() {
-            Some(code) => {
-                if code != 0 {
-                    return Err(
-                        ClientError::Internal {
-                            msg: String::from_utf8(init_output.stderr)
-                                .unwrap_or("git init operation failed. Additionally, stderr from the git rev-parse process could not be decoded".into()),
-                            scope: ClientErrorScope::Client
-                        }
-                    )
-                }
-            },
-            None => {
-                return Err(ClientError::Internal { msg: "The git init operation was terminated by an unknown signal".into(), scope: ClientErrorScope::Client })
-            } 
-        };
-        
-        // Get the current branch
-        let mut cmd = Command::new("git");
-
-        let branch_name_output = cmd.current_dir(&extracted_artifact_path)
-            .arg("rev-parse")
-            .arg("--abbrev-ref")
-            .arg("HEAD")
-            .output()
-            .map_err(|err| ClientError::Internal { msg: format!("Failed to get branch name: {}", err.to_string()), scope: ClientErrorScope::Client })?;
-        
-        // Check that the branch name was output successfully
-        let branch_name = match branch_name_output.status.code() {
-            Some(code) => {
-                if code != 0 {
-                    return Err(
-                        ClientError::Internal {
-                            msg: String::from_utf8(branch_name_output.stderr)
-                                .unwrap_or("git rev-parse operation failed. Additionally, stderr from the git rev-parse process could not be decoded".into()),
-                            scope: ClientErrorScope::Client }
-                    )
-                    
-                }
-                // NOTE We a trimming at the end because we are getting the newline from stdout!
-                String::from_utf8(branch_name_output.stdout)
-                    .map_err(|err| ClientError::Internal { msg: format!("Failed to decode stdout of command `git rev-parse ...`: {}", err.to_string()), scope: ClientErrorScope::Client })?
-                    .trim()
-                    .to_string()
-            },
-            None => {
-                return Err(ClientError::Internal { msg: "The git rev-parse operation was terminated by an unknown signal".into(), scope: ClientErrorScope::Client })
-            } 
+    /// Commits the files of an extracted artifact to a repo on the Hub and tags
+    /// the commit with the version of the artifact
+    async fn publish_repo(&self, params: PublishRepoParams<'_>) -> Result<ClientJsonResponse<Value, Value>, ClientError> {
+        let PublishRepoParams { repo_type, repo_id, source_dir, card, version, request } = params;
+
+        // Publishing writes to the Hub, so it is only done with the caller's
+        // own token and never with the token the client was configured with
+        let access_token = match request.headers.bearer_token()? {
+            Some(t) => t,
+            None => return Err(ClientError::BadRequest { msg: "Missing Authorization header".into(), scope: ClientErrorScope::Client })
         };
-        
-        // Start the git push command
-        let mut cmd = Command::new("git");
-
-        // Extend the headers on the push command with the provided access token
-        // and push to the branch according
-        let push_output = cmd.current_dir(&extracted_artifact_path)
-            .env("GIT_CURL_VERBOSE", "1")
-            .env("GIT_TRACE", "1")
-            .env("GIT_TRANSFER_TRACE", "1")
-            .arg("push")
-            .arg("-u")
-            .arg("origin")
-            .arg(&branch_name)
-            .output()
-            .map_err(|err| {
-                ClientError::Internal { msg: format!("Failed to push artifact: {}", err.to_string()), scope: ClientErrorScope::Client }
-            })?;
 
-        // TODO check metadata for a version number. If provided, create a tag
-        // and push it up
-
-        // Check that the push was successful
-        match push_output.status.code() {
-            Some(code) => {
-                if code != 0 {
-                    return Err(
-                        ClientError::Internal {
-                            msg: String::from_utf8(push_output.stderr)
-                                    .unwrap_or(format!("`git push origin {}` operation failed. Additionally, stderr from the git rev-parse process could not be decoded", &branch_name)),
-                            scope: ClientErrorScope::Client }
-                    )
-                    
-                }
+        let options = request.body.options.clone().unwrap_or_default();
+        let branch = options.branch.clone().unwrap_or(String::from(constants::DEFAULT_REVISION));
+        let commit_message = options.commit_message.clone()
+            .unwrap_or(String::from(match repo_type {
+                RepoType::Model => constants::DEFAULT_COMMIT_MESSAGE,
+                RepoType::Dataset => constants::DEFAULT_DATASET_COMMIT_MESSAGE,
+            }));
+
+        // Create the repo if it does not exist and the publication allows it
+        let created_repo = match self.repo_exists(repo_type, repo_id, &access_token).await? {
+            true => false,
+            false if options.create_repo.unwrap_or(false) => {
+                self.create_repo(repo_type, repo_id, options.private.unwrap_or(true), &access_token).await?;
+                true
             },
-            None => {
-                return Err(ClientError::Internal { msg: "The git push operation was terminated by an unknown signal".into(), scope: ClientErrorScope::Client })
-            } 
+            false => return Err(ClientError::NotFound { msg: format!("Repo for user/{} '{}' does not exist. Set the 'create_repo' publish option to create it.", repo_type.name(), repo_id), scope: ClientErrorScope::Client }),
         };
+
+        // Generate a card unless the artifact already has one
+        let readme_path = source_dir.join("README.md");
+        if !readme_path.exists() {
+            std::fs::write(&readme_path, card)
+                .map_err(|err| ClientError::Internal { msg: format!("Error writing {} card: {}", repo_type.name(), err), scope: ClientErrorScope::Client })?;
+        }
+
+        let uploader = self.uploader(&access_token);
+
+        // A tag that already exists would only be rejected after the files were
+        // committed
+        if let Some(tag) = &version {
+            if uploader.find_tag(repo_type, repo_id, tag).await?.is_some() {
+                return Err(tag_exists_error(repo_id, tag))
+            }
+        }
+
+        if branch != constants::DEFAULT_REVISION {
+            uploader.create_branch(repo_type, repo_id, &branch).await?;
+        }
+
+        // Commit every file of the artifact over the http api. Large files are
+        // uploaded to lfs storage first
+        let commit = uploader.upload_folder(UploadFolderParams {
+            repo_type,
+            repo_id,
+            branch: &branch,
+            source_dir,
+            commit_message: &commit_message,
+        }).await?;
+
+        // Tag the commit with the version of the artifact
+        if let Some(tag) = &version {
+            uploader.create_tag(repo_type, repo_id, &commit, tag, &commit_message).await?;
+        }
         
         return Ok(
             ClientJsonResponse::new(
                 None,
                 None,
-                None,
+                Some(json!({
+                    "repo_id": repo_id,
+                    "branch": branch,
+                    "tag": version,
+                    "commit": commit,
+                    "created_repo": created_repo,
+                })),
                 None
             )
         )
     }
-}
 
-#[async_trait::async_trait]
-impl PublishModelMetadataClient for HuggingFaceClient {
-    type Data = Value;
-    type Metadata = Value;
+    /// Checks whether a repo exists on the Hub
+    async fn repo_exists(&self, repo_type: RepoType, repo_id: &str, access_token: &str) -> Result<bool, ClientError> {
+        let response = self.client.get(self.format_url(format!("{}/{}", repo_type.api_prefix(), repo_id).as_str()))
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client })?;
 
-    async fn publish_model_metadata(&self, _metadata: &ModelMetadata, _result: &PublishArtifactRequest) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
-        return Ok(
-            ClientJsonResponse::new(
-                None,
-                None,
-                None,
-                None
-            )
-        )
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(false),
+            status if status.is_success() => Ok(true),
+            _ => Err(response_error(response).await),
+        }
     }
-}
 
-#[async_trait::async_trait]
-impl PublishDatasetClient for HuggingFaceClient {
-    type Data = Value;
-    type Metadata = Value;
+    /// Creates a repo on the Hub. The repo id is of the form
+    /// "{user or organization}/{name}"
+    pub(crate) async fn create_repo(&self, repo_type: RepoType, repo_id: &str, private: bool, access_token: &str) -> Result<(), ClientError> {
+        let (organization, name) = match repo_id.split_once("/") {
+            Some((organization, name)) => (Some(organization), name),
+            None => (None, repo_id),
+        };
 
-    async fn publish_dataset(
-        &self,
-        _result: &PublishDatasetRequest,
-    ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
-        Err(ClientError::Unimplemented)
+        let response = self.client.post(self.format_url("repos/create"))
+            .header("Authorization", format!("Bearer {}", access_token))
+            .json(&json!({
+                "type": repo_type.name(),
+                "name": name,
+                "organization": organization,
+                "private": private,
+            }))
+            .send()
+            .await
+            .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client })?;
+
+        let status = response.status();
+        // The repo was created by someone else in the meantime
+        if status.is_success() || status == StatusCode::CONFLICT {
+            return Ok(())
+        }
+
+        Err(response_error(response).await)
     }
-}
 
-impl SyncGitRepository for HuggingFaceClient {}
+    /// The token of the request's authorization header. The token the client
+    /// was configured with is never used here, so callers can only see and
+    /// download the repos their own token grants access to
+    fn access_token(&self, headers: &Headers) -> Result<Option<String>, ClientError> {
+        Ok(headers.bearer_token()?)
+    }
 
-impl HuggingFaceClient {
-    pub fn new() -> Self {
-        Self {
-            client: ReqwestClient::new(),
-            logger: SharedLogger::new(),
+    /// The token used to list models and datasets. Falls back to the token the
+    /// client was configured with so anonymous listings get the higher rate
+    /// limit of an authenticated account. Returns whether the configured token
+    /// is used, in which case private repos of its account must be left out
+    fn listing_token(&self, headers: &Headers) -> Result<(Option<String>, bool), ClientError> {
+        match self.access_token(headers)? {
+            Some(token) => Ok((Some(token), false)),
+            None => Ok((self.access_token.clone(), self.access_token.is_some())),
+        }
+    }
+
+    fn authorize(&self, request: RequestBuilder, access_token: &Option<String>) -> RequestBuilder {
+        match access_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    /// Checks that the repo can be downloaded before downloading it. Gated
+    /// repos require a token of an account that has been granted access, so
+    /// requests without a token fail fast. Returns whether the repo is gated
+    async fn check_access(&self, downloader: &HubDownloader<'_>, repo_type: RepoType, repo_id: &str) -> Result<bool, ClientError> {
+        let gated = downloader.repo_access(repo_type, repo_id).await?.is_gated();
+
+        if gated && downloader.access_token.is_none() {
+            return Err(gated_repo_error(&format!(
+                "{}/{}{}",
+                &self.base_url,
+                repo_type.resolve_prefix(),
+                repo_id
+            )))
+        }
+
+        Ok(gated)
+    }
+
+    fn uploader<'a>(&'a self, access_token: &'a str) -> HubUploader<'a> {
+        HubUploader {
+            client: &self.client,
+            base_url: &self.base_url,
+            access_token,
+            logger: &self.logger,
         }
     }
 
-    fn format_url(url: &str) -> String {
+    fn downloader(&self, access_token: Option<String>) -> HubDownloader<'_> {
+        HubDownloader {
+            client: &self.client,
+            base_url: &self.base_url,
+            access_token,
+            logger: &self.logger,
+        }
+    }
+
+    fn format_url(&self, url: &str) -> String {
         format!(
             "{}/api/{}",
-            constants::HUGGING_FACE_BASE_URL,
+            &self.base_url,
             url.strip_prefix("/").unwrap_or(url).to_string()
         )
     }
 }
+
+/// Serializes typed dataset responses into the data returned by dataset clients
+fn to_dataset_value<T: serde::Serialize>(datasets: T) -> Result<Value, ClientError> {
+    to_value(datasets)
+        .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server })
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "client.test.rs"]
+mod client_test;
@@ -0,0 +1,512 @@
+#[cfg(test)]
+mod client_test {
+    use std::collections::HashMap;
+    use std::env;
+    use std::sync::mpsc::Receiver;
+    use clients::{
+        ClientConfig, ClientError, ClientErrorScope, GetModelClient, IngestDatasetClient, IngestModelClient,
+        ListModelsClient, PublishDatasetClient,
+    };
+    use serde_json::{json, Value};
+    use shared::presentation::http::v1::dto::headers::Headers;
+    use shared::presentation::http::v1::dto::Parameters;
+    use shared::domain::entities::artifact::{Artifact, ArtifactType};
+    use shared::domain::entities::dataset_metadata::DatasetMetadata;
+    use shared::presentation::http::v1::dto::artifacts::{
+        IngestArtifactBody, PublishArtifactBody, PublishArtifactPath, PublishArtifactRequest, PublishOptions,
+    };
+    use shared::presentation::http::v1::dto::datasets::{IngestDatasetPath, IngestDatasetRequest};
+    use shared::presentation::http::v1::dto::models::{
+        GetModelPath, GetModelRequest, IngestModelPath, IngestModelRequest, ListModelsPath,
+        ListModelsRequest,
+    };
+    use crate::client::HuggingFaceClient;
+    use crate::constants;
+    use crate::download::RepoType;
+    use test_support::stub_server::{self, StubRequest, StubResponse};
+
+    /// Starts a stub server that responds to every request with the provided
+    /// json body. Returns the base url of the server and a receiver for the
+    /// requests it has handled
+    fn start_stub_server(body: Value) -> (String, Receiver<StubRequest>) {
+        start_stub_server_with_headers(body, Vec::new())
+    }
+
+    /// Same as `start_stub_server` but also responds with the provided headers
+    fn start_stub_server_with_headers(body: Value, headers: Vec<(&'static str, String)>) -> (String, Receiver<StubRequest>) {
+        stub_server::start(move |_| {
+            let mut response = StubResponse::json(body.to_string());
+            response.headers.extend(headers.iter().map(|(name, value)| (name.to_string(), value.clone())));
+            response
+        })
+    }
+
+    fn client_for(base_url: &str) -> HuggingFaceClient {
+        HuggingFaceClient::with_config(&ClientConfig {
+            base_url: Some(String::from(base_url)),
+            ..Default::default()
+        }).unwrap()
+    }
+
+    #[test]
+    fn test_base_url_resolution() {
+        let config = ClientConfig {
+            base_url: Some(String::from("https://hf.example.org/")),
+            ..Default::default()
+        };
+        assert_eq!(
+            HuggingFaceClient::with_config(&config).unwrap().format_url("models"),
+            "https://hf.example.org/api/models"
+        );
+
+        // The endpoint (HF_ENDPOINT) is only used when the config does not
+        // set a base url
+        let endpoint = || Some(String::from("https://hf-mirror.example.org"));
+        assert_eq!(HuggingFaceClient::resolve_base_url(None, endpoint()), "https://hf-mirror.example.org");
+        assert_eq!(
+            HuggingFaceClient::resolve_base_url(Some(String::from("https://hf.example.org/")), endpoint()),
+            "https://hf.example.org"
+        );
+        assert_eq!(HuggingFaceClient::resolve_base_url(None, Some(String::from(" "))), constants::HUGGING_FACE_BASE_URL);
+        assert_eq!(HuggingFaceClient::resolve_base_url(None, None), constants::HUGGING_FACE_BASE_URL);
+    }
+
+    #[tokio::test]
+    async fn test_list_models_uses_base_url() {
+        let (base_url, receiver) = start_stub_server(json!([{ "id": "org/model" }]));
+
+        let request = ListModelsRequest {
+            headers: Headers::new(Vec::new()),
+            path: ListModelsPath { platform: String::from("huggingface") },
+            query: HashMap::from([(String::from("search"), String::from("bert"))]),
+            body: Default::default(),
+        };
+
+        let response = client_for(&base_url).list_models(&request).await.unwrap();
+
+        let models = response.result.unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].id, "org/model");
+        assert_eq!(models[0].platform, "huggingface");
+        let request_line = receiver.recv().unwrap().request_line;
+        assert!(request_line.starts_with("GET /api/models?"));
+        assert!(request_line.contains("search=bert"));
+    }
+
+    #[tokio::test]
+    async fn test_list_models_pagination() {
+        let (base_url, receiver) = start_stub_server_with_headers(
+            json!([{ "id": "org/model" }]),
+            vec![("Link", String::from("<https://huggingface.co/api/models?limit=5&cursor=abc123>; rel=\"next\""))]
+        );
+
+        let request = ListModelsRequest {
+            headers: Headers::new(Vec::new()),
+            path: ListModelsPath { platform: String::from("huggingface") },
+            query: HashMap::from([
+                (String::from("limit"), String::from("5")),
+                (String::from("cursor"), String::from("xyz789")),
+                (String::from("full"), String::from("true")),
+            ]),
+            body: Default::default(),
+        };
+
+        let response = client_for(&base_url).list_models(&request).await.unwrap();
+
+        assert_eq!(response.metadata, Some(json!({ "limit": 5, "next_cursor": "abc123" })));
+        let request_line = receiver.recv().unwrap().request_line;
+        assert!(request_line.contains("cursor=xyz789"));
+        assert!(request_line.contains("full=true"));
+    }
+
+    #[tokio::test]
+    async fn test_get_model_uses_base_url() {
+        let (base_url, receiver) = start_stub_server(json!({ "id": "org/model" }));
+
+        let request = GetModelRequest {
+            headers: Headers::new(Vec::new()),
+            path: GetModelPath {
+                platform: String::from("huggingface"),
+                model_id: String::from("org/model"),
+            },
+            query: HashMap::new(),
+            body: Default::default(),
+        };
+
+        let response = client_for(&base_url).get_model(&request).await.unwrap();
+
+        assert_eq!(response.result.unwrap().name, "model");
+        assert_eq!(receiver.recv().unwrap().request_line, "GET /api/models/org/model HTTP/1.1");
+    }
+
+    fn client_with_token(base_url: &str) -> HuggingFaceClient {
+        HuggingFaceClient::with_config(&ClientConfig {
+            base_url: Some(String::from(base_url)),
+            access_token: Some(String::from("operator-token")),
+            ..Default::default()
+        }).unwrap()
+    }
+
+    fn list_models_request(headers: Vec<(String, String)>) -> ListModelsRequest {
+        ListModelsRequest {
+            headers: Headers::new(headers),
+            path: ListModelsPath { platform: String::from("huggingface") },
+            query: HashMap::new(),
+            body: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_models_falls_back_to_configured_token() {
+        let (base_url, receiver) = start_stub_server(json!([
+            { "id": "org/public", "private": false },
+            { "id": "org/private", "private": true }
+        ]));
+
+        let response = client_with_token(&base_url).list_models(&list_models_request(Vec::new())).await.unwrap();
+
+        // Private repos of the operator's account are not listed
+        let models = response.result.unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].id, "org/public");
+        assert_eq!(receiver.recv().unwrap().header("authorization"), Some("Bearer operator-token"));
+
+        // The caller's token takes precedence and can see its private repos
+        let headers = vec![(String::from("Authorization"), String::from("Bearer caller-token"))];
+        let response = client_with_token(&base_url).list_models(&list_models_request(headers)).await.unwrap();
+
+        assert_eq!(response.result.unwrap().len(), 2);
+        assert_eq!(receiver.recv().unwrap().header("authorization"), Some("Bearer caller-token"));
+    }
+
+    #[tokio::test]
+    async fn test_get_model_does_not_use_configured_token() {
+        let (base_url, receiver) = start_stub_server(json!({ "id": "org/model" }));
+
+        let request = GetModelRequest {
+            headers: Headers::new(Vec::new()),
+            path: GetModelPath {
+                platform: String::from("huggingface"),
+                model_id: String::from("org/model"),
+            },
+            query: HashMap::new(),
+            body: Default::default(),
+        };
+
+        client_with_token(&base_url).get_model(&request).await.unwrap();
+
+        assert!(receiver.recv().unwrap().header("authorization").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_model_maps_upstream_errors() {
+        let (base_url, _requests) = stub_server::start(|request: &StubRequest| {
+            let status = match request.path() {
+                "/api/models/org/gated" => 403,
+                "/api/models/org/missing" => 404,
+                "/api/models/org/busy" => 429,
+                _ => 500,
+            };
+            StubResponse {
+                status,
+                headers: vec![(String::from("Retry-After"), String::from("30"))],
+                body: json!({ "error": "Upstream error" }).to_string().into_bytes(),
+            }
+        });
+        let client = client_for(&base_url);
+
+        let get_model = |model_id: &str| GetModelRequest {
+            headers: Headers::new(Vec::new()),
+            path: GetModelPath {
+                platform: String::from("huggingface"),
+                model_id: String::from(model_id),
+            },
+            query: HashMap::new(),
+            body: Default::default(),
+        };
+
+        assert!(matches!(
+            client.get_model(&get_model("org/gated")).await,
+            Err(ClientError::Forbidden { scope: ClientErrorScope::Server, .. })
+        ));
+        assert!(matches!(
+            client.get_model(&get_model("org/missing")).await,
+            Err(ClientError::NotFound { ref msg, .. }) if msg.contains("Upstream error")
+        ));
+        assert!(matches!(
+            client.get_model(&get_model("org/busy")).await,
+            Err(ClientError::RateLimited { retry_after: Some(ref retry_after), .. }) if retry_after == "30"
+        ));
+        assert!(matches!(
+            client.get_model(&get_model("org/broken")).await,
+            Err(ClientError::Internal { scope: ClientErrorScope::Server, .. })
+        ));
+    }
+
+    fn ingest_request(model_id: &str, headers: Vec<(String, String)>, params: Option<Parameters>) -> IngestModelRequest {
+        IngestModelRequest {
+            headers: Headers::new(headers),
+            path: IngestModelPath {
+                platform: String::from("huggingface"),
+                model_id: String::from(model_id),
+            },
+            query: HashMap::new(),
+            body: IngestArtifactBody {
+                include_paths: None,
+                exclude_paths: None,
+                revision: None,
+                webhook_url: None,
+                params,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ingest_gated_model() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            match request.path() {
+                "/api/models/org/gated" => StubResponse::json(json!({ "id": "org/gated", "gated": "manual" }).to_string()),
+                _ => StubResponse {
+                    status: 403,
+                    headers: vec![
+                        (String::from("X-Error-Code"), String::from("GatedRepo")),
+                        (String::from("X-Error-Message"), String::from("Access to model org/gated is restricted")),
+                    ],
+                    body: Vec::new(),
+                },
+            }
+        });
+        let client = client_for(&base_url);
+        let target_dir = env::temp_dir().join(format!("hf-gated-{}", std::process::id()));
+
+        // Gated repos fail fast without a token
+        let result = client.ingest_model(&ingest_request("org/gated", Vec::new(), None), target_dir.clone()).await;
+        assert!(matches!(
+            result,
+            Err(ClientError::Forbidden { ref msg, .. }) if msg.contains("Access must be requested")
+        ));
+
+        // The Hub refuses a token that has not been granted access
+        let params = Parameters::from([(String::from("download_method"), json!("http"))]);
+        let headers = vec![(String::from("authorization"), String::from("Bearer hf_token"))];
+        let result = client.ingest_model(&ingest_request("org/gated", headers, Some(params)), target_dir.clone()).await;
+        assert!(matches!(
+            result,
+            Err(ClientError::Forbidden { ref msg, .. }) if msg.contains("Access must be requested") && msg.contains("is restricted")
+        ));
+
+        let authorizations: Vec<Option<String>> = requests.try_iter()
+            .map(|request| request.header("authorization").map(String::from))
+            .collect();
+        assert_eq!(authorizations, vec![None, Some(String::from("Bearer hf_token")), Some(String::from("Bearer hf_token"))]);
+        assert!(!target_dir.join("config.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_malformed_authorization_header() {
+        let (base_url, _requests) = start_stub_server(json!({ "id": "org/model" }));
+
+        let request = GetModelRequest {
+            headers: Headers::new(vec![(String::from("authorization"), String::from("Basic dXNlcjpwYXNz"))]),
+            path: GetModelPath {
+                platform: String::from("huggingface"),
+                model_id: String::from("org/model"),
+            },
+            query: HashMap::new(),
+            body: Default::default(),
+        };
+
+        let result = client_for(&base_url).get_model(&request).await;
+
+        match result {
+            Err(err @ ClientError::MissingInvalidCredentials(_)) => assert_eq!(err.status_code(), 400),
+            _ => panic!("A malformed authorization header should be a client error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_repo() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            match request.header("authorization") {
+                Some("Bearer hf_token") => StubResponse::json(json!({ "url": "https://hf.example.org/org/model" }).to_string()),
+                // Repo already exists
+                Some("Bearer hf_existing") => StubResponse { status: 409, headers: Vec::new(), body: Vec::new() },
+                _ => StubResponse { status: 403, headers: Vec::new(), body: Vec::new() },
+            }
+        });
+        let client = client_for(&base_url);
+
+        client.create_repo(RepoType::Model, "org/model", true, "hf_token").await.expect("Repo should be created");
+        client.create_repo(RepoType::Model, "org/model", true, "hf_existing").await.expect("Existing repo should not be an error");
+        assert!(matches!(
+            client.create_repo(RepoType::Model, "org/model", true, "hf_read_only").await,
+            Err(ClientError::Forbidden { .. })
+        ));
+        client.create_repo(RepoType::Dataset, "org/corpus", false, "hf_token").await.expect("Dataset repo should be created");
+
+        let request = requests.recv().unwrap();
+        assert!(request.request_line.starts_with("POST /api/repos/create "));
+        let body: Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body, json!({ "type": "model", "name": "model", "organization": "org", "private": true }));
+
+        let body: Value = serde_json::from_slice(&requests.iter().nth(2).unwrap().body).unwrap();
+        assert_eq!(body, json!({ "type": "dataset", "name": "corpus", "organization": "org", "private": false }));
+    }
+
+    const SHA: &str = "0123456789abcdef0123456789abcdef01234567";
+
+    #[tokio::test]
+    async fn test_ingest_dataset_over_http() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            match request.path() {
+                "/api/datasets/org/corpus" => StubResponse::json(json!({ "id": "org/corpus", "gated": false }).to_string()),
+                "/api/datasets/org/corpus/revision/v1" => StubResponse::json(json!({ "id": "org/corpus", "sha": SHA }).to_string()),
+                path if path.starts_with(&format!("/api/datasets/org/corpus/tree/{}", SHA)) => StubResponse::json(json!([
+                    { "type": "file", "path": "data/train.csv", "size": 4 }
+                ]).to_string()),
+                path if path == format!("/datasets/org/corpus/resolve/{}/data/train.csv", SHA) => StubResponse {
+                    status: 200,
+                    headers: Vec::new(),
+                    body: b"a,b\n".to_vec(),
+                },
+                _ => StubResponse { status: 404, headers: Vec::new(), body: Vec::new() },
+            }
+        });
+        let target_dir = env::temp_dir().join(format!("hf-ingest-dataset-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&target_dir);
+
+        let request = IngestDatasetRequest {
+            headers: Headers::new(Vec::new()),
+            path: IngestDatasetPath {
+                platform: String::from("huggingface"),
+                dataset_id: String::from("org/corpus"),
+            },
+            query: HashMap::new(),
+            body: IngestArtifactBody {
+                include_paths: None,
+                exclude_paths: None,
+                revision: Some(String::from("v1")),
+                webhook_url: None,
+                params: Some(Parameters::from([(String::from("download_method"), json!("http"))])),
+            },
+        };
+
+        let result = client_for(&base_url).ingest_dataset(&request, target_dir.clone()).await.unwrap();
+
+        // Files are downloaded from the commit the revision resolved to
+        assert_eq!(result.resolved_revision.as_deref(), Some(SHA));
+        assert_eq!(std::fs::read(target_dir.join("data/train.csv")).unwrap(), b"a,b\n");
+        assert_eq!(requests.try_iter().count(), 4);
+
+        std::fs::remove_dir_all(&target_dir).unwrap();
+    }
+
+    /// Serves the endpoints used to publish the `org/corpus` dataset, which
+    /// already exists and has no tags
+    fn handle_publish(request: &StubRequest) -> StubResponse {
+        match (request.method(), request.path()) {
+            ("GET", "/api/datasets/org/corpus") => StubResponse::json(json!({ "id": "org/corpus" }).to_string()),
+            ("GET", "/api/datasets/org/corpus/refs") => StubResponse::json(json!({ "tags": [] }).to_string()),
+            ("POST", "/api/datasets/org/corpus/preupload/main") => StubResponse::json(json!({
+                "files": [
+                    { "path": "README.md", "uploadMode": "regular", "shouldIgnore": false },
+                    { "path": "train.csv", "uploadMode": "regular", "shouldIgnore": false }
+                ]
+            }).to_string()),
+            ("POST", "/api/datasets/org/corpus/commit/main") => StubResponse::json(json!({
+                "commitOid": SHA,
+                "commitUrl": format!("https://hf.example.org/datasets/org/corpus/commit/{}", SHA)
+            }).to_string()),
+            ("POST", path) if path == format!("/api/datasets/org/corpus/tag/{}", SHA) => StubResponse::json(String::from("{}")),
+            _ => StubResponse { status: 404, headers: Vec::new(), body: Vec::new() },
+        }
+    }
+
+    fn dataset_metadata() -> DatasetMetadata {
+        DatasetMetadata {
+            name: Some(String::from("org/corpus")),
+            version: Some(String::from("v1.0.0")),
+            description: Some(String::from("A corpus")),
+            modalities: None,
+            task_types: None,
+            format: Some(String::from("csv")),
+            labels: None,
+            schema: None,
+            splits: None,
+            num_rows: None,
+            size_bytes: None,
+            provenance: None,
+            license: None,
+            regulatory: None,
+        }
+    }
+
+    fn publish_request(headers: Vec<(String, String)>) -> PublishArtifactRequest {
+        PublishArtifactRequest {
+            headers: Headers::new(headers),
+            path: PublishArtifactPath { artifact_id: String::from("0196b5a9-0e6f-7d43-a3d2-4c1a2b3c4d5e") },
+            query: HashMap::new(),
+            body: PublishArtifactBody {
+                target_platform: String::from("huggingface"),
+                webhook_url: None,
+                options: Some(PublishOptions {
+                    create_repo: None,
+                    private: None,
+                    commit_message: None,
+                    branch: None,
+                }),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_dataset() {
+        let (base_url, requests) = stub_server::start(handle_publish);
+        let source_dir = env::temp_dir().join(format!("hf-publish-dataset-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&source_dir);
+        std::fs::create_dir_all(&source_dir).unwrap();
+        std::fs::write(source_dir.join("train.csv"), b"a,b\n").unwrap();
+
+        let headers = vec![(String::from("authorization"), String::from("Bearer hf_token"))];
+        let response = client_for(&base_url)
+            .publish_dataset(&source_dir, &Artifact::new(ArtifactType::Dataset), &dataset_metadata(), &publish_request(headers))
+            .await
+            .unwrap();
+
+        assert_eq!(response.result.unwrap(), json!({
+            "repo_id": "org/corpus",
+            "branch": "main",
+            "tag": "v1.0.0",
+            "commit": SHA,
+            "created_repo": false,
+        }));
+
+        // A dataset card is generated for the artifact and the commit is tagged
+        // with the version of the dataset
+        let requests: Vec<StubRequest> = requests.try_iter().collect();
+        assert!(requests.iter().all(|request| request.header("authorization") == Some("Bearer hf_token")));
+        let commit = requests.iter()
+            .find(|request| request.path() == "/api/datasets/org/corpus/commit/main")
+            .expect("Missing commit request");
+        assert!(String::from_utf8_lossy(&commit.body).contains("README.md"));
+        let tag = requests.iter()
+            .find(|request| request.path() == format!("/api/datasets/org/corpus/tag/{}", SHA))
+            .expect("Missing tag request");
+        let body: Value = serde_json::from_slice(&tag.body).unwrap();
+        assert_eq!(body["tag"], "v1.0.0");
+
+        std::fs::remove_dir_all(&source_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_publish_dataset_requires_token() {
+        let (base_url, requests) = stub_server::start(handle_publish);
+
+        let result = client_for(&base_url)
+            .publish_dataset(&env::temp_dir(), &Artifact::new(ArtifactType::Dataset), &dataset_metadata(), &publish_request(Vec::new()))
+            .await;
+
+        assert!(matches!(result, Err(ClientError::BadRequest { .. })));
+        assert_eq!(requests.try_iter().count(), 0);
+    }
+}
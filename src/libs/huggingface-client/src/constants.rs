@@ -1 +1,14 @@
-pub const HUGGING_FACE_BASE_URL: &str = "https://huggingface.co";
\ No newline at end of file
+pub const HUGGING_FACE_BASE_URL: &str = "https://huggingface.co";
+
+/// Env var overriding the base url of the Hugging Face hub (ex. an internal
+/// mirror). Follows the convention of the huggingface_hub python library
+pub const HUGGING_FACE_ENDPOINT_ENV_VAR: &str = "HF_ENDPOINT";
+
+/// Revision downloaded when an ingestion does not specify a branch
+pub const DEFAULT_REVISION: &str = "main";
+
+/// Commit message used when a model publication does not specify one
+pub const DEFAULT_COMMIT_MESSAGE: &str = "MLHub HuggingFace Client: publish model";
+
+/// Commit message used when a dataset publication does not specify one
+pub const DEFAULT_DATASET_COMMIT_MESSAGE: &str = "MLHub HuggingFace Client: publish dataset";
\ No newline at end of file
@@ -0,0 +1,120 @@
+use shared::domain::entities::dataset_metadata::DatasetMetadata;
+use crate::model_card::{push_list, yaml_string};
+
+/// Builds a Hugging Face dataset card (README.md) from the metadata of a
+/// dataset. The yaml front matter contains the fields the Hub indexes, and the
+/// body describes the structure and provenance of the dataset
+pub fn build_dataset_card(metadata: &DatasetMetadata) -> String {
+    let mut front_matter: Vec<String> = Vec::new();
+
+    if let Some(name) = &metadata.name {
+        front_matter.push(format!("pretty_name: {}", yaml_string(name)));
+    }
+
+    if let Some(license) = &metadata.license {
+        front_matter.push(format!("license: {}", yaml_string(license)));
+    }
+
+    if let Some(tasks) = &metadata.task_types {
+        push_list(&mut front_matter, "task_categories", tasks);
+    }
+
+    if let Some(labels) = &metadata.labels {
+        push_list(&mut front_matter, "tags", labels);
+    }
+
+    let source_id = metadata.provenance.as_ref()
+        .and_then(|provenance| provenance.source_id.clone());
+    if let Some(source_id) = &source_id {
+        push_list(&mut front_matter, "source_datasets", &[source_id.clone()]);
+    }
+
+    let mut card = String::new();
+    if !front_matter.is_empty() {
+        card.push_str(&format!("---\n{}\n---\n\n", front_matter.join("\n")));
+    }
+
+    card.push_str(&format!("# {}\n", metadata.name.as_deref().unwrap_or("Dataset")));
+
+    if let Some(description) = &metadata.description {
+        card.push_str(&format!("\n{}\n", description));
+    }
+
+    let details: Vec<(&str, Option<String>)> = vec![
+        ("Version", metadata.version.clone()),
+        ("Format", metadata.format.clone()),
+        ("Modalities", metadata.modalities.as_ref().map(|modalities| modalities.join(", "))),
+        ("Tasks", metadata.task_types.as_ref().map(|tasks| tasks.join(", "))),
+        ("Rows", metadata.num_rows.map(|rows| rows.to_string())),
+        ("Size (bytes)", metadata.size_bytes.map(|size| size.to_string())),
+        ("License", metadata.license.clone()),
+        ("Regulatory standards", metadata.regulatory.as_ref().map(|standards| standards.join(", "))),
+    ];
+
+    let details: Vec<String> = details.into_iter()
+        .filter_map(|(label, value)| value.map(|value| format!("- **{}:** {}", label, value)))
+        .collect();
+
+    if !details.is_empty() {
+        card.push_str(&format!("\n## Dataset details\n\n{}\n", details.join("\n")));
+    }
+
+    if let Some(splits) = metadata.splits.as_ref().filter(|splits| !splits.is_empty()) {
+        card.push_str("\n## Splits\n\n| Name | Rows | Size (bytes) |\n| --- | --- | --- |\n");
+        for split in splits {
+            card.push_str(&format!(
+                "| {} | {} | {} |\n",
+                split.name,
+                optional_cell(split.num_rows),
+                optional_cell(split.num_bytes),
+            ));
+        }
+    }
+
+    if let Some(schema) = metadata.schema.as_ref().filter(|schema| !schema.is_empty()) {
+        card.push_str("\n## Schema\n\n| Name | Type | Nullable | Description |\n| --- | --- | --- | --- |\n");
+        for feature in schema {
+            card.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                feature.name,
+                optional_cell(feature.data_type.as_ref()),
+                optional_cell(feature.nullable),
+                optional_cell(feature.description.as_ref()),
+            ));
+        }
+    }
+
+    if let Some(provenance) = &metadata.provenance {
+        let sources: Vec<(&str, Option<String>)> = vec![
+            ("Source platform", provenance.source_platform.clone()),
+            ("Source", provenance.source_url.clone().or(source_id)),
+            ("Revision", provenance.revision.clone()),
+            ("Creators", provenance.creators.as_ref().map(|creators| creators.join(", "))),
+            ("Collection method", provenance.collection_method.clone()),
+        ];
+
+        let sources: Vec<String> = sources.into_iter()
+            .filter_map(|(label, value)| value.map(|value| format!("- **{}:** {}", label, value)))
+            .collect();
+
+        if !sources.is_empty() {
+            card.push_str(&format!("\n## Provenance\n\n{}\n", sources.join("\n")));
+        }
+
+        if let Some(citation) = &provenance.citation {
+            card.push_str(&format!("\n## Citation\n\n```\n{}\n```\n", citation));
+        }
+    }
+
+    card
+}
+
+/// Table cells cannot be empty, so missing values are rendered as a dash
+fn optional_cell<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or(String::from("-"))
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "dataset_card.test.rs"]
+mod dataset_card_test;
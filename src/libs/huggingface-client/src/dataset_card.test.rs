@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod dataset_card_test {
+    use shared::domain::entities::dataset_metadata::{
+        DatasetFeature, DatasetMetadata, DatasetProvenance, DatasetSplit,
+    };
+    use crate::dataset_card::build_dataset_card;
+
+    fn metadata() -> DatasetMetadata {
+        DatasetMetadata {
+            name: Some(String::from("org/corpus")),
+            version: Some(String::from("2.0.0")),
+            description: Some(String::from("A corpus of reviews")),
+            modalities: Some(vec![String::from("text")]),
+            task_types: Some(vec![String::from("text-classification")]),
+            format: Some(String::from("parquet")),
+            labels: Some(vec![String::from("reviews")]),
+            schema: Some(vec![DatasetFeature {
+                name: String::from("text"),
+                data_type: Some(String::from("string")),
+                description: None,
+                nullable: Some(false),
+            }]),
+            splits: Some(vec![DatasetSplit {
+                name: String::from("train"),
+                num_rows: Some(1000),
+                num_bytes: None,
+            }]),
+            num_rows: Some(1000),
+            size_bytes: None,
+            provenance: Some(DatasetProvenance {
+                source_platform: Some(String::from("huggingface")),
+                source_id: Some(String::from("upstream/corpus")),
+                source_url: None,
+                revision: None,
+                creators: None,
+                citation: None,
+                collection_method: None,
+            }),
+            license: Some(String::from("cc-by-4.0")),
+            regulatory: None,
+        }
+    }
+
+    #[test]
+    fn test_build_dataset_card() {
+        let card = build_dataset_card(&metadata());
+
+        assert!(card.starts_with("---\npretty_name: \"org/corpus\"\nlicense: \"cc-by-4.0\"\ntask_categories:\n- \"text-classification\"\n"));
+        assert!(card.contains("source_datasets:\n- \"upstream/corpus\"\n---\n"));
+        assert!(card.contains("# org/corpus\n\nA corpus of reviews\n"));
+        assert!(card.contains("- **Format:** parquet\n"));
+        assert!(card.contains("| train | 1000 | - |\n"));
+        assert!(card.contains("| text | string | false | - |\n"));
+        assert!(card.contains("- **Source:** upstream/corpus\n"));
+        assert!(!card.contains("## Citation"));
+    }
+}
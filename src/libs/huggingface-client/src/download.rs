@@ -0,0 +1,363 @@
+use crate::responses::{RepoAccess, RepoRevision, RepoTreeEntry};
+use crate::utils::{deserialize_response_body, encode_path, encode_revision, is_path_selected, parse_next_cursor, response_error};
+use clients::{ClientError, ClientErrorScope};
+use reqwest::header::{AUTHORIZATION, RANGE};
+use reqwest::{Client as ReqwestClient, RequestBuilder, Response, StatusCode};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use shared::logging::SharedLogger;
+use std::io::SeekFrom;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+/// How many times the download of a file is attempted when its stream is
+/// interrupted
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 3;
+
+/// How the files of a repository are downloaded during ingestion
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadMethod {
+    /// Clone the repository and pull its large files with git and git lfs
+    Git,
+    /// Download each file over the Hub http api. Does not require git
+    Http,
+}
+
+impl FromStr for DownloadMethod {
+    type Err = ClientError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "git" => Ok(DownloadMethod::Git),
+            "http" => Ok(DownloadMethod::Http),
+            _ => Err(ClientError::BadRequest {
+                msg: format!("Invalid download method '{}'. Expected 'git' or 'http'", value),
+                scope: ClientErrorScope::Client,
+            })
+        }
+    }
+}
+
+/// The type of a Hugging Face repository. Each type of repository is served
+/// under a different path of the Hub
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RepoType {
+    Model,
+    Dataset,
+}
+
+impl RepoType {
+    pub(crate) fn api_prefix(&self) -> &str {
+        match self {
+            RepoType::Model => "models",
+            RepoType::Dataset => "datasets",
+        }
+    }
+
+    /// The name of the repo type used by the repo creation api
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            RepoType::Model => "model",
+            RepoType::Dataset => "dataset",
+        }
+    }
+
+    pub(crate) fn resolve_prefix(&self) -> &str {
+        match self {
+            RepoType::Model => "",
+            RepoType::Dataset => "datasets/",
+        }
+    }
+}
+
+/// Downloads the files of a Hugging Face repository over the Hub http api
+pub(crate) struct HubDownloader<'a> {
+    pub client: &'a ReqwestClient,
+    pub base_url: &'a str,
+    pub access_token: Option<String>,
+    pub logger: &'a SharedLogger,
+}
+
+/// The repository files to download
+pub(crate) struct DownloadRepoParams<'a> {
+    pub repo_type: RepoType,
+    pub repo_id: &'a str,
+    /// Branch, tag or commit sha
+    pub revision: &'a str,
+    pub target_dir: &'a Path,
+    pub include_paths: &'a [String],
+    pub exclude_paths: &'a [String],
+}
+
+impl<'a> HubDownloader<'a> {
+    /// Downloads every file of the repository that is selected by the include
+    /// and exclude patterns into the target directory
+    pub async fn download_repo(&self, params: DownloadRepoParams<'_>) -> Result<(), ClientError> {
+        let entries = self.list_tree(&params).await?;
+
+        for entry in entries {
+            if !entry.is_file() || !is_path_selected(&entry.path, params.include_paths, params.exclude_paths) {
+                continue
+            }
+
+            self.download_file(&params, &entry).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the access settings of a repository. Fails if the repository
+    /// does not exist or is private and the token cannot access it
+    pub async fn repo_access(&self, repo_type: RepoType, repo_id: &str) -> Result<RepoAccess, ClientError> {
+        let url = format!("{}/api/{}/{}", self.base_url, repo_type.api_prefix(), repo_id);
+
+        let response = self.send(self.authorize(self.client.get(&url))).await?;
+
+        deserialize_response_body(response).await
+    }
+
+    /// Resolves a branch, tag or commit of a repository to the sha of the
+    /// commit it points to
+    pub async fn resolve_revision(&self, repo_type: RepoType, repo_id: &str, revision: &str) -> Result<String, ClientError> {
+        let url = format!(
+            "{}/api/{}/{}/revision/{}",
+            self.base_url,
+            repo_type.api_prefix(),
+            repo_id,
+            encode_revision(revision)
+        );
+
+        let response = self.send(self.authorize(self.client.get(&url))).await?;
+        let info: RepoRevision = deserialize_response_body(response).await?;
+
+        Ok(info.sha)
+    }
+
+    /// Lists every entry of the repository tree, following the pagination
+    /// cursors returned by the Hub
+    async fn list_tree(&self, params: &DownloadRepoParams<'_>) -> Result<Vec<RepoTreeEntry>, ClientError> {
+        let url = format!(
+            "{}/api/{}/{}/tree/{}",
+            self.base_url,
+            params.repo_type.api_prefix(),
+            params.repo_id,
+            encode_revision(params.revision)
+        );
+
+        let mut entries = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut query = vec![("recursive", String::from("true"))];
+            if let Some(cursor) = &cursor {
+                query.push(("cursor", cursor.clone()));
+            }
+
+            let response = self.send(self.authorize(self.client.get(&url).query(&query))).await?;
+            cursor = parse_next_cursor(response.headers());
+
+            let page: Vec<RepoTreeEntry> = deserialize_response_body(response).await?;
+            entries.extend(page);
+
+            if cursor.is_none() {
+                return Ok(entries)
+            }
+        }
+    }
+
+    /// Streams a file to the target directory. The file is written to a
+    /// ".incomplete" file first. If the stream is interrupted, the download is
+    /// resumed from the last byte received with a range request
+    async fn download_file(&self, params: &DownloadRepoParams<'_>, entry: &RepoTreeEntry) -> Result<(), ClientError> {
+        let target_path = safe_join(params.target_dir, &entry.path)?;
+        let size = entry.file_size();
+
+        // Skip files that were already downloaded
+        if is_downloaded(&target_path, entry).await? {
+            return Ok(())
+        }
+
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent).await.map_err(io_error)?;
+        }
+
+        let url = format!(
+            "{}/{}{}/resolve/{}/{}",
+            self.base_url,
+            params.repo_type.resolve_prefix(),
+            params.repo_id,
+            encode_revision(params.revision),
+            encode_path(&entry.path)
+        );
+
+        let partial_path = PathBuf::from(format!("{}.incomplete", target_path.to_string_lossy()));
+        let mut file = fs::File::create(&partial_path).await.map_err(io_error)?;
+        let mut offset: u64 = 0;
+        let mut attempt = 1;
+        loop {
+            let mut request = self.authorize(self.client.get(&url));
+            if offset > 0 {
+                request = request.header(RANGE, format!("bytes={}-", offset));
+            }
+
+            let mut response = self.send(request).await?;
+
+            // The server responds with the whole file if it does not support
+            // range requests, in which case the download starts over
+            if offset > 0 && response.status() != StatusCode::PARTIAL_CONTENT {
+                file.set_len(0).await.map_err(io_error)?;
+                file.seek(SeekFrom::Start(0)).await.map_err(io_error)?;
+                offset = 0;
+            }
+
+            let interrupted = loop {
+                match response.chunk().await {
+                    Ok(Some(chunk)) => {
+                        file.write_all(&chunk).await.map_err(io_error)?;
+                        offset += chunk.len() as u64;
+                    },
+                    Ok(None) => break None,
+                    Err(err) => break Some(err),
+                }
+            };
+
+            match interrupted {
+                None => break,
+                Some(err) if attempt < MAX_DOWNLOAD_ATTEMPTS => {
+                    self.logger.debug(format!("Download of '{}' was interrupted at byte {}: {}. Resuming", &entry.path, offset, err).as_str());
+                    attempt += 1;
+                },
+                Some(err) => return Err(request_error(err)),
+            }
+        }
+
+        file.flush().await.map_err(io_error)?;
+
+        if let Some(size) = size {
+            if offset != size {
+                return Err(ClientError::Internal {
+                    msg: format!("Incomplete download of '{}': expected {} bytes, received {}", &entry.path, size, offset),
+                    scope: ClientErrorScope::Server,
+                })
+            }
+        }
+
+        fs::rename(&partial_path, &target_path).await.map_err(io_error)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.access_token {
+            Some(token) => request.header(AUTHORIZATION, format!("Bearer {}", token)),
+            None => request
+        }
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        send_request(request, self.logger).await
+    }
+}
+
+/// Sends a request to the Hub and maps unsuccessful statuses to client errors
+pub(crate) async fn send_request(request: RequestBuilder, logger: &SharedLogger) -> Result<Response, ClientError> {
+    let response = request.send().await.map_err(request_error)?;
+
+    check_response_status(response, logger).await
+}
+
+/// Maps unsuccessful statuses of a Hub response to client errors
+pub(crate) async fn check_response_status(response: Response, logger: &SharedLogger) -> Result<Response, ClientError> {
+    if response.status().is_success() {
+        return Ok(response)
+    }
+
+    let err = response_error(response).await;
+    logger.error(err.to_string().as_str());
+
+    Err(err)
+}
+
+/// Whether the file on disk is the file in the repository tree. Large files
+/// are compared by their sha256 and other files by their git blob sha1. Files
+/// without a hash to compare against are always downloaded again
+async fn is_downloaded(target_path: &Path, entry: &RepoTreeEntry) -> Result<bool, ClientError> {
+    let metadata = match fs::metadata(target_path).await {
+        Ok(metadata) => metadata,
+        Err(_) => return Ok(false)
+    };
+
+    if entry.file_size() != Some(metadata.len()) {
+        return Ok(false)
+    }
+
+    match (&entry.lfs, &entry.oid) {
+        (Some(lfs), _) => Ok(sha256_file(target_path).await? == lfs.oid),
+        (None, Some(oid)) => Ok(git_blob_sha1(target_path, metadata.len()).await? == *oid),
+        (None, None) => Ok(false)
+    }
+}
+
+/// The hex encoded sha256 of a file
+pub(crate) async fn sha256_file(path: &Path) -> Result<String, ClientError> {
+    let mut hasher = Sha256::new();
+    hash_file(path, |chunk| hasher.update(chunk)).await?;
+
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// The object id git gives a file, the sha1 of a "blob <size>\0" header
+/// followed by the contents
+async fn git_blob_sha1(path: &Path, size: u64) -> Result<String, ClientError> {
+    let mut hasher = Sha1::new();
+    hasher.update(format!("blob {}\0", size).as_bytes());
+    hash_file(path, |chunk| hasher.update(chunk)).await?;
+
+    Ok(hex::encode(hasher.finalize()))
+}
+
+async fn hash_file(path: &Path, mut update: impl FnMut(&[u8])) -> Result<(), ClientError> {
+    let mut file = fs::File::open(path).await.map_err(io_error)?;
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer).await.map_err(io_error)?;
+        if read == 0 {
+            return Ok(())
+        }
+        update(&buffer[..read]);
+    }
+}
+
+/// Joins a repository path onto the target directory. Paths that could escape
+/// the target directory are rejected
+fn safe_join(target_dir: &Path, path: &str) -> Result<PathBuf, ClientError> {
+    let relative = Path::new(path);
+    let is_safe = relative.components()
+        .all(|component| matches!(component, Component::Normal(_)));
+
+    if !is_safe {
+        return Err(ClientError::Internal {
+            msg: format!("Refusing to download file with unsafe path '{}'", path),
+            scope: ClientErrorScope::Server,
+        })
+    }
+
+    Ok(target_dir.join(relative))
+}
+
+pub(crate) fn io_error(err: std::io::Error) -> ClientError {
+    ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client }
+}
+
+pub(crate) fn request_error(err: reqwest::Error) -> ClientError {
+    if err.is_connect() || err.is_timeout() {
+        return ClientError::Unavailable(err.to_string())
+    }
+
+    ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "download.test.rs"]
+mod download_test;
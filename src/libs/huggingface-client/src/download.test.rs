@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod download_test {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+    use clients::ClientError;
+    use reqwest::Client as ReqwestClient;
+    use serde_json::json;
+    use shared::logging::SharedLogger;
+    use crate::download::{DownloadMethod, DownloadRepoParams, HubDownloader, RepoType};
+    use test_support::stub_server::{self, StubRequest, StubResponse};
+
+    const WEIGHTS: &[u8] = b"0123456789abcdefghij";
+
+    /// The sha256 of the weights
+    const WEIGHTS_SHA256: &str = "6bc14bdc4517a7a682c6910de2e2946eb8e1ecd04090728fef6d092a7ceb62c5";
+
+    /// The git blob sha1 of the config
+    const CONFIG_OID: &str = "9e26dfeeb6e641a33dae4961196235bdb965b21b";
+
+    fn target_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hf-download-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Serves a repository containing a config, lfs weights and a readme. The
+    /// weights support range requests
+    fn handle(request: &StubRequest) -> StubResponse {
+        let path = request.path();
+
+        if path.starts_with("/api/models/org/model/tree/main") {
+            return StubResponse::json(json!([
+                { "type": "file", "path": "config.json", "size": 2, "oid": CONFIG_OID },
+                { "type": "directory", "path": "weights", "oid": "b" },
+                {
+                    "type": "file",
+                    "path": "weights/model.safetensors",
+                    "size": 20,
+                    "oid": "c",
+                    "lfs": { "oid": WEIGHTS_SHA256, "size": WEIGHTS.len(), "pointerSize": 130 }
+                },
+                { "type": "file", "path": "README.md", "size": 6, "oid": "e" }
+            ]).to_string())
+        }
+
+        let body: &[u8] = match path {
+            "/org/model/resolve/main/config.json" => b"{}",
+            "/org/model/resolve/main/README.md" => b"readme",
+            "/org/model/resolve/main/weights/model.safetensors" => WEIGHTS,
+            _ => return StubResponse { status: 404, headers: Vec::new(), body: Vec::new() }
+        };
+
+        let offset = request.header("range")
+            .and_then(|range| range.strip_prefix("bytes="))
+            .and_then(|range| range.trim_end_matches("-").parse::<usize>().ok());
+
+        match offset {
+            Some(offset) => StubResponse {
+                status: 206,
+                headers: vec![(
+                    String::from("Content-Range"),
+                    format!("bytes {}-{}/{}", offset, body.len() - 1, body.len())
+                )],
+                body: body[offset..].to_vec(),
+            },
+            None => StubResponse { status: 200, headers: Vec::new(), body: body.to_vec() }
+        }
+    }
+
+    async fn download(base_url: &str, target_dir: &PathBuf, include_paths: &[String], exclude_paths: &[String]) {
+        let client = ReqwestClient::new();
+        let logger = SharedLogger::new();
+        let downloader = HubDownloader {
+            client: &client,
+            base_url,
+            access_token: Some(String::from("hf_token")),
+            logger: &logger,
+        };
+
+        downloader.download_repo(DownloadRepoParams {
+            repo_type: RepoType::Model,
+            repo_id: "org/model",
+            revision: "main",
+            target_dir,
+            include_paths,
+            exclude_paths,
+        })
+            .await
+            .expect("Download failed");
+    }
+
+    #[test]
+    fn test_download_method_from_str() {
+        assert_eq!(DownloadMethod::from_str("HTTP").unwrap(), DownloadMethod::Http);
+        assert_eq!(DownloadMethod::from_str("git").unwrap(), DownloadMethod::Git);
+        assert!(DownloadMethod::from_str("ftp").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_download_repo_applies_globs() {
+        let (base_url, requests) = stub_server::start(handle);
+        let dir = target_dir("globs");
+
+        download(&base_url, &dir, &[String::from("*.json"), String::from("weights/")], &[String::from("README.md")]).await;
+
+        assert_eq!(fs::read(dir.join("config.json")).unwrap(), b"{}");
+        assert_eq!(fs::read(dir.join("weights/model.safetensors")).unwrap(), WEIGHTS);
+        assert!(!dir.join("README.md").exists());
+
+        let requests: Vec<StubRequest> = requests.try_iter().collect();
+        assert!(requests.iter().all(|request| request.header("authorization") == Some("Bearer hf_token")));
+        assert!(!requests.iter().any(|request| request.path().ends_with("README.md")));
+    }
+
+    #[tokio::test]
+    async fn test_download_repo_resumes_interrupted_streams() {
+        // The connection drops after the first 8 bytes of the weights
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            if request.path().ends_with("model.safetensors") && request.header("range").is_none() {
+                return StubResponse {
+                    status: 200,
+                    headers: vec![(String::from("Content-Length"), WEIGHTS.len().to_string())],
+                    body: WEIGHTS[..8].to_vec(),
+                }
+            }
+            handle(request)
+        });
+        let dir = target_dir("resume");
+
+        // A partial file left behind by an earlier download is not reused
+        fs::create_dir_all(dir.join("weights")).unwrap();
+        fs::write(dir.join("weights/model.safetensors.incomplete"), b"stale").unwrap();
+
+        download(&base_url, &dir, &[String::from("weights/**")], &[]).await;
+
+        assert_eq!(fs::read(dir.join("weights/model.safetensors")).unwrap(), WEIGHTS);
+        assert!(!dir.join("weights/model.safetensors.incomplete").exists());
+
+        let ranges: Vec<Option<String>> = requests.try_iter()
+            .filter(|request| request.path().ends_with("model.safetensors"))
+            .map(|request| request.header("range").map(String::from))
+            .collect();
+        assert_eq!(ranges, vec![None, Some(String::from("bytes=8-"))]);
+    }
+
+    #[tokio::test]
+    async fn test_download_repo_skips_files_with_matching_hash() {
+        let (base_url, requests) = stub_server::start(handle);
+        let dir = target_dir("skip");
+
+        // The config is already downloaded while the weights on disk have the
+        // right size but different contents
+        fs::create_dir_all(dir.join("weights")).unwrap();
+        fs::write(dir.join("config.json"), b"{}").unwrap();
+        fs::write(dir.join("weights/model.safetensors"), b"jihgfedcba9876543210").unwrap();
+
+        download(&base_url, &dir, &[String::from("*.json"), String::from("weights/")], &[]).await;
+
+        assert_eq!(fs::read(dir.join("weights/model.safetensors")).unwrap(), WEIGHTS);
+
+        let downloaded: Vec<String> = requests.try_iter()
+            .map(|request| request.path().to_string())
+            .filter(|path| path.contains("/resolve/"))
+            .collect();
+        assert_eq!(downloaded, vec![String::from("/org/model/resolve/main/weights/model.safetensors")]);
+    }
+
+    #[tokio::test]
+    async fn test_download_repo_encodes_revision_and_paths() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            match request.path() {
+                path if path.starts_with("/api/models/org/model/tree/refs%2Fpr%2F1") => StubResponse::json(json!([
+                    { "type": "file", "path": "docs/read me#1.md", "size": 6 }
+                ]).to_string()),
+                "/org/model/resolve/refs%2Fpr%2F1/docs/read%20me%231.md" => StubResponse {
+                    status: 200,
+                    headers: Vec::new(),
+                    body: b"readme".to_vec(),
+                },
+                _ => StubResponse { status: 404, headers: Vec::new(), body: Vec::new() }
+            }
+        });
+        let dir = target_dir("encoding");
+
+        let client = ReqwestClient::new();
+        let logger = SharedLogger::new();
+        HubDownloader { client: &client, base_url: &base_url, access_token: None, logger: &logger }
+            .download_repo(DownloadRepoParams {
+                repo_type: RepoType::Model,
+                repo_id: "org/model",
+                revision: "refs/pr/1",
+                target_dir: &dir,
+                include_paths: &[],
+                exclude_paths: &[],
+            })
+            .await
+            .expect("Download failed");
+
+        assert_eq!(fs::read(dir.join("docs/read me#1.md")).unwrap(), b"readme");
+        assert_eq!(requests.try_iter().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_revision() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            match request.path() {
+                "/api/datasets/org/data/revision/refs%2Fpr%2F1" => StubResponse::json(json!({
+                    "id": "org/data",
+                    "sha": "0123456789abcdef0123456789abcdef01234567"
+                }).to_string()),
+                _ => StubResponse { status: 404, headers: Vec::new(), body: Vec::new() }
+            }
+        });
+
+        let client = ReqwestClient::new();
+        let logger = SharedLogger::new();
+        let downloader = HubDownloader { client: &client, base_url: &base_url, access_token: None, logger: &logger };
+
+        let sha = downloader.resolve_revision(RepoType::Dataset, "org/data", "refs/pr/1").await.unwrap();
+        assert_eq!(sha, "0123456789abcdef0123456789abcdef01234567");
+
+        let missing = downloader.resolve_revision(RepoType::Dataset, "org/data", "missing").await;
+        assert!(matches!(missing, Err(ClientError::NotFound { .. })));
+        assert_eq!(requests.try_iter().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_download_repo_rejects_unsafe_paths() {
+        let (base_url, _requests) = stub_server::start(|request: &StubRequest| {
+            if request.path().starts_with("/api/models/org/model/tree/main") {
+                return StubResponse::json(json!([
+                    { "type": "file", "path": "../escaped.txt", "size": 1 }
+                ]).to_string())
+            }
+
+            StubResponse { status: 200, headers: Vec::new(), body: b"x".to_vec() }
+        });
+        let dir = target_dir("unsafe");
+
+        let client = ReqwestClient::new();
+        let logger = SharedLogger::new();
+        let result = HubDownloader { client: &client, base_url: &base_url, access_token: None, logger: &logger }
+            .download_repo(DownloadRepoParams {
+                repo_type: RepoType::Model,
+                repo_id: "org/model",
+                revision: "main",
+                target_dir: &dir,
+                include_paths: &[],
+                exclude_paths: &[],
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert!(!dir.parent().unwrap().join("escaped.txt").exists());
+    }
+}
@@ -1,4 +1,9 @@
 pub mod client;
 pub mod constants;
+pub mod dataset_card;
+pub mod download;
+pub mod model_card;
 pub mod requests;
-pub(crate) mod utils;
\ No newline at end of file
+pub mod responses;
+pub mod upload;
+pub(crate) mod utils;
@@ -0,0 +1,90 @@
+use shared::domain::entities::model_metadata::ModelMetadata;
+
+/// Builds a Hugging Face model card (README.md) from the metadata of a model.
+/// The yaml front matter contains the fields the Hub indexes, and the body
+/// summarizes the rest of the metadata
+pub fn build_model_card(metadata: &ModelMetadata) -> String {
+    let mut front_matter: Vec<String> = Vec::new();
+
+    if let Some(license) = &metadata.license {
+        front_matter.push(format!("license: {}", yaml_string(license)));
+    }
+
+    if let Some(framework) = &metadata.framework {
+        front_matter.push(format!("library_name: {}", yaml_string(framework)));
+    }
+
+    if let Some(task) = metadata.task_types.as_ref().and_then(|tasks| tasks.first()) {
+        front_matter.push(format!("pipeline_tag: {}", yaml_string(task)));
+    }
+
+    if let Some(labels) = &metadata.labels {
+        push_list(&mut front_matter, "tags", labels);
+    }
+
+    let datasets: Vec<String> = metadata.pretraining_datasets.iter()
+        .chain(metadata.finetuning_datasets.iter())
+        .flatten()
+        .cloned()
+        .collect();
+    push_list(&mut front_matter, "datasets", &datasets);
+
+    let mut card = String::new();
+    if !front_matter.is_empty() {
+        card.push_str(&format!("---\n{}\n---\n\n", front_matter.join("\n")));
+    }
+
+    card.push_str(&format!("# {}\n", metadata.name.as_deref().unwrap_or("Model")));
+
+    let details: Vec<(&str, Option<String>)> = vec![
+        ("Version", metadata.version.clone()),
+        ("Model type", metadata.model_type.clone()),
+        ("Framework", metadata.framework.clone()),
+        ("Tasks", metadata.task_types.as_ref().map(|tasks| tasks.join(", "))),
+        ("License", metadata.license.clone()),
+        ("Pretrained", metadata.pretrained.map(|value| value.to_string())),
+        ("Inference precision", metadata.inference_precision.clone()),
+        ("Training precision", metadata.training_precision.clone()),
+        ("Regulatory standards", metadata.regulatory.as_ref().map(|standards| standards.join(", "))),
+    ];
+
+    let details: Vec<String> = details.into_iter()
+        .filter_map(|(label, value)| value.map(|value| format!("- **{}:** {}", label, value)))
+        .collect();
+
+    if !details.is_empty() {
+        card.push_str(&format!("\n## Model details\n\n{}\n", details.join("\n")));
+    }
+
+    if !datasets.is_empty() {
+        card.push_str(&format!("\n## Training data\n\n{}\n", datasets.iter()
+            .map(|dataset| format!("- {}", dataset))
+            .collect::<Vec<String>>()
+            .join("\n")
+        ));
+    }
+
+    card
+}
+
+pub(crate) fn push_list(front_matter: &mut Vec<String>, key: &str, values: &[String]) {
+    if values.is_empty() {
+        return
+    }
+
+    front_matter.push(format!("{}:", key));
+    for value in values {
+        front_matter.push(format!("- {}", yaml_string(value)));
+    }
+}
+
+/// Json strings are valid yaml strings, so quoting a value as json escapes any
+/// characters that would otherwise break the front matter
+pub(crate) fn yaml_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| String::from("\"\""))
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "model_card.test.rs"]
+mod model_card_test;
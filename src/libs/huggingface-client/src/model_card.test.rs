@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod model_card_test {
+    use shared::domain::entities::model_metadata::ModelMetadata;
+    use crate::model_card::build_model_card;
+
+    fn metadata() -> ModelMetadata {
+        ModelMetadata {
+            name: Some(String::from("org/model")),
+            model_type: None,
+            version: Some(String::from("1.0.0")),
+            framework: Some(String::from("transformers")),
+            image: None,
+            labels: Some(vec![String::from("nlp"), String::from("quote: \"x\"")]),
+            label_map: None,
+            multi_modal: None,
+            model_inputs: None,
+            model_outputs: None,
+            task_types: Some(vec![String::from("text-classification")]),
+            inference_precision: None,
+            inference_hardware: None,
+            inference_software_dependencies: None,
+            inference_max_energy_consumption_watts: None,
+            inference_max_latency_ms: None,
+            inference_min_throughput: None,
+            inference_max_compute_utilization_percentage: None,
+            inference_max_memory_usage_mb: None,
+            inference_distributed: None,
+            training_time: None,
+            training_precision: None,
+            training_hardware: None,
+            pretraining_datasets: Some(vec![String::from("org/corpus")]),
+            finetuning_datasets: None,
+            edge_optimized: None,
+            quantization_aware: None,
+            supports_quantization: None,
+            pretrained: None,
+            pruned: None,
+            slimmed: None,
+            training_distributed: None,
+            training_max_energy_consumption_watts: None,
+            regulatory: None,
+            license: Some(String::from("apache-2.0")),
+            bias_evaluation_score: None,
+        }
+    }
+
+    #[test]
+    fn test_build_model_card() {
+        let card = build_model_card(&metadata());
+
+        assert!(card.starts_with("---\nlicense: \"apache-2.0\"\nlibrary_name: \"transformers\"\npipeline_tag: \"text-classification\"\n"));
+        assert!(card.contains("tags:\n- \"nlp\"\n- \"quote: \\\"x\\\"\"\n"));
+        assert!(card.contains("datasets:\n- \"org/corpus\"\n---\n"));
+        assert!(card.contains("# org/model\n"));
+        assert!(card.contains("- **Version:** 1.0.0\n"));
+        assert!(card.contains("## Training data\n\n- org/corpus\n"));
+    }
+
+    #[test]
+    fn test_build_model_card_without_front_matter() {
+        let mut metadata = metadata();
+        metadata.license = None;
+        metadata.framework = None;
+        metadata.task_types = None;
+        metadata.labels = None;
+        metadata.pretraining_datasets = None;
+
+        let card = build_model_card(&metadata);
+
+        assert!(card.starts_with("# org/model\n"));
+        assert!(!card.contains("## Training data"));
+    }
+}
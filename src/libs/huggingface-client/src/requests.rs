@@ -10,6 +10,8 @@ pub struct ListModelsQueryParameters {
     pub limit: Option<i64>,
     pub full: Option<bool>,
     pub config: Option<bool>,
+    /// Opaque cursor from the "next" link of a previous page
+    pub cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -35,7 +37,9 @@ pub struct ListDatasetsQueryParameters {
     pub sort: Option<String>,
     pub direction: Option<String>,
     pub limit: Option<u64>,
-    pub full: Option<bool>
+    pub full: Option<bool>,
+    /// Opaque cursor from the "next" link of a previous page
+    pub cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use shared::presentation::http::v1::responses::ModelSummary;
+
+/// A file in a Hugging Face repository
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Sibling {
+    /// Path of the file relative to the root of the repository
+    pub rfilename: String,
+    pub size: Option<u64>,
+    pub blob_id: Option<String>,
+}
+
+/// A model as returned by the Hugging Face models api
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelInfo {
+    pub id: String,
+    pub model_id: Option<String>,
+    pub author: Option<String>,
+    /// Commit sha of the revision that was fetched
+    pub sha: Option<String>,
+    pub created_at: Option<String>,
+    pub last_modified: Option<String>,
+    pub private: Option<bool>,
+    /// Either false or the gating mode of the repository ("auto", "manual")
+    pub gated: Option<Value>,
+    pub disabled: Option<bool>,
+    pub downloads: Option<u64>,
+    pub likes: Option<u64>,
+    #[serde(rename = "library_name")]
+    pub library_name: Option<String>,
+    #[serde(rename = "pipeline_tag")]
+    pub pipeline_tag: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub siblings: Vec<Sibling>,
+    /// The metadata in the yaml header of the model card
+    pub card_data: Option<Value>,
+}
+
+/// A dataset as returned by the Hugging Face datasets api
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetInfo {
+    pub id: String,
+    pub author: Option<String>,
+    /// Commit sha of the revision that was fetched
+    pub sha: Option<String>,
+    pub created_at: Option<String>,
+    pub last_modified: Option<String>,
+    pub private: Option<bool>,
+    /// Either false or the gating mode of the repository ("auto", "manual")
+    pub gated: Option<Value>,
+    pub disabled: Option<bool>,
+    pub downloads: Option<u64>,
+    pub likes: Option<u64>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub siblings: Vec<Sibling>,
+    /// The metadata in the yaml header of the dataset card
+    pub card_data: Option<Value>,
+    /// Fields returned by the Hub that are not modelled above. Ex. cardData
+    /// extensions or fields added to the api after this struct was written
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// The access settings of a repository
+#[derive(Deserialize, Debug, Clone)]
+pub struct RepoAccess {
+    pub private: Option<bool>,
+    /// Either false or the gating mode of the repository ("auto", "manual")
+    pub gated: Option<Value>,
+}
+
+impl RepoAccess {
+    /// Whether users must request access to the repository before they can
+    /// download its files
+    pub fn is_gated(&self) -> bool {
+        match &self.gated {
+            Some(Value::Bool(gated)) => *gated,
+            Some(Value::String(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A revision of a repository. Returned by the Hub when resolving a branch,
+/// tag or commit
+#[derive(Deserialize, Debug, Clone)]
+pub struct RepoRevision {
+    /// Commit sha the revision points to
+    pub sha: String,
+}
+
+/// The branches and tags of a repository
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RepoRefs {
+    #[serde(default)]
+    pub tags: Vec<GitRef>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GitRef {
+    pub name: String,
+    /// Commit sha the ref points to
+    pub target_commit: String,
+}
+
+/// Large file storage info of a file in a repository tree
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LfsInfo {
+    pub oid: String,
+    pub size: u64,
+    pub pointer_size: Option<u64>,
+}
+
+/// An entry returned by the Hugging Face repository tree api
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RepoTreeEntry {
+    /// Either "file" or "directory"
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    /// Path of the entry relative to the root of the repository
+    pub path: String,
+    pub oid: Option<String>,
+    pub size: Option<u64>,
+    pub lfs: Option<LfsInfo>,
+}
+
+impl RepoTreeEntry {
+    pub fn is_file(&self) -> bool {
+        self.entry_type == "file"
+    }
+
+    /// The size of the file's contents. For files stored in lfs this is the
+    /// size of the large file rather than the size of its pointer
+    pub fn file_size(&self) -> Option<u64> {
+        self.lfs.as_ref()
+            .map(|lfs| lfs.size)
+            .or(self.size)
+    }
+}
+
+impl ModelInfo {
+    /// The license from the model card metadata, falling back to the
+    /// "license:<name>" tag Hugging Face adds to licensed models
+    pub fn license(&self) -> Option<String> {
+        self.card_data.as_ref()
+            .and_then(|card_data| card_data.get("license"))
+            .and_then(|license| license.as_str())
+            .map(String::from)
+            .or_else(|| self.tags.iter()
+                .find_map(|tag| tag.strip_prefix("license:"))
+                .map(String::from))
+    }
+
+    /// Maps the model into the summary shared by every platform. The platform
+    /// is the name the client is registered under, which differs from
+    /// "huggingface" for mirrors of the Hub
+    pub fn into_summary(self, platform: &str) -> ModelSummary {
+        let license = self.license();
+
+        // Model ids are of the form "<author>/<name>" except for some legacy
+        // models that have no author
+        let (author, name) = match self.id.split_once("/") {
+            Some((author, name)) => (Some(String::from(author)), String::from(name)),
+            None => (None, self.id.clone()),
+        };
+
+        ModelSummary {
+            platform: String::from(platform),
+            name,
+            author: self.author.or(author),
+            description: self.card_data.as_ref()
+                .and_then(|card_data| card_data.get("description"))
+                .and_then(|description| description.as_str())
+                .map(String::from),
+            version: self.sha,
+            license,
+            task: self.pipeline_tag,
+            framework: self.library_name,
+            tags: self.tags,
+            downloads: self.downloads,
+            likes: self.likes,
+            created_at: self.created_at,
+            last_modified: self.last_modified,
+            files: self.siblings.into_iter()
+                .map(|sibling| sibling.rfilename)
+                .collect(),
+            id: self.id,
+        }
+    }
+}
+
+/// How a file is uploaded to the Hub, as decided by the preupload endpoint
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadMode {
+    /// Uploaded to lfs storage and referenced by its sha256 in the commit
+    Lfs,
+    /// Sent inline in the commit
+    Regular,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PreuploadFile {
+    pub path: String,
+    pub upload_mode: UploadMode,
+    /// Files ignored by the repo's .gitignore
+    #[serde(default)]
+    pub should_ignore: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PreuploadResponse {
+    pub files: Vec<PreuploadFile>,
+}
+
+/// A request to make to upload or verify an lfs object
+#[derive(Deserialize, Debug, Clone)]
+pub struct LfsAction {
+    pub href: String,
+    #[serde(default)]
+    pub header: HashMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct LfsActions {
+    pub upload: Option<LfsAction>,
+    pub verify: Option<LfsAction>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LfsObjectError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LfsBatchObject {
+    pub oid: String,
+    pub size: u64,
+    /// Missing when the object is already stored on the Hub
+    pub actions: Option<LfsActions>,
+    pub error: Option<LfsObjectError>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LfsBatchResponse {
+    pub objects: Vec<LfsBatchObject>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitInfo {
+    pub commit_oid: String,
+    pub commit_url: Option<String>,
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "responses.test.rs"]
+mod responses_test;
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod responses_test {
+    use serde_json::{from_value, json, to_value};
+    use crate::responses::{DatasetInfo, ModelInfo};
+
+    #[test]
+    fn test_model_info_to_summary() {
+        let info: ModelInfo = from_value(json!({
+            "_id": "621ffdc036468d709f174338",
+            "id": "google-bert/bert-base-uncased",
+            "modelId": "google-bert/bert-base-uncased",
+            "sha": "86b5e0934494bd15c9632b12f734a8a67f723594",
+            "createdAt": "2022-03-02T23:29:04.000Z",
+            "lastModified": "2024-02-19T11:06:12.000Z",
+            "private": false,
+            "gated": false,
+            "downloads": 100,
+            "likes": 10,
+            "library_name": "transformers",
+            "pipeline_tag": "fill-mask",
+            "tags": ["transformers", "license:apache-2.0"],
+            "siblings": [{ "rfilename": "config.json" }, { "rfilename": "model.safetensors" }]
+        })).unwrap();
+
+        let summary = info.into_summary("huggingface");
+
+        assert_eq!(summary.id, "google-bert/bert-base-uncased");
+        assert_eq!(summary.platform, "huggingface");
+        assert_eq!(summary.name, "bert-base-uncased");
+        assert_eq!(summary.author, Some(String::from("google-bert")));
+        assert_eq!(summary.license, Some(String::from("apache-2.0")));
+        assert_eq!(summary.task, Some(String::from("fill-mask")));
+        assert_eq!(summary.framework, Some(String::from("transformers")));
+        assert_eq!(summary.version, Some(String::from("86b5e0934494bd15c9632b12f734a8a67f723594")));
+        assert_eq!(summary.files, vec![String::from("config.json"), String::from("model.safetensors")]);
+    }
+
+    #[test]
+    fn test_model_info_minimal() {
+        let info: ModelInfo = from_value(json!({
+            "id": "gpt2",
+            "gated": "manual",
+            "cardData": { "license": "mit" }
+        })).unwrap();
+
+        assert_eq!(info.license(), Some(String::from("mit")));
+
+        let summary = info.into_summary("hf-mirror");
+        assert_eq!(summary.platform, "hf-mirror");
+        assert_eq!(summary.name, "gpt2");
+        assert_eq!(summary.author, None);
+        assert!(summary.tags.is_empty());
+        assert!(summary.files.is_empty());
+    }
+
+    #[test]
+    fn test_dataset_info() {
+        let info: DatasetInfo = from_value(json!({
+            "id": "stanfordnlp/imdb",
+            "author": "stanfordnlp",
+            "description": "Large Movie Review Dataset",
+            "siblings": [{ "rfilename": "README.md", "size": 10 }],
+            "paperswithcode_id": "imdb-movie-reviews"
+        })).unwrap();
+
+        assert_eq!(info.author, Some(String::from("stanfordnlp")));
+        assert_eq!(info.siblings[0].size, Some(10));
+
+        // Fields that are not modelled are passed through
+        let value = to_value(&info).unwrap();
+        assert_eq!(value["paperswithcode_id"], "imdb-movie-reviews");
+        assert_eq!(value["id"], "stanfordnlp/imdb");
+    }
+}
@@ -0,0 +1,515 @@
+use crate::download::{check_response_status, io_error, request_error, send_request, sha256_file, RepoType};
+use crate::responses::{CommitInfo, LfsAction, LfsBatchResponse, PreuploadFile, PreuploadResponse, RepoRefs, UploadMode};
+use crate::utils::{deserialize_response_body, encode_revision};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use bytes::Bytes;
+use clients::{ClientError, ClientErrorScope};
+use futures_util::stream::{self, Stream, StreamExt};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, ETAG};
+use reqwest::{Body, Client as ReqwestClient, RequestBuilder, StatusCode};
+use serde_json::{json, Value};
+use shared::logging::SharedLogger;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+use tokio_util::io::ReaderStream;
+
+const LFS_CONTENT_TYPE: &str = "application/vnd.git-lfs+json";
+
+/// Number of files sent to the preupload and lfs batch endpoints per request
+const BATCH_SIZE: usize = 100;
+
+/// Number of leading bytes of a file the Hub uses to decide whether a file is
+/// uploaded to lfs storage
+const PREUPLOAD_SAMPLE_SIZE: u64 = 512;
+
+/// Number of bytes of a regular file encoded at a time when streaming it into
+/// the commit. A multiple of 3 so the encoded blocks can be concatenated
+const BASE64_BLOCK_SIZE: u64 = 3 * 64 * 1024;
+
+/// Uploads files to a Hugging Face repository over the Hub http api. Unlike
+/// pushing with git, the access token is only ever sent in request headers and
+/// is never written to disk
+pub(crate) struct HubUploader<'a> {
+    pub client: &'a ReqwestClient,
+    pub base_url: &'a str,
+    pub access_token: &'a str,
+    pub logger: &'a SharedLogger,
+}
+
+/// The files to commit to a repository
+pub(crate) struct UploadFolderParams<'a> {
+    pub repo_type: RepoType,
+    pub repo_id: &'a str,
+    /// Branch the commit is made on. The branch must exist
+    pub branch: &'a str,
+    pub source_dir: &'a Path,
+    pub commit_message: &'a str,
+}
+
+/// A file of the source directory
+struct LocalFile {
+    /// Path of the file in the repository. Always separated by "/"
+    path: String,
+    absolute_path: PathBuf,
+    size: u64,
+}
+
+/// A file that is uploaded to lfs storage before the commit
+struct LfsFile<'a> {
+    file: &'a LocalFile,
+    oid: String,
+}
+
+/// A line of the commit request
+enum CommitOperation<'a> {
+    Json(Value),
+    /// A regular file whose content is sent inline
+    File(&'a LocalFile),
+}
+
+/// A part of the commit request body. Regular files are read and encoded as
+/// the body is sent rather than held in memory
+enum CommitChunk {
+    Bytes(Bytes),
+    Base64File(PathBuf),
+}
+
+impl CommitChunk {
+    fn len(&self, files: &HashMap<PathBuf, u64>) -> u64 {
+        match self {
+            Self::Bytes(bytes) => bytes.len() as u64,
+            Self::Base64File(path) => files.get(path).map(|size| size.div_ceil(3) * 4).unwrap_or(0),
+        }
+    }
+}
+
+impl<'a> HubUploader<'a> {
+    /// Commits every file in the source directory to the branch. Large files
+    /// are uploaded to lfs storage first and referenced by the commit. Returns
+    /// the sha of the commit
+    pub async fn upload_folder(&self, params: UploadFolderParams<'_>) -> Result<String, ClientError> {
+        let files = list_files(params.source_dir).await?;
+        let preuploaded = self.preupload(&params, &files).await?;
+
+        let mut operations = vec![CommitOperation::Json(json!({
+            "key": "header",
+            "value": { "summary": params.commit_message, "description": "" }
+        }))];
+        let mut lfs_files = Vec::new();
+
+        for file in &files {
+            let preuploaded_file = preuploaded.get(&file.path);
+            if preuploaded_file.map(|preuploaded_file| preuploaded_file.should_ignore).unwrap_or(false) {
+                self.logger.debug(format!("Skipping ignored file '{}'", &file.path).as_str());
+                continue
+            }
+
+            match preuploaded_file.map(|preuploaded_file| &preuploaded_file.upload_mode) {
+                Some(UploadMode::Lfs) => {
+                    let oid = sha256_file(&file.absolute_path).await?;
+                    operations.push(CommitOperation::Json(json!({
+                        "key": "lfsFile",
+                        "value": { "path": &file.path, "algo": "sha256", "oid": &oid }
+                    })));
+                    lfs_files.push(LfsFile { file, oid });
+                },
+                _ => operations.push(CommitOperation::File(file))
+            }
+        }
+
+        for batch in lfs_files.chunks(BATCH_SIZE) {
+            self.upload_lfs_files(&params, batch).await?;
+        }
+
+        self.commit(&params, operations).await
+    }
+
+    /// Creates a branch from the head of the default branch. Creating a branch
+    /// that already exists is not an error
+    pub async fn create_branch(&self, repo_type: RepoType, repo_id: &str, branch: &str) -> Result<(), ClientError> {
+        let url = format!(
+            "{}/api/{}/{}/branch/{}",
+            self.base_url,
+            repo_type.api_prefix(),
+            repo_id,
+            encode_revision(branch)
+        );
+
+        let response = self.authorize(self.client.post(url).json(&json!({})))
+            .send()
+            .await
+            .map_err(request_error)?;
+
+        if response.status() == StatusCode::CONFLICT {
+            return Ok(())
+        }
+
+        check_response_status(response, self.logger).await.map(|_| ())
+    }
+
+    /// Tags a revision of the repository. Tagging a revision with a tag that
+    /// already points to it is not an error
+    pub async fn create_tag(&self, repo_type: RepoType, repo_id: &str, revision: &str, tag: &str, message: &str) -> Result<(), ClientError> {
+        let url = format!(
+            "{}/api/{}/{}/tag/{}",
+            self.base_url,
+            repo_type.api_prefix(),
+            repo_id,
+            encode_revision(revision)
+        );
+
+        let response = self.authorize(self.client.post(url).json(&json!({ "tag": tag, "message": message })))
+            .send()
+            .await
+            .map_err(request_error)?;
+
+        if response.status() == StatusCode::CONFLICT {
+            return match self.find_tag(repo_type, repo_id, tag).await? {
+                Some(commit) if commit == revision => Ok(()),
+                _ => Err(tag_exists_error(repo_id, tag)),
+            }
+        }
+
+        check_response_status(response, self.logger).await.map(|_| ())
+    }
+
+    /// Returns the sha of the commit a tag points to, if the tag exists
+    pub async fn find_tag(&self, repo_type: RepoType, repo_id: &str, tag: &str) -> Result<Option<String>, ClientError> {
+        let url = format!(
+            "{}/api/{}/{}/refs",
+            self.base_url,
+            repo_type.api_prefix(),
+            repo_id
+        );
+
+        let response = send_request(self.authorize(self.client.get(url)), self.logger).await?;
+        let refs: RepoRefs = deserialize_response_body(response).await?;
+
+        Ok(refs.tags.into_iter()
+            .find(|git_ref| git_ref.name == tag)
+            .map(|git_ref| git_ref.target_commit))
+    }
+
+    /// Asks the Hub which files must be uploaded to lfs storage and which
+    /// files are ignored by the repository
+    async fn preupload(&self, params: &UploadFolderParams<'_>, files: &[LocalFile]) -> Result<HashMap<String, PreuploadFile>, ClientError> {
+        let url = format!(
+            "{}/api/{}/{}/preupload/{}",
+            self.base_url,
+            params.repo_type.api_prefix(),
+            params.repo_id,
+            encode_revision(params.branch)
+        );
+
+        let mut preuploaded = HashMap::new();
+        for batch in files.chunks(BATCH_SIZE) {
+            let mut entries = Vec::with_capacity(batch.len());
+            for file in batch {
+                entries.push(json!({
+                    "path": &file.path,
+                    "size": file.size,
+                    "sample": BASE64.encode(read_range(&file.absolute_path, 0, PREUPLOAD_SAMPLE_SIZE).await?),
+                }));
+            }
+
+            let response = send_request(
+                self.authorize(self.client.post(&url).json(&json!({ "files": entries }))),
+                self.logger
+            ).await?;
+
+            let body: PreuploadResponse = deserialize_response_body(response).await?;
+            preuploaded.extend(body.files.into_iter().map(|file| (file.path.clone(), file)));
+        }
+
+        Ok(preuploaded)
+    }
+
+    /// Uploads files to lfs storage using the lfs batch api. Files already
+    /// stored on the Hub are skipped
+    async fn upload_lfs_files(&self, params: &UploadFolderParams<'_>, files: &[LfsFile<'_>]) -> Result<(), ClientError> {
+        let url = format!(
+            "{}/{}{}.git/info/lfs/objects/batch",
+            self.base_url,
+            params.repo_type.resolve_prefix(),
+            params.repo_id
+        );
+
+        let body = json!({
+            "operation": "upload",
+            "transfers": ["basic", "multipart"],
+            "hash_algo": "sha256",
+            "ref": { "name": format!("refs/heads/{}", params.branch) },
+            "objects": files.iter()
+                .map(|lfs_file| json!({ "oid": &lfs_file.oid, "size": lfs_file.file.size }))
+                .collect::<Vec<Value>>(),
+        });
+
+        let response = send_request(
+            self.authorize(self.client.post(url)
+                .header(ACCEPT, LFS_CONTENT_TYPE)
+                .header(CONTENT_TYPE, LFS_CONTENT_TYPE)
+                .body(body.to_string())),
+            self.logger
+        ).await?;
+
+        let batch: LfsBatchResponse = deserialize_response_body(response).await?;
+
+        for object in batch.objects {
+            let lfs_file = files.iter()
+                .find(|lfs_file| lfs_file.oid == object.oid)
+                .ok_or(ClientError::Internal {
+                    msg: format!("Lfs batch api responded with unknown object '{}'", &object.oid),
+                    scope: ClientErrorScope::Server,
+                })?;
+
+            if let Some(error) = object.error {
+                return Err(ClientError::Internal {
+                    msg: format!("Lfs upload of '{}' was rejected ({}): {}", &lfs_file.file.path, error.code, error.message),
+                    scope: ClientErrorScope::Server,
+                })
+            }
+
+            let actions = match object.actions {
+                Some(actions) => actions,
+                None => {
+                    self.logger.debug(format!("'{}' is already stored on the Hub", &lfs_file.file.path).as_str());
+                    continue
+                }
+            };
+
+            if let Some(upload) = actions.upload {
+                match upload.header.contains_key("chunk_size") {
+                    true => self.upload_multipart(lfs_file, &upload).await?,
+                    false => self.upload_single_part(lfs_file, &upload).await?,
+                }
+            }
+
+            if let Some(verify) = actions.verify {
+                let mut request = self.authorize(self.client.post(&verify.href))
+                    .header(ACCEPT, LFS_CONTENT_TYPE)
+                    .header(CONTENT_TYPE, LFS_CONTENT_TYPE)
+                    .body(json!({ "oid": &lfs_file.oid, "size": lfs_file.file.size }).to_string());
+                for (name, value) in &verify.header {
+                    request = request.header(name, value);
+                }
+
+                send_request(request, self.logger).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Uploads a file in a single request. The upload url is presigned so the
+    /// access token is not sent
+    async fn upload_single_part(&self, lfs_file: &LfsFile<'_>, upload: &LfsAction) -> Result<(), ClientError> {
+        let mut request = self.client.put(&upload.href)
+            .header(CONTENT_LENGTH, lfs_file.file.size)
+            .body(file_body(&lfs_file.file.absolute_path, 0, lfs_file.file.size).await?);
+        for (name, value) in &upload.header {
+            request = request.header(name, value);
+        }
+
+        send_request(request, self.logger).await?;
+
+        Ok(())
+    }
+
+    /// Uploads a file in parts of "chunk_size" bytes to the presigned urls of
+    /// the numbered headers, then completes the upload with the etag of each
+    /// part
+    async fn upload_multipart(&self, lfs_file: &LfsFile<'_>, upload: &LfsAction) -> Result<(), ClientError> {
+        let chunk_size = upload.header.get("chunk_size")
+            .and_then(|chunk_size| chunk_size.parse::<u64>().ok())
+            .filter(|chunk_size| *chunk_size > 0)
+            .ok_or(ClientError::Internal {
+                msg: format!("Invalid chunk size for multipart upload of '{}'", &lfs_file.file.path),
+                scope: ClientErrorScope::Server,
+            })?;
+
+        let mut part_urls: Vec<(u64, &String)> = upload.header.iter()
+            .filter_map(|(name, url)| name.parse::<u64>().ok().map(|part_number| (part_number, url)))
+            .collect();
+        part_urls.sort_by_key(|(part_number, _)| *part_number);
+
+        let mut parts = Vec::with_capacity(part_urls.len());
+        for (part_number, url) in part_urls {
+            self.logger.debug(format!("Uploading part {} of '{}'", part_number, &lfs_file.file.path).as_str());
+
+            let offset = (part_number - 1) * chunk_size;
+            let length = chunk_size.min(lfs_file.file.size.saturating_sub(offset));
+            let chunk = file_body(&lfs_file.file.absolute_path, offset, length).await?;
+
+            let response = send_request(self.client.put(url).header(CONTENT_LENGTH, length).body(chunk), self.logger).await?;
+            let etag = response.headers().get(ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .map(String::from)
+                .ok_or(ClientError::Internal {
+                    msg: format!("Missing etag for part {} of '{}'", part_number, &lfs_file.file.path),
+                    scope: ClientErrorScope::Server,
+                })?;
+
+            parts.push(json!({ "partNumber": part_number, "etag": etag }));
+        }
+
+        send_request(
+            self.client.post(&upload.href)
+                .header(ACCEPT, LFS_CONTENT_TYPE)
+                .header(CONTENT_TYPE, LFS_CONTENT_TYPE)
+                .body(json!({ "oid": &lfs_file.oid, "parts": parts }).to_string()),
+            self.logger
+        ).await?;
+
+        Ok(())
+    }
+
+    /// Creates the commit. Each operation is sent as a line of ndjson
+    async fn commit(&self, params: &UploadFolderParams<'_>, operations: Vec<CommitOperation<'_>>) -> Result<String, ClientError> {
+        let url = format!(
+            "{}/api/{}/{}/commit/{}",
+            self.base_url,
+            params.repo_type.api_prefix(),
+            params.repo_id,
+            encode_revision(params.branch)
+        );
+
+        let mut chunks = Vec::new();
+        let mut file_sizes = HashMap::new();
+        for (index, operation) in operations.into_iter().enumerate() {
+            if index > 0 {
+                chunks.push(CommitChunk::Bytes(Bytes::from_static(b"\n")));
+            }
+
+            match operation {
+                CommitOperation::Json(value) => chunks.push(CommitChunk::Bytes(Bytes::from(value.to_string()))),
+                // The content is the last field so it can be streamed between
+                // the rest of the operation
+                CommitOperation::File(file) => {
+                    let path = serde_json::to_string(&file.path)
+                        .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client })?;
+
+                    file_sizes.insert(file.absolute_path.clone(), file.size);
+                    chunks.push(CommitChunk::Bytes(Bytes::from(format!(r#"{{"key":"file","value":{{"path":{},"encoding":"base64","content":""#, path))));
+                    chunks.push(CommitChunk::Base64File(file.absolute_path.clone()));
+                    chunks.push(CommitChunk::Bytes(Bytes::from_static(br#""}}"#)));
+                }
+            }
+        }
+
+        let content_length: u64 = chunks.iter().map(|chunk| chunk.len(&file_sizes)).sum();
+        let body = stream::iter(chunks).flat_map(|chunk| match chunk {
+            CommitChunk::Bytes(bytes) => stream::once(async move { Ok(bytes) }).boxed(),
+            CommitChunk::Base64File(path) => base64_file_stream(path).boxed(),
+        });
+
+        let response = send_request(
+            self.authorize(self.client.post(url)
+                .header(CONTENT_TYPE, "application/x-ndjson")
+                .header(CONTENT_LENGTH, content_length)
+                .body(Body::wrap_stream(body))),
+            self.logger
+        ).await?;
+
+        let commit: CommitInfo = deserialize_response_body(response).await?;
+
+        Ok(commit.commit_oid)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        request.header(AUTHORIZATION, format!("Bearer {}", self.access_token))
+    }
+}
+
+/// Lists every file in a directory recursively, sorted by path. Git metadata
+/// is skipped
+async fn list_files(source_dir: &Path) -> Result<Vec<LocalFile>, ClientError> {
+    let mut files = Vec::new();
+    let mut dirs = vec![source_dir.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let mut entries = fs::read_dir(&dir).await.map_err(io_error)?;
+        while let Some(entry) = entries.next_entry().await.map_err(io_error)? {
+            let absolute_path = entry.path();
+            let file_type = entry.file_type().await.map_err(io_error)?;
+
+            if file_type.is_dir() {
+                if entry.file_name() != ".git" {
+                    dirs.push(absolute_path);
+                }
+                continue
+            }
+
+            let relative_path = absolute_path.strip_prefix(source_dir)
+                .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client })?;
+
+            let path = relative_path.components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<String>>()
+                .join("/");
+
+            let size = entry.metadata().await.map_err(io_error)?.len();
+
+            files.push(LocalFile { path, absolute_path, size });
+        }
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(files)
+}
+
+/// Reads up to `length` bytes of a file starting at `offset`
+async fn read_range(path: &Path, offset: u64, length: u64) -> Result<Vec<u8>, ClientError> {
+    let mut file = fs::File::open(path).await.map_err(io_error)?;
+    file.seek(SeekFrom::Start(offset)).await.map_err(io_error)?;
+
+    let mut buffer = Vec::new();
+    file.take(length).read_to_end(&mut buffer).await.map_err(io_error)?;
+
+    Ok(buffer)
+}
+
+/// Streams up to `length` bytes of a file starting at `offset`
+async fn file_body(path: &Path, offset: u64, length: u64) -> Result<Body, ClientError> {
+    let mut file = fs::File::open(path).await.map_err(io_error)?;
+    file.seek(SeekFrom::Start(offset)).await.map_err(io_error)?;
+
+    Ok(Body::wrap_stream(ReaderStream::new(file.take(length))))
+}
+
+/// Streams the base64 encoding of a file
+fn base64_file_stream(path: PathBuf) -> impl Stream<Item = Result<Bytes, std::io::Error>> {
+    stream::try_unfold(None, move |file: Option<fs::File>| {
+        let path = path.clone();
+        async move {
+            let mut file = match file {
+                Some(file) => file,
+                None => fs::File::open(&path).await?,
+            };
+
+            let mut block = Vec::new();
+            (&mut file).take(BASE64_BLOCK_SIZE).read_to_end(&mut block).await?;
+            if block.is_empty() {
+                return Ok(None)
+            }
+
+            Ok(Some((Bytes::from(BASE64.encode(&block)), Some(file))))
+        }
+    })
+}
+
+/// Error for a tag that already points to another revision
+pub(crate) fn tag_exists_error(repo_id: &str, tag: &str) -> ClientError {
+    ClientError::BadRequest {
+        msg: format!("Tag '{}' already exists on repo '{}'", tag, repo_id),
+        scope: ClientErrorScope::Client,
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "upload.test.rs"]
+mod upload_test;
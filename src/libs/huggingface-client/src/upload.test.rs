@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod upload_test {
+    use std::fs;
+    use std::path::PathBuf;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+    use base64::Engine;
+    use reqwest::Client as ReqwestClient;
+    use serde_json::{json, Value};
+    use sha2::{Digest, Sha256};
+    use shared::logging::SharedLogger;
+    use crate::download::RepoType;
+    use test_support::stub_server::{self, StubRequest, StubResponse};
+    use crate::upload::{HubUploader, UploadFolderParams};
+
+    const WEIGHTS: &[u8] = b"weights";
+    const SHARDS: &[u8] = b"0123456789";
+
+    fn oid(content: &[u8]) -> String {
+        hex::encode(Sha256::digest(content))
+    }
+
+    /// Spans several blocks of the streamed base64 encoding
+    fn vocab() -> Vec<u8> {
+        (0..500_000u32).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn source_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hf-upload-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("shards")).unwrap();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::write(dir.join("config.json"), b"{}").unwrap();
+        fs::write(dir.join("vocab.txt"), vocab()).unwrap();
+        fs::write(dir.join("weights.bin"), WEIGHTS).unwrap();
+        fs::write(dir.join("shards/model.bin"), SHARDS).unwrap();
+        fs::write(dir.join(".git/config"), b"[remote]").unwrap();
+        dir
+    }
+
+    /// Serves the preupload, lfs batch and commit endpoints. The weights are
+    /// uploaded in a single part and the shards in parts of 4 bytes
+    fn handle(request: &StubRequest) -> StubResponse {
+        let host = format!("http://{}", request.header("host").unwrap_or_default());
+
+        match (request.method(), request.path()) {
+            ("POST", "/api/models/org/model/preupload/main") => StubResponse::json(json!({
+                "files": [
+                    { "path": "config.json", "uploadMode": "regular", "shouldIgnore": false },
+                    { "path": "shards/model.bin", "uploadMode": "lfs", "shouldIgnore": false },
+                    { "path": "vocab.txt", "uploadMode": "regular", "shouldIgnore": false },
+                    { "path": "weights.bin", "uploadMode": "lfs", "shouldIgnore": false }
+                ]
+            }).to_string()),
+            ("POST", "/org/model.git/info/lfs/objects/batch") => StubResponse::json(json!({
+                "objects": [
+                    {
+                        "oid": oid(SHARDS),
+                        "size": SHARDS.len(),
+                        "actions": {
+                            "upload": {
+                                "href": format!("{}/complete", host),
+                                "header": {
+                                    "chunk_size": "4",
+                                    "00002": format!("{}/parts/2", host),
+                                    "00001": format!("{}/parts/1", host),
+                                    "00003": format!("{}/parts/3", host)
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "oid": oid(WEIGHTS),
+                        "size": WEIGHTS.len(),
+                        "actions": {
+                            "upload": { "href": format!("{}/upload/weights", host) },
+                            "verify": { "href": format!("{}/verify", host) }
+                        }
+                    }
+                ]
+            }).to_string()),
+            ("PUT", path) if path.starts_with("/parts/") => StubResponse {
+                status: 200,
+                headers: vec![(String::from("ETag"), format!("etag-{}", path.trim_start_matches("/parts/")))],
+                body: Vec::new(),
+            },
+            ("PUT", "/upload/weights") | ("POST", "/complete") | ("POST", "/verify") => StubResponse::json(String::from("{}")),
+            ("POST", "/api/models/org/model/commit/main") => StubResponse::json(json!({
+                "commitOid": "0123456789abcdef0123456789abcdef01234567",
+                "commitUrl": "https://hf.example.org/org/model/commit/0123456789abcdef0123456789abcdef01234567"
+            }).to_string()),
+            _ => StubResponse { status: 404, headers: Vec::new(), body: Vec::new() }
+        }
+    }
+
+    fn find<'a>(requests: &'a [StubRequest], method: &str, path: &str) -> &'a StubRequest {
+        requests.iter()
+            .find(|request| request.method() == method && request.path() == path)
+            .unwrap_or_else(|| panic!("Missing request {} {}", method, path))
+    }
+
+    #[tokio::test]
+    async fn test_upload_folder() {
+        let (base_url, requests) = stub_server::start(handle);
+        let dir = source_dir();
+
+        let client = ReqwestClient::new();
+        let logger = SharedLogger::new();
+        let commit = HubUploader { client: &client, base_url: &base_url, access_token: "hf_token", logger: &logger }
+            .upload_folder(UploadFolderParams {
+                repo_type: RepoType::Model,
+                repo_id: "org/model",
+                branch: "main",
+                source_dir: &dir,
+                commit_message: "Publish model",
+            })
+            .await
+            .expect("Upload failed");
+
+        assert_eq!(commit, "0123456789abcdef0123456789abcdef01234567");
+
+        let requests: Vec<StubRequest> = requests.try_iter().collect();
+
+        // Git metadata is never uploaded
+        let preupload: Value = serde_json::from_slice(&find(&requests, "POST", "/api/models/org/model/preupload/main").body).unwrap();
+        let paths: Vec<&str> = preupload["files"].as_array().unwrap().iter()
+            .map(|file| file["path"].as_str().unwrap())
+            .collect();
+        assert_eq!(paths, vec!["config.json", "shards/model.bin", "vocab.txt", "weights.bin"]);
+
+        // Small lfs files are uploaded in a single request and verified
+        assert_eq!(find(&requests, "PUT", "/upload/weights").body, WEIGHTS);
+        let verify: Value = serde_json::from_slice(&find(&requests, "POST", "/verify").body).unwrap();
+        assert_eq!(verify, json!({ "oid": oid(WEIGHTS), "size": WEIGHTS.len() }));
+
+        // Large lfs files are uploaded in parts and completed with the etags
+        assert_eq!(find(&requests, "PUT", "/parts/1").body, b"0123");
+        assert_eq!(find(&requests, "PUT", "/parts/2").body, b"4567");
+        assert_eq!(find(&requests, "PUT", "/parts/3").body, b"89");
+        let complete: Value = serde_json::from_slice(&find(&requests, "POST", "/complete").body).unwrap();
+        assert_eq!(complete, json!({
+            "oid": oid(SHARDS),
+            "parts": [
+                { "partNumber": 1, "etag": "etag-1" },
+                { "partNumber": 2, "etag": "etag-2" },
+                { "partNumber": 3, "etag": "etag-3" }
+            ]
+        }));
+
+        // The commit sends regular files inline and references lfs files
+        let commit_request = find(&requests, "POST", "/api/models/org/model/commit/main");
+        assert_eq!(commit_request.header("content-type"), Some("application/x-ndjson"));
+        let operations: Vec<Value> = String::from_utf8_lossy(&commit_request.body).lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(operations, vec![
+            json!({ "key": "header", "value": { "summary": "Publish model", "description": "" } }),
+            json!({ "key": "file", "value": { "path": "config.json", "content": "e30=", "encoding": "base64" } }),
+            json!({ "key": "lfsFile", "value": { "path": "shards/model.bin", "algo": "sha256", "oid": oid(SHARDS) } }),
+            json!({ "key": "file", "value": { "path": "vocab.txt", "content": BASE64.encode(vocab()), "encoding": "base64" } }),
+            json!({ "key": "lfsFile", "value": { "path": "weights.bin", "algo": "sha256", "oid": oid(WEIGHTS) } }),
+        ]);
+
+        // The token is only sent to the Hub, never to the presigned upload urls
+        for request in &requests {
+            let is_presigned = request.path().starts_with("/parts/")
+                || request.path() == "/upload/weights"
+                || request.path() == "/complete";
+            match is_presigned {
+                true => assert_eq!(request.header("authorization"), None),
+                false => assert_eq!(request.header("authorization"), Some("Bearer hf_token")),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_branch_and_tag() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            match request.path() {
+                // The branch already exists
+                "/api/models/org/model/branch/dev%2Fv1" => StubResponse { status: 409, headers: Vec::new(), body: Vec::new() },
+                // The tag already exists and points to "abc"
+                "/api/models/org/model/tag/abc" | "/api/models/org/model/tag/ghi" => StubResponse { status: 409, headers: Vec::new(), body: Vec::new() },
+                "/api/models/org/model/refs" => StubResponse::json(json!({
+                    "branches": [],
+                    "tags": [{ "name": "1.0.0", "ref": "refs/tags/1.0.0", "targetCommit": "abc" }]
+                }).to_string()),
+                _ => StubResponse::json(String::from("{}"))
+            }
+        });
+
+        let client = ReqwestClient::new();
+        let logger = SharedLogger::new();
+        let uploader = HubUploader { client: &client, base_url: &base_url, access_token: "hf_token", logger: &logger };
+
+        uploader.create_branch(RepoType::Model, "org/model", "dev/v1").await.expect("Existing branch should not be an error");
+        uploader.create_tag(RepoType::Model, "org/model", "def", "1.0.0", "Release").await.expect("Tag should be created");
+        uploader.create_tag(RepoType::Model, "org/model", "abc", "1.0.0", "Release").await.expect("Identical tag should not be an error");
+        assert!(uploader.create_tag(RepoType::Model, "org/model", "ghi", "1.0.0", "Release").await.is_err());
+
+        assert_eq!(uploader.find_tag(RepoType::Model, "org/model", "1.0.0").await.unwrap(), Some(String::from("abc")));
+        assert_eq!(uploader.find_tag(RepoType::Model, "org/model", "2.0.0").await.unwrap(), None);
+
+        let requests: Vec<StubRequest> = requests.try_iter().collect();
+        let tag: Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(tag, json!({ "tag": "1.0.0", "message": "Release" }));
+    }
+}
@@ -1,8 +1,27 @@
-use clients::{ClientError, ClientErrorScope};
-use serde_json::{Value, from_str};
-use reqwest::Response;
+use clients::{error_message_from_body, ClientError, ClientErrorScope};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value, from_str};
+use reqwest::header::{HeaderMap, LINK, RETRY_AFTER};
+use reqwest::{Response, Url};
+use std::collections::HashMap;
+
+/// Header the Hub uses to describe why a request failed
+const ERROR_MESSAGE_HEADER: &str = "x-error-message";
+
+/// Header the Hub uses to identify the kind of error. Ex. GatedRepo
+const ERROR_CODE_HEADER: &str = "x-error-code";
+
+/// Error code returned when the account has not been granted access to a gated repo
+const GATED_REPO_ERROR_CODE: &str = "GatedRepo";
+
+/// Deserializes the body of a successful response. Unsuccessful responses are
+/// mapped onto the matching client error instead
+pub(crate) async fn deserialize_response_body<T: DeserializeOwned>(response: Response) -> Result<T, ClientError> {
+    if !response.status().is_success() {
+        return Err(response_error(response).await)
+    }
 
-pub(crate) async fn deserialize_response_body(response: Response) -> Result<Value, ClientError> {
     response
         .text()
         .await
@@ -13,7 +32,7 @@ pub(crate) async fn deserialize_response_body(response: Response) -> Result<Valu
             }
         })
         .and_then(|text| {
-            from_str::<Value>(&text.trim())
+            from_str::<T>(&text.trim())
                 .map_err(|err| {
                     ClientError::Internal {
                         msg: err.to_string(),
@@ -21,4 +40,221 @@ pub(crate) async fn deserialize_response_body(response: Response) -> Result<Valu
                     }
                 })
         })
-}
\ No newline at end of file
+}
+
+/// Maps an unsuccessful response from the Hub onto the matching client error.
+/// The message is taken from the error header or body of the response when
+/// the Hub provides one
+pub(crate) async fn response_error(response: Response) -> ClientError {
+    let status = response.status();
+    let url = response.url().to_string();
+    let retry_after = header_value(response.headers(), RETRY_AFTER.as_str());
+    let header_message = header_value(response.headers(), ERROR_MESSAGE_HEADER);
+    let error_code = header_value(response.headers(), ERROR_CODE_HEADER);
+    let body = response.text().await.unwrap_or_default();
+
+    let reason = header_message
+        .or_else(|| error_message_from_body(&body))
+        .unwrap_or(status.canonical_reason().unwrap_or("Unknown error").to_string());
+
+    if error_code.as_deref() == Some(GATED_REPO_ERROR_CODE) {
+        return gated_repo_error(&reason)
+    }
+
+    ClientError::from_upstream_status(
+        status.as_u16(),
+        format!("Hugging Face responded to {} with status {}: {}", url, status.as_u16(), reason),
+        retry_after,
+    )
+}
+
+/// The error returned when a gated repo is accessed by an account that has not
+/// been granted access to it
+pub(crate) fn gated_repo_error(detail: &str) -> ClientError {
+    ClientError::Forbidden {
+        msg: format!("The repo is gated. Access must be requested on the Hugging Face Hub and the request must be authenticated with a token of an account that has been granted access: {}", detail),
+        scope: ClientErrorScope::Server,
+    }
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers.get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from)
+}
+
+/// Characters that are not percent-encoded in a path segment. Everything else
+/// is, including "/", "?" and "#"
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Encodes a revision for use as a single path segment. Revisions such as
+/// "refs/pr/1" contain slashes
+pub(crate) fn encode_revision(revision: &str) -> String {
+    utf8_percent_encode(revision, PATH_SEGMENT).to_string()
+}
+
+/// Encodes a repository file path for use in a url. Each segment is encoded
+/// on its own so that the "/" separators are kept
+pub(crate) fn encode_path(path: &str) -> String {
+    path.split("/")
+        .map(|segment| utf8_percent_encode(segment, PATH_SEGMENT).to_string())
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+/// Extracts the pagination cursor from the "next" link of a Link header.
+/// Hugging Face pages list results by returning a header like
+/// `<https://huggingface.co/api/models?limit=10&cursor=abc>; rel="next"`
+pub(crate) fn parse_next_cursor(headers: &HeaderMap) -> Option<String> {
+    for value in headers.get_all(LINK).iter() {
+        let value = match value.to_str() {
+            Ok(v) => v,
+            Err(_) => continue
+        };
+
+        for link in value.split(",") {
+            let mut parts = link.split(";").map(|part| part.trim());
+
+            let url = match parts.next() {
+                Some(url) => url.trim_start_matches("<").trim_end_matches(">"),
+                None => continue
+            };
+
+            let is_next = parts.any(|param| {
+                param.replace(" ", "") == "rel=\"next\"" || param == "rel=next"
+            });
+
+            if !is_next {
+                continue
+            }
+
+            let cursor = Url::parse(url).ok()
+                .and_then(|url| url.query_pairs()
+                    .find(|(key, _)| key == "cursor")
+                    .map(|(_, value)| value.into_owned()));
+
+            if cursor.is_some() {
+                return cursor
+            }
+        }
+    }
+
+    None
+}
+
+/// Parses an optional boolean query parameter (ex. ?full=true)
+pub(crate) fn parse_bool_param(query: &HashMap<String, String>, name: &str) -> Result<Option<bool>, ClientError> {
+    match query.get(name) {
+        Some(value) => value.parse::<bool>()
+            .map(Some)
+            .map_err(|_| ClientError::BadRequest {
+                msg: format!("Query parameter '{}' must be 'true' or 'false'", name),
+                scope: ClientErrorScope::Client
+            }),
+        None => Ok(None)
+    }
+}
+
+/// Metadata returned with a page of list results. The next cursor is null on
+/// the last page
+pub(crate) fn build_pagination_metadata(limit: i64, next_cursor: Option<String>) -> Value {
+    json!({
+        "limit": limit,
+        "next_cursor": next_cursor
+    })
+}
+
+/// Matches a repository file path against a glob pattern. `*` and `?` do not
+/// match across directories while `**` does. Like git lfs include/exclude
+/// patterns, a pattern without a "/" is matched against the file name alone and
+/// a pattern ending in "/" matches everything under that directory
+pub(crate) fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim().trim_start_matches("./");
+    let path = path.trim_start_matches("/");
+
+    if let Some(dir) = pattern.strip_suffix("/") {
+        return glob_match(&format!("{}/**", dir), path)
+    }
+
+    if !pattern.contains("/") {
+        let file_name = path.rsplit("/").next().unwrap_or(path);
+        return match_segment(pattern.as_bytes(), file_name.as_bytes())
+    }
+
+    let pattern: Vec<&str> = pattern.trim_start_matches("/").split("/").collect();
+    let path: Vec<&str> = path.split("/").collect();
+
+    match_segments(&pattern, &path)
+}
+
+/// Whether a path is selected by the include patterns (all paths if there are
+/// none) and not selected by any of the exclude patterns
+pub(crate) fn is_path_selected(path: &str, include_paths: &[String], exclude_paths: &[String]) -> bool {
+    let included = include_paths.is_empty()
+        || include_paths.iter().any(|pattern| glob_match(pattern, path));
+
+    included && !exclude_paths.iter().any(|pattern| glob_match(pattern, path))
+}
+
+/// Matches path segments against pattern segments where a "**" segment
+/// matches any number of path segments. Each "**" only remembers the last
+/// position it was tried at, so the match is linear in the number of segments
+/// rather than backtracking over every combination
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < path.len() {
+        if p < pattern.len() && pattern[p] == "**" {
+            star = Some((p, n));
+            p += 1;
+        } else if p < pattern.len() && match_segment(pattern[p].as_bytes(), path[n].as_bytes()) {
+            p += 1;
+            n += 1;
+        } else if let Some((star_p, star_n)) = star {
+            // Let the last "**" absorb one more segment and retry from there
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, n));
+        } else {
+            return false
+        }
+    }
+
+    pattern[p..].iter().all(|segment| *segment == "**")
+}
+
+/// Matches a file name against a pattern segment where `*` matches any run of
+/// characters and `?` matches a single character. Like `match_segments`, only
+/// the last `*` is backtracked to
+fn match_segment(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            star = Some((p, n));
+            p += 1;
+        } else if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, n));
+        } else {
+            return false
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "utils.test.rs"]
+mod utils_test;
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod utils_test {
+    use std::collections::HashMap;
+    use clients::ClientError;
+    use reqwest::header::{HeaderMap, HeaderValue, LINK};
+    use crate::utils::{encode_path, encode_revision, glob_match, is_path_selected, parse_bool_param, parse_next_cursor};
+
+    fn link_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LINK, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_parse_next_cursor() {
+        let headers = link_headers(
+            r#"<https://huggingface.co/api/models?limit=10&cursor=eyJfaWQiOjF9>; rel="next""#
+        );
+
+        assert_eq!(parse_next_cursor(&headers), Some(String::from("eyJfaWQiOjF9")));
+    }
+
+    #[test]
+    fn test_parse_next_cursor_ignores_other_relations() {
+        let headers = link_headers(
+            r#"<https://huggingface.co/api/models?cursor=prev>; rel="prev", <https://huggingface.co/api/models?cursor=next%3D%3D>; rel="next""#
+        );
+        assert_eq!(parse_next_cursor(&headers), Some(String::from("next==")));
+
+        let headers = link_headers(r#"<https://huggingface.co/api/models?cursor=prev>; rel="prev""#);
+        assert_eq!(parse_next_cursor(&headers), None);
+
+        assert_eq!(parse_next_cursor(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_parse_bool_param() {
+        let query = HashMap::from([
+            (String::from("full"), String::from("true")),
+            (String::from("config"), String::from("yes")),
+        ]);
+
+        assert_eq!(parse_bool_param(&query, "full").unwrap(), Some(true));
+        assert_eq!(parse_bool_param(&query, "missing").unwrap(), None);
+        assert!(matches!(parse_bool_param(&query, "config"), Err(ClientError::BadRequest { .. })));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        // Patterns without a "/" match the file name in any directory
+        assert!(glob_match("*.safetensors", "model.safetensors"));
+        assert!(glob_match("*.safetensors", "onnx/model.safetensors"));
+        assert!(!glob_match("*.bin", "model.safetensors"));
+        assert!(glob_match("config.json", "config.json"));
+        assert!(glob_match("model-?????-of-00002.bin", "model-00001-of-00002.bin"));
+
+        // Patterns with a "/" match the full path
+        assert!(glob_match("onnx/*", "onnx/model.onnx"));
+        assert!(!glob_match("onnx/*", "onnx/quantized/model.onnx"));
+        assert!(glob_match("onnx/**", "onnx/quantized/model.onnx"));
+        assert!(glob_match("**/*.onnx", "model.onnx"));
+        assert!(glob_match("onnx/", "onnx/quantized/model.onnx"));
+        assert!(!glob_match("onnx/*", "other/onnx/model.onnx"));
+    }
+
+    #[test]
+    fn test_glob_match_does_not_backtrack_exponentially() {
+        let name = "a".repeat(64);
+        assert!(!glob_match(&format!("{}b", "a*".repeat(32)), &name));
+        assert!(glob_match(&format!("{}a", "a*".repeat(32)), &name));
+
+        let path = vec!["a"; 64].join("/");
+        assert!(!glob_match(&format!("{}b", "**/a/".repeat(16)), &path));
+        assert!(glob_match(&format!("{}a", "**/a/".repeat(16)), &path));
+    }
+
+    #[test]
+    fn test_encode_revision_and_path() {
+        assert_eq!(encode_revision("refs/pr/1"), "refs%2Fpr%2F1");
+        assert_eq!(encode_revision("v1.0_rc-1~2"), "v1.0_rc-1~2");
+        assert_eq!(encode_revision("a b?#"), "a%20b%3F%23");
+        assert_eq!(encode_path("docs/read me#1.md"), "docs/read%20me%231.md");
+        assert_eq!(encode_path("weights/model.safetensors"), "weights/model.safetensors");
+    }
+
+    #[test]
+    fn test_is_path_selected() {
+        let include = vec![String::from("*.json"), String::from("*.safetensors")];
+        let exclude = vec![String::from("tokenizer*")];
+
+        assert!(is_path_selected("config.json", &include, &exclude));
+        assert!(!is_path_selected("tokenizer.json", &include, &exclude));
+        assert!(!is_path_selected("pytorch_model.bin", &include, &exclude));
+        assert!(is_path_selected("pytorch_model.bin", &[], &exclude));
+    }
+}
@@ -0,0 +1,272 @@
+use crate::utils::{criterion_to_filters, matches_filter, parse_u64_param};
+use async_trait;
+use clients::{
+    ClientError, ClientErrorScope, ClientJsonResponse, DiscoverModelsClient, GetModelClient,
+    ListModelsClient,
+};
+use serde_json::{json, to_value, Value};
+use shared::application::errors::ApplicationError;
+use shared::application::inputs::filtering::Filter;
+use shared::application::ports::repositories::{ArtifactRepository, ModelMetadataRepository};
+use shared::domain::entities::artifact::{Artifact, ArtifactType};
+use shared::domain::entities::model_metadata::ModelMetadata;
+use shared::logging::SharedLogger;
+use shared::presentation::http::v1::dto::models::{
+    discovery_confidence, DiscoverModelsRequest, GetModelRequest, ListModelsRequest,
+    ModelMetadata as ModelMetadataDto,
+};
+use shared::presentation::http::v1::responses::{Artifact as ArtifactResponse, ModelSummary};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// A client for the artifacts stored in MLHub itself. Models are read from the
+/// artifact and metadata repositories rather than from a remote platform
+pub struct MlhubClient {
+    artifact_repo: Arc<dyn ArtifactRepository>,
+    model_metadata_repo: Arc<dyn ModelMetadataRepository>,
+    logger: SharedLogger,
+}
+
+#[async_trait::async_trait]
+impl ListModelsClient for MlhubClient {
+    type Data = Vec<ModelSummary>;
+    type Metadata = Value;
+
+    async fn list_models(
+        &self,
+        request: &ListModelsRequest,
+    ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+        self.logger.debug("List models");
+
+        let limit = parse_u64_param(&request.query, "limit")?.unwrap_or(Self::DEFAULT_LIST_LIMIT);
+        if limit == 0 || limit > Self::MAX_LIST_LIMIT {
+            return Err(ClientError::BadRequest {
+                msg: format!("Invalid limit '{}'. Expected a number between 1 and {}", limit, Self::MAX_LIST_LIMIT),
+                scope: ClientErrorScope::Client
+            })
+        }
+
+        let offset = parse_u64_param(&request.query, "offset")?.unwrap_or(0);
+
+        // Only models that were uploaded or have finished ingesting are listed
+        let models = self.artifact_repo.list_ingested_models(limit, offset)
+            .await
+            .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server })?;
+
+        Ok(ClientJsonResponse::new(
+            Some(200),
+            Some(String::from("success")),
+            Some(models.into_iter().map(|(artifact, metadata)| Self::build_summary(artifact, metadata, &request.path.platform)).collect()),
+            Some(json!({ "limit": limit, "offset": offset })),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl GetModelClient for MlhubClient {
+    type Data = ModelSummary;
+    type Metadata = Value;
+
+    async fn get_model(
+        &self,
+        request: &GetModelRequest,
+    ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+        self.logger.debug("Get model");
+
+        let artifact_id = Uuid::parse_str(&request.path.model_id)
+            .map_err(|err| ClientError::BadRequest { msg: format!("Invalid model id: {}", err), scope: ClientErrorScope::Client })?;
+
+        let artifact = self.artifact_repo.find_by_id(&artifact_id)
+            .await
+            .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server })?
+            .filter(Self::is_ingested_model)
+            .ok_or_else(|| ClientError::NotFound { msg: format!("Model '{}' not found", artifact_id), scope: ClientErrorScope::Server })?;
+
+        let metadata = self.find_metadata(&artifact.id).await?;
+
+        Ok(ClientJsonResponse::new(
+            Some(200),
+            Some(String::from("success")),
+            Some(Self::build_summary(artifact, metadata, &request.path.platform)),
+            None,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl DiscoverModelsClient for MlhubClient {
+    type Data = Value;
+    type Metadata = Value;
+
+    async fn discover_models(
+        &self,
+        request: &DiscoverModelsRequest,
+    ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
+        self.logger.debug("Discover models");
+
+        let thresholds = request.body.confidence_thresholds()
+            .map_err(|msg| ClientError::BadRequest { msg, scope: ClientErrorScope::Client })?;
+
+        // A model is discovered if it matches any of the criteria. Criteria
+        // without any field that can be filtered on match nothing
+        let mut queries: Vec<(Vec<Filter>, f64)> = Vec::with_capacity(request.body.criteria.len());
+        for (criterion, threshold) in request.body.criteria.iter().zip(thresholds) {
+            let criterion = to_value(criterion)
+                .map_err(|err| ClientError::BadRequest { msg: err.to_string(), scope: ClientErrorScope::Client })?;
+
+            let filters = criterion_to_filters(&criterion);
+            if !filters.is_empty() {
+                queries.push((filters, threshold));
+            }
+        }
+
+        // Candidates match at least one field of any criterion and are then
+        // scored against each criterion. No criteria matches every model
+        let filters: Vec<Filter> = queries.iter()
+            .flat_map(|(filters, _)| filters.iter().cloned())
+            .collect();
+
+        if filters.is_empty() && !request.body.criteria.is_empty() {
+            return Ok(ClientJsonResponse::new(
+                Some(200),
+                Some(String::from("success")),
+                Some(Value::Array(Vec::new())),
+                Some(json!({ "results": [] })),
+            ))
+        }
+
+        let candidates = self.model_metadata_repo.find_any(&filters, Self::DISCOVERY_LIMIT)
+            .await
+            .map_err(|err| match err {
+                ApplicationError::ConvesionError(msg) => ClientError::BadRequest { msg, scope: ClientErrorScope::Client },
+                err => ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server }
+            })?;
+
+        let mut matches: Vec<(Uuid, Value, f64, Vec<String>)> = Vec::new();
+        for (artifact_id, metadata) in candidates {
+            let internal = |msg: String| ClientError::Internal { msg, scope: ClientErrorScope::Server };
+            let dto = to_value(ModelMetadataDto::try_from(metadata).map_err(|err| internal(err.to_string()))?)
+                .map_err(|err| internal(err.to_string()))?;
+
+            // Keep the criterion the model matches best
+            let mut best: Option<(f64, Vec<String>)> = if queries.is_empty() { Some((1.0, Vec::new())) } else { None };
+            for (filters, threshold) in &queries {
+                let matched_fields: Vec<String> = filters.iter()
+                    .filter(|filter| matches_filter(&dto, filter))
+                    .map(|filter| filter.field.clone())
+                    .collect();
+
+                if let Some(confidence) = discovery_confidence(matched_fields.len(), filters.len(), *threshold) {
+                    if best.as_ref().is_none_or(|(best, _)| confidence > *best) {
+                        best = Some((confidence, matched_fields));
+                    }
+                }
+            }
+
+            if let Some((confidence, matched_fields)) = best {
+                matches.push((artifact_id, dto, confidence, matched_fields));
+            }
+        }
+
+        matches.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+        let mut models = Vec::with_capacity(matches.len());
+        let mut scores = Vec::with_capacity(matches.len());
+        for (artifact_id, metadata, confidence, matched_fields) in matches {
+            let artifact = self.artifact_repo.find_by_id(&artifact_id)
+                .await
+                .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server })?;
+
+            // Metadata can outlive its artifact if the artifact was removed, and
+            // is created before a model has finished ingesting
+            if let Some(artifact) = artifact.filter(Self::is_ingested_model) {
+                models.push(self.build_model(artifact, metadata)?);
+                scores.push(json!({
+                    "id": artifact_id.to_string(),
+                    "confidence": confidence,
+                    "matched_fields": matched_fields,
+                }));
+            }
+        }
+
+        Ok(ClientJsonResponse::new(
+            Some(200),
+            Some(String::from("success")),
+            Some(Value::Array(models)),
+            Some(json!({ "results": scores })),
+        ))
+    }
+}
+
+impl MlhubClient {
+    /// Maximum number of candidate models scored by a discovery request
+    const DISCOVERY_LIMIT: u64 = 100;
+
+    const DEFAULT_LIST_LIMIT: u64 = 10;
+
+    const MAX_LIST_LIMIT: u64 = 100;
+
+    pub fn new(
+        artifact_repo: Arc<dyn ArtifactRepository>,
+        model_metadata_repo: Arc<dyn ModelMetadataRepository>,
+    ) -> Self {
+        Self {
+            artifact_repo,
+            model_metadata_repo,
+            logger: SharedLogger::new(),
+        }
+    }
+
+    /// Whether an artifact is a model that was uploaded or has finished
+    /// ingesting. The same models that are listed
+    fn is_ingested_model(artifact: &Artifact) -> bool {
+        artifact.artifact_type == ArtifactType::Model && artifact.is_fully_ingested()
+    }
+
+    async fn find_metadata(&self, artifact_id: &Uuid) -> Result<Option<ModelMetadata>, ClientError> {
+        self.model_metadata_repo.find_by_artifact_id(artifact_id)
+            .await
+            .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Server })
+    }
+
+    /// Maps a model and its metadata, if any, into the summary shared by every
+    /// platform. The platform is the name the client is registered under
+    fn build_summary(artifact: Artifact, metadata: Option<ModelMetadata>, platform: &str) -> ModelSummary {
+        let mut summary = ModelSummary {
+            id: artifact.id.to_string(),
+            platform: String::from(platform),
+            name: artifact.id.to_string(),
+            created_at: Some(String::from(artifact.created_at)),
+            last_modified: Some(String::from(artifact.last_modified)),
+            ..Default::default()
+        };
+
+        if let Some(metadata) = metadata {
+            summary.name = metadata.name.unwrap_or(summary.name);
+            summary.version = metadata.version;
+            summary.license = metadata.license;
+            summary.task = metadata.task_types.and_then(|task_types| task_types.into_iter().next());
+            summary.framework = metadata.framework;
+            summary.tags = metadata.labels.unwrap_or_default();
+        }
+
+        summary
+    }
+
+    /// Represents a model as its artifact along with its metadata
+    fn build_model(&self, artifact: Artifact, metadata: Value) -> Result<Value, ClientError> {
+        let mut model = to_value(ArtifactResponse::from(artifact))
+            .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client })?;
+
+        if let Value::Object(map) = &mut model {
+            map.insert(String::from("metadata"), metadata);
+        }
+
+        Ok(model)
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "client.test.rs"]
+mod client_test;
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod client_test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use async_trait::async_trait;
+    use clients::{ClientError, DiscoverModelsClient, GetModelClient, ListModelsClient};
+    use serde_json::{json, Value};
+    use shared::application::errors::ApplicationError;
+    use shared::application::inputs::filtering::{Filter, ListAll};
+    use shared::application::inputs::model_metadata::{CreateModelMetadata, ModelMetadata as ModelMetadataInput};
+    use shared::application::ports::repositories::{ArtifactRepository, ModelMetadataRepository};
+    use shared::domain::entities::artifact::{Artifact, ArtifactType};
+    use shared::domain::entities::model_metadata::ModelMetadata;
+    use shared::presentation::http::v1::dto::headers::Headers;
+    use shared::presentation::http::v1::dto::models::{
+        DiscoverModelsPath, DiscoverModelsRequest, DiscoveryCriteriaBody, GetModelPath, GetModelRequest,
+        ListModelsPath, ListModelsRequest, ModelMetadata as ModelMetadataDto,
+    };
+    use uuid::Uuid;
+    use crate::client::MlhubClient;
+
+    /// Artifacts are returned in the order they were added
+    struct InMemoryArtifactRepository {
+        artifacts: Vec<Artifact>,
+    }
+
+    #[async_trait]
+    impl ArtifactRepository for InMemoryArtifactRepository {
+        async fn save(&self, _artifact: &Artifact) -> Result<(), ApplicationError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _artifact: &Artifact) -> Result<(), ApplicationError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: &Uuid) -> Result<Option<Artifact>, ApplicationError> {
+            Ok(self.artifacts.iter().find(|a| &a.id == id).cloned())
+        }
+
+        async fn list_all(&self) -> Result<Vec<Artifact>, ApplicationError> {
+            Ok(self.artifacts.clone())
+        }
+
+        async fn list_ingested_models(&self, limit: u64, offset: u64) -> Result<Vec<(Artifact, Option<ModelMetadata>)>, ApplicationError> {
+            Ok(self.artifacts.iter()
+                .filter(|a| a.artifact_type == ArtifactType::Model && a.is_fully_ingested())
+                .skip(offset as usize)
+                .take(limit as usize)
+                .map(|a| (a.clone(), None))
+                .collect())
+        }
+
+        async fn update_path(&self, _artifact: &Artifact) -> Result<(), ApplicationError> {
+            unimplemented!()
+        }
+    }
+
+    /// Every model has metadata with a name. Discovery candidates are every
+    /// model, regardless of the filters
+    struct InMemoryModelMetadataRepository {
+        artifact_ids: Vec<Uuid>,
+    }
+
+    #[async_trait]
+    impl ModelMetadataRepository for InMemoryModelMetadataRepository {
+        async fn save(&self, _input: &CreateModelMetadata) -> Result<(), ApplicationError> {
+            unimplemented!()
+        }
+
+        async fn find_by_artifact_id(&self, artifact_id: &Uuid) -> Result<Option<ModelMetadata>, ApplicationError> {
+            Ok(self.artifact_ids.iter().find(|id| *id == artifact_id).map(metadata))
+        }
+
+        async fn update(&self, _input: &CreateModelMetadata) -> Result<Option<ModelMetadata>, ApplicationError> {
+            unimplemented!()
+        }
+
+        async fn list(&self) -> Result<Vec<ModelMetadata>, ApplicationError> {
+            unimplemented!()
+        }
+
+        async fn find(&self, _input: &ListAll) -> Result<Vec<(Uuid, ModelMetadata)>, ApplicationError> {
+            unimplemented!()
+        }
+
+        async fn find_any(&self, _filters: &[Filter], limit: u64) -> Result<Vec<(Uuid, ModelMetadata)>, ApplicationError> {
+            Ok(self.artifact_ids.iter()
+                .take(limit as usize)
+                .map(|id| (*id, metadata(id)))
+                .collect())
+        }
+
+        async fn delete_by_artifact_id(&self, _artifact_id: &Uuid) -> Result<(), ApplicationError> {
+            unimplemented!()
+        }
+    }
+
+    /// Metadata named after the artifact it belongs to
+    fn metadata(artifact_id: &Uuid) -> ModelMetadata {
+        let dto: ModelMetadataDto = serde_json::from_value(json!({ "name": artifact_id.to_string() })).unwrap();
+        ModelMetadata::try_from(ModelMetadataInput::try_from(dto).unwrap()).unwrap()
+    }
+
+    /// An ingested model, a model that is still being ingested and an ingested
+    /// dataset, in that order
+    fn artifacts() -> Vec<Artifact> {
+        let mut ingested = Artifact::new(ArtifactType::Model);
+        ingested.set_path("/path/to/model".into());
+
+        let ingesting = Artifact::new(ArtifactType::Model);
+
+        let mut dataset = Artifact::new(ArtifactType::Dataset);
+        dataset.set_path("/path/to/dataset".into());
+
+        vec![ingested, ingesting, dataset]
+    }
+
+    fn client(artifacts: &[Artifact]) -> MlhubClient {
+        MlhubClient::new(
+            Arc::new(InMemoryArtifactRepository { artifacts: artifacts.to_vec() }),
+            Arc::new(InMemoryModelMetadataRepository { artifact_ids: artifacts.iter().map(|a| a.id).collect() }),
+        )
+    }
+
+    fn get_model_request(model_id: &str) -> GetModelRequest {
+        GetModelRequest {
+            headers: Headers::new(Vec::new()),
+            path: GetModelPath { platform: String::from("mlhub"), model_id: String::from(model_id) },
+            query: HashMap::new(),
+            body: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_models() {
+        let artifacts = artifacts();
+
+        let response = client(&artifacts).list_models(&ListModelsRequest {
+            headers: Headers::new(Vec::new()),
+            path: ListModelsPath { platform: String::from("mlhub") },
+            query: HashMap::from([(String::from("limit"), String::from("5"))]),
+            body: Default::default(),
+        }).await.unwrap();
+
+        let ids: Vec<String> = response.result.unwrap().into_iter().map(|model| model.id).collect();
+        assert_eq!(ids, vec![artifacts[0].id.to_string()]);
+        assert_eq!(response.metadata, Some(json!({ "limit": 5, "offset": 0 })));
+
+        for limit in ["0", "101"] {
+            let result = client(&artifacts).list_models(&ListModelsRequest {
+                headers: Headers::new(Vec::new()),
+                path: ListModelsPath { platform: String::from("mlhub") },
+                query: HashMap::from([(String::from("limit"), String::from(limit))]),
+                body: Default::default(),
+            }).await;
+
+            assert!(matches!(result, Err(ClientError::BadRequest { .. })));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_model() {
+        let artifacts = artifacts();
+
+        let response = client(&artifacts).get_model(&get_model_request(&artifacts[0].id.to_string())).await.unwrap();
+
+        let model = response.result.unwrap();
+        assert_eq!(model.id, artifacts[0].id.to_string());
+        assert_eq!(model.platform, "mlhub");
+        assert_eq!(model.name, artifacts[0].id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_get_model_not_listed() {
+        let artifacts = artifacts();
+
+        // Models that are still being ingested and datasets are not found,
+        // like they are not listed
+        for artifact in &artifacts[1..] {
+            let result = client(&artifacts).get_model(&get_model_request(&artifact.id.to_string())).await;
+
+            assert!(matches!(result, Err(ClientError::NotFound { .. })));
+        }
+
+        let result = client(&artifacts).get_model(&get_model_request("not-a-uuid")).await;
+        assert!(matches!(result, Err(ClientError::BadRequest { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_discover_models_only_returns_ingested_models() {
+        let artifacts = artifacts();
+
+        let response = client(&artifacts).discover_models(&DiscoverModelsRequest {
+            headers: Headers::new(Vec::new()),
+            path: DiscoverModelsPath { platform: String::from("mlhub") },
+            query: HashMap::new(),
+            body: DiscoveryCriteriaBody { criteria: Vec::new(), confidence_threshold: None },
+        }).await.unwrap();
+
+        let ids: Vec<Value> = response.result.unwrap().as_array().unwrap().iter()
+            .map(|model| model["id"].clone())
+            .collect();
+        assert_eq!(ids, vec![json!(artifacts[0].id.to_string())]);
+        assert_eq!(response.metadata.unwrap()["results"].as_array().unwrap().len(), 1);
+    }
+}
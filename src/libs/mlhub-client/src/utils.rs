@@ -0,0 +1,109 @@
+use clients::{ClientError, ClientErrorScope};
+use serde_json::Value;
+use shared::application::inputs::filtering::{Filter, FilterOperation};
+use std::collections::HashMap;
+
+/// Converts a discovery criterion into filters on the model metadata. Every
+/// field set on the criterion must match. Scalar values are compared for
+/// equality, lists of scalars match when any of their values match, and nested
+/// objects are matched field by field using dot notation. Lists of objects
+/// cannot be expressed as filters and are ignored
+pub(crate) fn criterion_to_filters(criterion: &Value) -> Vec<Filter> {
+    let mut filters = Vec::new();
+    collect_filters(criterion, None, &mut filters);
+    filters
+}
+
+fn collect_filters(value: &Value, prefix: Option<&str>, filters: &mut Vec<Filter>) {
+    let field = |key: &str| match prefix {
+        Some(p) => format!("{}.{}", p, key),
+        None => key.to_string()
+    };
+
+    let Value::Object(map) = value else {
+        return
+    };
+
+    for (key, value) in map {
+        match value {
+            Value::Null => continue,
+            Value::Object(_) => collect_filters(value, Some(&field(key)), filters),
+            Value::Array(values) => {
+                let scalars: Option<Vec<String>> = values.iter()
+                    .map(scalar_to_string)
+                    .collect();
+
+                match scalars {
+                    Some(scalars) if !scalars.is_empty() => filters.push(Filter {
+                        field: field(key),
+                        operation: FilterOperation::In,
+                        value: scalars.join(",")
+                    }),
+                    _ => continue
+                }
+            },
+            scalar => {
+                if let Some(value) = scalar_to_string(scalar) {
+                    filters.push(Filter {
+                        field: field(key),
+                        operation: FilterOperation::Eq,
+                        value
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None
+    }
+}
+
+/// Evaluates a filter built by `criterion_to_filters` against a model's
+/// metadata. Dotted fields are resolved through nested objects and a field
+/// holding a list matches when any of its values match
+pub(crate) fn matches_filter(metadata: &Value, filter: &Filter) -> bool {
+    let mut values = vec![metadata];
+    for key in filter.field.split(".") {
+        values = values.into_iter()
+            .flat_map(|value| match value {
+                Value::Array(items) => items.iter().collect(),
+                value => vec![value]
+            })
+            .filter_map(|value| value.get(key))
+            .collect();
+    }
+
+    let expected: Vec<&str> = match filter.operation {
+        FilterOperation::In => filter.value.split(",").collect(),
+        _ => vec![filter.value.as_str()]
+    };
+
+    values.into_iter()
+        .flat_map(|value| match value {
+            Value::Array(items) => items.iter().collect(),
+            value => vec![value]
+        })
+        .filter_map(scalar_to_string)
+        .any(|value| expected.contains(&value.as_str()))
+}
+
+/// Parses an optional non-negative integer from the query string
+pub(crate) fn parse_u64_param(query: &HashMap<String, String>, key: &str) -> Result<Option<u64>, ClientError> {
+    query.get(key)
+        .map(|value| value.parse::<u64>().map_err(|_| ClientError::BadRequest {
+            msg: format!("Invalid {} '{}'. Expected a non-negative integer", key, value),
+            scope: ClientErrorScope::Client
+        }))
+        .transpose()
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "utils.test.rs"]
+mod utils_test;
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod utils_test {
+    use std::collections::HashMap;
+    use serde_json::json;
+    use shared::application::inputs::filtering::{Filter, FilterOperation};
+    use crate::utils::{criterion_to_filters, matches_filter, parse_u64_param};
+
+    fn filter(field: &str, operation: FilterOperation, value: &str) -> Filter {
+        Filter { field: field.to_string(), operation, value: value.to_string() }
+    }
+
+    #[test]
+    fn test_criterion_to_filters() {
+        let criterion = json!({
+            "framework": "pytorch",
+            "name": null,
+            "task_types": ["text-generation", "summarization"],
+            "multi_modal": false,
+            "inference_hardware": {
+                "memory_gb": 16,
+                "accelerators": [{ "accelerator_type": "gpu" }]
+            }
+        });
+
+        let mut filters: Vec<(String, FilterOperation, String)> = criterion_to_filters(&criterion)
+            .into_iter()
+            .map(|f| (f.field, f.operation, f.value))
+            .collect();
+        filters.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(filters, vec![
+            ("framework".into(), FilterOperation::Eq, "pytorch".into()),
+            ("inference_hardware.memory_gb".into(), FilterOperation::Eq, "16".into()),
+            ("multi_modal".into(), FilterOperation::Eq, "false".into()),
+            ("task_types".into(), FilterOperation::In, "text-generation,summarization".into()),
+        ]);
+    }
+
+    #[test]
+    fn test_empty_criterion() {
+        assert!(criterion_to_filters(&json!({ "name": null })).is_empty());
+    }
+
+    #[test]
+    fn test_matches_filter() {
+        let metadata = json!({
+            "framework": "pytorch",
+            "multi_modal": false,
+            "task_types": ["text-generation", "summarization"],
+            "inference_hardware": {
+                "memory_gb": 16,
+                "accelerators": [{ "accelerator_type": "gpu" }, { "accelerator_type": "tpu" }]
+            }
+        });
+
+        assert!(matches_filter(&metadata, &filter("framework", FilterOperation::Eq, "pytorch")));
+        assert!(!matches_filter(&metadata, &filter("framework", FilterOperation::Eq, "tensorflow")));
+        assert!(matches_filter(&metadata, &filter("multi_modal", FilterOperation::Eq, "false")));
+        assert!(matches_filter(&metadata, &filter("task_types", FilterOperation::In, "translation,summarization")));
+        assert!(!matches_filter(&metadata, &filter("task_types", FilterOperation::In, "translation")));
+        assert!(matches_filter(&metadata, &filter("inference_hardware.memory_gb", FilterOperation::Eq, "16")));
+        assert!(matches_filter(&metadata, &filter("inference_hardware.accelerators.accelerator_type", FilterOperation::Eq, "tpu")));
+        assert!(!matches_filter(&metadata, &filter("license", FilterOperation::Eq, "mit")));
+    }
+
+    #[test]
+    fn test_parse_u64_param() {
+        let query = HashMap::from([
+            (String::from("limit"), String::from("20")),
+            (String::from("offset"), String::from("-1")),
+        ]);
+
+        assert_eq!(parse_u64_param(&query, "limit").unwrap(), Some(20));
+        assert_eq!(parse_u64_param(&query, "cursor").unwrap(), None);
+        assert!(parse_u64_param(&query, "offset").is_err());
+    }
+}
@@ -1,63 +1,57 @@
-use crate::utils::deserialize_response_body;
+use crate::constants;
+use crate::discovery::{DiscoveryQuery, DiscoveryScore};
+use crate::model_card::build_model_card;
+use crate::responses::{PatraModelCard, PatraUploadResponse};
+use crate::utils::{deserialize_response_body, request_error};
 use async_trait;
 use clients::{
-    ClientError, ClientErrorScope, ClientJsonResponse, DiscoverModelsClient, GetModelClient,
-    ListModelsClient, PublishModelMetadataClient
+    ClientConfig, ClientError, ClientErrorScope, ClientJsonResponse, DiscoverModelsClient,
+    GetModelClient, ListModelsClient, PublicationResult, PublishModelMetadataClient
 };
-use reqwest::blocking::Client as ReqwestClient;
-use serde_json::Value;
+use reqwest::{Client as ReqwestClient, RequestBuilder};
+use serde_json::{from_value, json, Value};
 use shared::logging::SharedLogger;
+use shared::presentation::http::v1::responses::ModelSummary;
+use shared::presentation::http::v1::dto::headers::Headers;
 use shared::presentation::http::v1::dto::models::{
     DiscoverModelsRequest, GetModelRequest, ListModelsRequest,
 };
 use shared::presentation::http::v1::dto::artifacts::PublishArtifactRequest;
 use shared::domain::entities:: model_metadata::ModelMetadata;
 use std::collections::hash_map::HashMap;
+use std::env;
 
 #[derive(Debug)]
 pub struct PatraClient {
     client: ReqwestClient,
+    base_url: String,
+    access_token: Option<String>,
     logger: SharedLogger,
 }
 
 #[async_trait::async_trait]
 impl ListModelsClient for PatraClient {
-    type Data = Value;
+    type Data = Vec<ModelSummary>;
     type Metadata = Value;
     async fn list_models(
         &self,
-        _request: &ListModelsRequest,
+        request: &ListModelsRequest,
     ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
         self.logger.debug("List models");
         let resp = self
-            .client
-            .get(PatraClient::LIST_MODELS_ENDPOINT)
+            .get(constants::LIST_MODELS_PATH, &request.headers)?
             .send()
-            .map_err(|err| {
-                let msg = err.to_string();
-                if err.is_body() {
-                    ClientError::BadRequest {
-                        msg,
-                        scope: ClientErrorScope::Client,
-                    }
-                } else if err.is_connect() {
-                    ClientError::Unavailable(err.to_string())
-                } else {
-                    ClientError::Internal {
-                        msg: "An unknown error occured".into(),
-                        scope: ClientErrorScope::Client,
-                    }
-                }
-            })?;
+            .await
+            .map_err(request_error)?;
 
         let status_code = resp.status().as_u16();
 
-        let deserialized_resp = deserialize_response_body(resp)?;
+        let model_cards: Vec<PatraModelCard> = deserialize_response_body(resp).await?;
 
         return Ok(ClientJsonResponse::new(
             Some(status_code),
             Some(String::from("success")),
-            Some(deserialized_resp),
+            Some(model_cards.into_iter().map(|model_card| model_card.into_summary(&request.path.platform)).collect()),
             None,
         ));
     }
@@ -65,7 +59,7 @@ impl ListModelsClient for PatraClient {
 
 #[async_trait::async_trait]
 impl GetModelClient for PatraClient {
-    type Data = Value;
+    type Data = ModelSummary;
     type Metadata = Value;
 
     async fn get_model(
@@ -77,35 +71,20 @@ impl GetModelClient for PatraClient {
         let mut query_params = HashMap::new();
         query_params.insert("id", request.path.model_id.clone());
         let resp = self
-            .client
-            .get(PatraClient::GET_MODEL_ENDPOINT)
+            .get(constants::GET_MODEL_PATH, &request.headers)?
             .query(&query_params)
             .send()
-            .map_err(|err| {
-                let msg = err.to_string();
-                if err.is_body() {
-                    ClientError::BadRequest {
-                        msg,
-                        scope: ClientErrorScope::Client,
-                    }
-                } else if err.is_connect() {
-                    ClientError::Unavailable(err.to_string())
-                } else {
-                    ClientError::Internal {
-                        msg: "An unknown error occured".into(),
-                        scope: ClientErrorScope::Client,
-                    }
-                }
-            })?;
+            .await
+            .map_err(request_error)?;
 
         let status_code = resp.status().as_u16();
 
-        let deserialized_resp = deserialize_response_body(resp)?;
+        let model_card: PatraModelCard = deserialize_response_body(resp).await?;
 
         return Ok(ClientJsonResponse::new(
             Some(status_code),
             Some(String::from("success")),
-            Some(deserialized_resp),
+            Some(model_card.into_summary(&request.path.platform)),
             None,
         ));
     }
@@ -121,82 +100,178 @@ impl DiscoverModelsClient for PatraClient {
         request: &DiscoverModelsRequest,
     ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
         self.logger.debug("Discover models");
-        let mut query_params = HashMap::new();
-        if request.body.criteria.len() > 0 {
-            // We are only taking the first critera because Patra has not implemented
-            // a way to search on more than one criterion
-            let name = request.body.criteria[0]
-                .name
-                .clone()
-                .unwrap_or(String::from(""));
-            query_params.insert("q", name);
+        let queries = DiscoveryQuery::build(&request.body)?;
+
+        // Criteria without any field Patra can evaluate match nothing
+        if queries.is_empty() && !request.body.criteria.is_empty() {
+            return Ok(ClientJsonResponse::new(
+                Some(200),
+                Some(String::from("success")),
+                Some(Value::Array(Vec::new())),
+                Some(json!({ "results": [] })),
+            ))
         }
 
-        let resp = self
-            .client
-            .get(PatraClient::SEARCH_MODEL_ENDPOINT)
-            .query(&query_params)
-            .send()
-            .map_err(|err| {
-                let msg = err.to_string();
-                if err.is_body() {
-                    ClientError::BadRequest {
-                        msg,
-                        scope: ClientErrorScope::Client,
-                    }
-                } else if err.is_connect() {
-                    ClientError::Unavailable(err.to_string())
-                } else {
-                    ClientError::Internal {
-                        msg: "An unknown error occured".into(),
-                        scope: ClientErrorScope::Client,
-                    }
+        // Model cards found by any of the searches keyed by card id
+        let mut candidates: Vec<(String, Value)> = Vec::new();
+        for terms in DiscoveryQuery::searches(&queries) {
+            for card in self.search(terms, &request.headers).await? {
+                let id = Self::discovered_card_id(&card);
+                if !candidates.iter().any(|(existing_id, _)| existing_id == &id) {
+                    candidates.push((id, card));
                 }
-            })?;
+            }
+        }
 
-        let status_code = resp.status().as_u16();
+        // Candidates are scored against each criterion and keep their best
+        // score. Without any criteria every model card is discovered
+        let mut matches: Vec<(String, Value, DiscoveryScore)> = Vec::new();
+        for (id, card) in candidates {
+            let parsed: PatraModelCard = from_value(card.clone()).unwrap_or_default();
+            let mut best: Option<DiscoveryScore> = if queries.is_empty() {
+                Some(DiscoveryScore { confidence: 1.0, matched_fields: Vec::new() })
+            } else {
+                None
+            };
 
-        let deserialized_resp = deserialize_response_body(resp)?;
+            for score in queries.iter().filter_map(|query| query.score(&parsed)) {
+                if best.as_ref().is_none_or(|best| score.confidence > best.confidence) {
+                    best = Some(score);
+                }
+            }
+
+            if let Some(score) = best {
+                matches.push((id, card, score));
+            }
+        }
+
+        // Best matches first
+        matches.sort_by(|(_, _, a), (_, _, b)| b.confidence.total_cmp(&a.confidence));
+
+        let scores: Vec<Value> = matches.iter()
+            .map(|(id, _, score)| json!({
+                "id": id,
+                "confidence": score.confidence,
+                "matched_fields": score.matched_fields,
+            }))
+            .collect();
 
         return Ok(ClientJsonResponse::new(
-            Some(status_code),
+            Some(200),
             Some(String::from("success")),
-            Some(deserialized_resp),
-            None,
+            Some(Value::Array(matches.into_iter().map(|(_, card, _)| card).collect())),
+            Some(json!({ "results": scores })),
         ));
     }
 }
 
 #[async_trait::async_trait]
 impl PublishModelMetadataClient for PatraClient {
-    type Data = Value;
-    type Metadata = Value;
-
     async fn publish_model_metadata(
         &self,
-        _metadata: &ModelMetadata,
-        _request: &PublishArtifactRequest,
-    ) -> Result<ClientJsonResponse<Self::Data, Self::Metadata>, ClientError> {
-        return Ok(
-            ClientJsonResponse::new(
-                None,
-                None,
-                None,
-                None
-            )
-        )
+        metadata: &ModelMetadata,
+        request: &PublishArtifactRequest,
+    ) -> Result<PublicationResult, ClientError> {
+        self.logger.debug("Publish model metadata");
+        let card = build_model_card(metadata)?;
+
+        // Unlike reading model cards, uploading one always requires the token
+        // of the caller. The configured token is never used so that cards are
+        // not published under the operator's account
+        let token = request.headers.bearer_token()?
+            .ok_or(ClientError::MissingInvalidCredentials(String::from("An access token is required to publish a model card to Patra")))?;
+
+        let resp = self.client.post(self.format_url(constants::UPLOAD_MODEL_CARD_PATH))
+            .bearer_auth(token)
+            .json(&card)
+            .send()
+            .await
+            .map_err(request_error)?;
+
+        let upload: PatraUploadResponse = deserialize_response_body(resp).await?;
+
+        let published_ids = upload.published_ids();
+        if !published_ids.contains_key("model_card_id") {
+            return Err(ClientError::Internal {
+                msg: String::from("Patra did not return the id of the uploaded model card"),
+                scope: ClientErrorScope::Server
+            })
+        }
+
+        Ok(PublicationResult::new(published_ids))
     }
 }
 
 impl PatraClient {
-    const LIST_MODELS_ENDPOINT: &str = "https://patraserver.pods.icicleai.tapis.io/list";
-    const GET_MODEL_ENDPOINT: &str = "https://patraserver.pods.icicleai.tapis.io/download_mc";
-    const SEARCH_MODEL_ENDPOINT: &str = "https://patraserver.pods.icicleai.tapis.io/search";
+    pub fn new() -> Result<Self, ClientError> {
+        Self::with_config(&ClientConfig::default())
+    }
 
-    pub fn new() -> Self {
-        Self {
-            client: ReqwestClient::new(),
+    /// Builds a client from the provided config. The base url is resolved from
+    /// the config, then the PATRA_ENDPOINT env var, and finally defaults to the
+    /// public Patra server
+    pub fn with_config(config: &ClientConfig) -> Result<Self, ClientError> {
+        Ok(Self {
+            client: config.http_client()?,
+            base_url: config.base_url.clone()
+                .or_else(|| env::var(constants::PATRA_ENDPOINT_ENV_VAR).ok())
+                .filter(|url| !url.trim().is_empty())
+                .unwrap_or(String::from(constants::PATRA_BASE_URL))
+                .trim_end_matches("/")
+                .to_string(),
+            access_token: config.access_token.clone(),
             logger: SharedLogger::new(),
+        })
+    }
+
+    /// Builds a GET request to a Patra endpoint. The request is authorized with
+    /// the token of the request's authorization header, falling back to the
+    /// token the client was configured with. Patra does not require a token
+    /// for public model cards
+    fn get(&self, path: &str, headers: &Headers) -> Result<RequestBuilder, ClientError> {
+        let request = self.client.get(self.format_url(path));
+
+        Ok(match headers.bearer_token()?.or(self.access_token.clone()) {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        })
+    }
+
+    /// Searches Patra's model cards. Patra responds with a list of model cards,
+    /// or a single model card if only one matched
+    async fn search(&self, terms: Option<String>, headers: &Headers) -> Result<Vec<Value>, ClientError> {
+        let mut request = self.get(constants::SEARCH_MODELS_PATH, headers)?;
+        if let Some(terms) = terms {
+            request = request.query(&[("q", terms)]);
         }
+
+        let resp = request.send()
+            .await
+            .map_err(request_error)?;
+
+        let cards = match deserialize_response_body(resp).await? {
+            Value::Array(cards) => cards,
+            Value::Null => Vec::new(),
+            card => vec![card],
+        };
+
+        Ok(cards)
+    }
+
+    /// The id used to deduplicate cards discovered by more than one criterion
+    fn discovered_card_id(card: &Value) -> String {
+        from_value::<PatraModelCard>(card.clone())
+            .ok()
+            .and_then(|parsed| parsed.card_id().or(parsed.name))
+            .unwrap_or(card.to_string())
+    }
+
+    fn format_url(&self, path: &str) -> String {
+        format!("{}/{}", &self.base_url, path.strip_prefix("/").unwrap_or(path))
     }
 }
+
+// Unit tests
+#[cfg(test)]
+#[path = "client.test.rs"]
+mod client_test;
@@ -0,0 +1,330 @@
+#[cfg(test)]
+mod client_test {
+    use std::collections::HashMap;
+    use std::env;
+    use std::thread;
+    use std::time::Duration;
+    use clients::{ClientConfig, ClientError, ClientErrorScope, DiscoverModelsClient, GetModelClient, ListModelsClient, PublishModelMetadataClient};
+    use serde_json::{json, Value};
+    use shared::domain::entities::model_metadata::ModelMetadata;
+    use shared::presentation::http::v1::dto::artifacts::{PublishArtifactBody, PublishArtifactPath, PublishArtifactRequest};
+    use shared::presentation::http::v1::dto::headers::Headers;
+    use shared::presentation::http::v1::dto::models::{
+        DiscoverModelsPath, DiscoverModelsRequest, DiscoveryCriteriaBody, GetModelPath,
+        GetModelRequest, ListModelsPath, ListModelsRequest,
+    };
+    use crate::client::PatraClient;
+    use crate::constants;
+    use test_support::stub_server::{self, StubRequest, StubResponse};
+
+    fn client_for(base_url: &str) -> PatraClient {
+        PatraClient::with_config(&ClientConfig {
+            base_url: Some(String::from(base_url)),
+            ..Default::default()
+        }).unwrap()
+    }
+
+    fn list_models_request(headers: Vec<(String, String)>) -> ListModelsRequest {
+        ListModelsRequest {
+            headers: Headers::new(headers),
+            path: ListModelsPath { platform: String::from("patra") },
+            query: HashMap::new(),
+            body: Default::default(),
+        }
+    }
+
+    fn get_model_request(model_id: &str) -> GetModelRequest {
+        GetModelRequest {
+            headers: Headers::new(Vec::new()),
+            path: GetModelPath {
+                platform: String::from("patra"),
+                model_id: String::from(model_id),
+            },
+            query: HashMap::new(),
+            body: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_base_url_resolution() {
+        env::set_var(constants::PATRA_ENDPOINT_ENV_VAR, "https://patra.example.org/");
+        assert_eq!(PatraClient::new().unwrap().format_url("list"), "https://patra.example.org/list");
+        assert_eq!(client_for("http://localhost:5002").format_url("/search"), "http://localhost:5002/search");
+
+        env::remove_var(constants::PATRA_ENDPOINT_ENV_VAR);
+        assert_eq!(PatraClient::new().unwrap().format_url("list"), format!("{}/list", constants::PATRA_BASE_URL));
+    }
+
+    #[tokio::test]
+    async fn test_list_models() {
+        let (base_url, requests) = stub_server::start(|_: &StubRequest| {
+            StubResponse::json(json!([{ "mc_id": 1, "name": "MegaDetector", "author": "ICICLE" }]).to_string())
+        });
+
+        let headers = vec![(String::from("authorization"), String::from("Bearer patra_token"))];
+        let response = client_for(&base_url).list_models(&list_models_request(headers)).await.unwrap();
+
+        let models = response.result.unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].id, "1");
+        assert_eq!(models[0].platform, "patra");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "GET /list HTTP/1.1");
+        assert_eq!(request.header("authorization"), Some("Bearer patra_token"));
+    }
+
+    #[tokio::test]
+    async fn test_configured_access_token() {
+        let (base_url, requests) = stub_server::start(|_: &StubRequest| StubResponse::json(json!([]).to_string()));
+        let client = PatraClient::with_config(&ClientConfig {
+            base_url: Some(base_url),
+            access_token: Some(String::from("configured_token")),
+            ..Default::default()
+        }).unwrap();
+
+        client.list_models(&list_models_request(Vec::new())).await.unwrap();
+
+        assert_eq!(requests.recv().unwrap().header("authorization"), Some("Bearer configured_token"));
+    }
+
+    #[tokio::test]
+    async fn test_get_model() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            match request.path() {
+                "/download_mc?id=megadetector" => StubResponse::json(json!({ "external_id": "megadetector", "name": "MegaDetector" }).to_string()),
+                _ => StubResponse {
+                    status: 404,
+                    headers: Vec::new(),
+                    body: json!({ "error": "Model card not found" }).to_string().into_bytes(),
+                },
+            }
+        });
+        let client = client_for(&base_url);
+
+        let model = client.get_model(&get_model_request("megadetector")).await.unwrap().result.unwrap();
+        assert_eq!(model.id, "megadetector");
+        assert_eq!(model.name, "MegaDetector");
+        assert_eq!(requests.recv().unwrap().header("authorization"), None);
+
+        assert!(matches!(
+            client.get_model(&get_model_request("missing")).await,
+            Err(ClientError::NotFound { ref msg, scope: ClientErrorScope::Server }) if msg.contains("Model card not found")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_discover_models() {
+        let (base_url, requests) = stub_server::start(|_: &StubRequest| {
+            StubResponse::json(json!([{ "mc_id": 1, "name": "MegaDetector" }]).to_string())
+        });
+
+        let request = DiscoverModelsRequest {
+            headers: Headers::new(Vec::new()),
+            path: DiscoverModelsPath { platform: String::from("patra") },
+            query: HashMap::new(),
+            body: DiscoveryCriteriaBody {
+                criteria: vec![serde_json::from_value(json!({ "name": "MegaDetector" })).unwrap()],
+                confidence_threshold: None,
+            },
+        };
+
+        let response = client_for(&base_url).discover_models(&request).await.unwrap();
+
+        assert_eq!(response.result, Some(json!([{ "mc_id": 1, "name": "MegaDetector" }])));
+        let request = requests.recv().unwrap();
+        assert_eq!(request.method(), "GET");
+        assert_eq!(request.path(), "/search?q=MegaDetector");
+        assert!(request.body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_discover_models_ranks_matches() {
+        let mega_detector = json!({ "mc_id": 1, "name": "MegaDetector", "ai_model": { "framework": "pytorch", "license": "MIT" } });
+        let bird_detector = json!({ "mc_id": 2, "name": "BirdDetector", "keywords": "birds", "ai_model": { "framework": "tensorflow", "license": "MIT" } });
+        let classifier = json!({ "mc_id": 3, "name": "Classifier", "ai_model": { "framework": "pytorch" } });
+
+        let (base_url, requests) = stub_server::start(move |request: &StubRequest| {
+            let cards = match request.path() {
+                "/search?q=detector" => json!([mega_detector, bird_detector]),
+                "/search?q=pytorch" => json!([mega_detector, classifier]),
+                "/search?q=birds" => bird_detector.clone(),
+                _ => json!([]),
+            };
+            StubResponse::json(cards.to_string())
+        });
+
+        let request = DiscoverModelsRequest {
+            headers: Headers::new(Vec::new()),
+            path: DiscoverModelsPath { platform: String::from("patra") },
+            query: HashMap::new(),
+            body: serde_json::from_value(json!({
+                "criteria": [
+                    { "name": "detector", "framework": "pytorch", "license": "MIT" },
+                    { "labels": ["birds"], "license": "MIT" },
+                ],
+                "confidence_threshold": ["0.5"],
+            })).unwrap(),
+        };
+
+        let response = client_for(&base_url).discover_models(&request).await.unwrap();
+
+        let names: Vec<Value> = response.result.unwrap().as_array().unwrap().iter()
+            .map(|card| card["name"].clone())
+            .collect();
+        assert_eq!(names, vec![json!("MegaDetector"), json!("BirdDetector")]);
+        assert_eq!(response.metadata, Some(json!({ "results": [
+            { "id": "1", "confidence": 1.0, "matched_fields": ["name", "framework", "license"] },
+            { "id": "2", "confidence": 1.0, "matched_fields": ["license", "labels.birds"] },
+        ]})));
+
+        // Every term is searched on its own
+        let paths: Vec<String> = requests.try_iter().map(|request| request.path().to_string()).collect();
+        assert_eq!(paths, vec!["/search?q=detector", "/search?q=pytorch", "/search?q=birds"]);
+    }
+
+    #[tokio::test]
+    async fn test_discover_models_skips_criteria_patra_cannot_evaluate() {
+        let (base_url, requests) = stub_server::start(|_: &StubRequest| {
+            StubResponse::json(json!([{ "mc_id": 1, "name": "MegaDetector" }]).to_string())
+        });
+
+        let request = DiscoverModelsRequest {
+            headers: Headers::new(Vec::new()),
+            path: DiscoverModelsPath { platform: String::from("patra") },
+            query: HashMap::new(),
+            body: serde_json::from_value(json!({
+                "criteria": [{ "inference_hardware": { "cpus": 4 } }],
+            })).unwrap(),
+        };
+
+        let response = client_for(&base_url).discover_models(&request).await.unwrap();
+
+        assert_eq!(response.result, Some(json!([])));
+        assert_eq!(response.metadata, Some(json!({ "results": [] })));
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let (base_url, _requests) = stub_server::start(|_: &StubRequest| {
+            thread::sleep(Duration::from_millis(1500));
+            StubResponse::json(json!([]).to_string())
+        });
+        let client = PatraClient::with_config(&ClientConfig {
+            base_url: Some(base_url),
+            timeout_secs: Some(1),
+            ..Default::default()
+        }).unwrap();
+
+        assert!(matches!(
+            client.list_models(&list_models_request(Vec::new())).await,
+            Err(ClientError::Unavailable(_))
+        ));
+    }
+
+    fn metadata(name: &str) -> ModelMetadata {
+        ModelMetadata {
+            name: Some(String::from(name)),
+            model_type: None,
+            version: Some(String::from("5.0")),
+            framework: None,
+            image: None,
+            labels: None,
+            label_map: None,
+            multi_modal: None,
+            model_inputs: None,
+            model_outputs: None,
+            task_types: None,
+            inference_precision: None,
+            inference_hardware: None,
+            inference_software_dependencies: None,
+            inference_max_energy_consumption_watts: None,
+            inference_max_latency_ms: Some(120),
+            inference_min_throughput: None,
+            inference_max_compute_utilization_percentage: None,
+            inference_max_memory_usage_mb: None,
+            inference_distributed: None,
+            training_time: None,
+            training_precision: None,
+            training_hardware: None,
+            pretraining_datasets: None,
+            finetuning_datasets: None,
+            edge_optimized: None,
+            quantization_aware: None,
+            supports_quantization: None,
+            pretrained: None,
+            pruned: None,
+            slimmed: None,
+            training_distributed: None,
+            training_max_energy_consumption_watts: None,
+            regulatory: None,
+            license: None,
+            bias_evaluation_score: None,
+        }
+    }
+
+    fn publish_request(headers: Vec<(String, String)>) -> PublishArtifactRequest {
+        PublishArtifactRequest {
+            headers: Headers::new(headers),
+            path: PublishArtifactPath { artifact_id: String::from("artifact") },
+            query: HashMap::new(),
+            body: PublishArtifactBody {
+                target_platform: String::from("patra"),
+                webhook_url: None,
+                options: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_model_metadata() {
+        let (base_url, requests) = stub_server::start(|request: &StubRequest| {
+            let body: Value = serde_json::from_slice(&request.body).unwrap_or_default();
+            match body["name"].as_str() {
+                Some("MegaDetector") => StubResponse::json(json!({
+                    "message": "Successfully uploaded the model card",
+                    "model_card_id": 42,
+                    "model_id": "megadetector-5.0",
+                }).to_string()),
+                _ => StubResponse::json(json!({ "message": "Successfully uploaded the model card" }).to_string()),
+            }
+        });
+        let client = client_for(&base_url);
+        let headers = vec![(String::from("authorization"), String::from("Bearer patra_token"))];
+
+        let result = client.publish_model_metadata(&metadata("MegaDetector"), &publish_request(headers.clone())).await.unwrap();
+
+        assert_eq!(result.published_ids.get("model_card_id").map(String::as_str), Some("42"));
+        assert_eq!(result.published_ids.get("model_id").map(String::as_str), Some("megadetector-5.0"));
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "POST /upload_mc HTTP/1.1");
+        assert_eq!(request.header("authorization"), Some("Bearer patra_token"));
+        let card: Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(card["ai_model"]["model_metrics"], json!({ "inference_max_latency_ms": 120 }));
+
+        // The upload is not considered successful if Patra does not return an id
+        assert!(matches!(
+            client.publish_model_metadata(&metadata("Untracked"), &publish_request(headers)).await,
+            Err(ClientError::Internal { scope: ClientErrorScope::Server, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_publish_model_metadata_requires_token() {
+        let (base_url, requests) = stub_server::start(|_: &StubRequest| StubResponse::json(json!({}).to_string()));
+
+        // The token the client was configured with is not used for uploads
+        let client = PatraClient::with_config(&ClientConfig {
+            base_url: Some(base_url),
+            access_token: Some(String::from("operator_token")),
+            ..Default::default()
+        }).unwrap();
+
+        let result = client.publish_model_metadata(&metadata("MegaDetector"), &publish_request(Vec::new())).await;
+
+        assert!(matches!(result, Err(ClientError::MissingInvalidCredentials(_))));
+        assert!(requests.try_recv().is_err());
+    }
+}
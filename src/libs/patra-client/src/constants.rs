@@ -0,0 +1,10 @@
+pub const PATRA_BASE_URL: &str = "https://patraserver.pods.icicleai.tapis.io";
+
+/// Env var overriding the base url of the Patra server (ex. an on-prem
+/// deployment)
+pub const PATRA_ENDPOINT_ENV_VAR: &str = "PATRA_ENDPOINT";
+
+pub const LIST_MODELS_PATH: &str = "list";
+pub const GET_MODEL_PATH: &str = "download_mc";
+pub const SEARCH_MODELS_PATH: &str = "search";
+pub const UPLOAD_MODEL_CARD_PATH: &str = "upload_mc";
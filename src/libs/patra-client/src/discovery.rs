@@ -0,0 +1,157 @@
+use clients::{ClientError, ClientErrorScope};
+use serde::Serialize;
+use shared::presentation::http::v1::dto::models::{discovery_confidence, DiscoveryCriteriaBody, ModelMetadata};
+use crate::responses::PatraModelCard;
+
+/// A discovery criterion translated into Patra searches. Patra only supports
+/// free-text search, so each descriptive field of the criterion is searched on
+/// its own and every field a model card carries is checked again locally
+#[derive(Debug, Clone)]
+pub struct DiscoveryQuery<'a> {
+    criterion: &'a ModelMetadata,
+    /// Minimum confidence a model card must have to match the criterion
+    threshold: f64,
+}
+
+/// How well a model card matches a discovery criterion
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct DiscoveryScore {
+    /// Fraction of the criterion's fields that the model card matches
+    pub confidence: f64,
+    pub matched_fields: Vec<String>,
+}
+
+impl<'a> DiscoveryQuery<'a> {
+    /// Builds one query per criterion. Criteria without any field Patra can
+    /// evaluate are skipped as they match nothing
+    pub fn build(body: &'a DiscoveryCriteriaBody) -> Result<Vec<Self>, ClientError> {
+        let thresholds = body.confidence_thresholds()
+            .map_err(|msg| ClientError::BadRequest { msg, scope: ClientErrorScope::Client })?;
+
+        Ok(body.criteria.iter()
+            .zip(thresholds)
+            .map(|(criterion, threshold)| Self { criterion, threshold })
+            .filter(|query| !query.checks(&PatraModelCard::default()).is_empty())
+            .collect())
+    }
+
+    /// The searches to send to Patra to find the candidates of every query,
+    /// where None is a search without the `q` query parameter. Terms are
+    /// searched one at a time as Patra only returns cards matching the whole
+    /// search string
+    pub fn searches(queries: &[Self]) -> Vec<Option<String>> {
+        let mut searches: Vec<Option<String>> = Vec::new();
+        for query in queries {
+            let terms = query.search_terms();
+
+            // A criterion without search terms can only be evaluated against
+            // every model card
+            if terms.is_empty() {
+                return vec![None]
+            }
+
+            for term in terms {
+                if !searches.iter().flatten().any(|search| search.eq_ignore_ascii_case(&term)) {
+                    searches.push(Some(term));
+                }
+            }
+        }
+
+        match searches.is_empty() {
+            true => vec![None],
+            false => searches,
+        }
+    }
+
+    /// The search terms of the criterion's descriptive fields
+    pub fn search_terms(&self) -> Vec<String> {
+        let criterion = self.criterion;
+        [&criterion.name, &criterion.model_type, &criterion.framework]
+            .into_iter()
+            .flatten()
+            .chain(criterion.task_types.iter().flatten())
+            .chain(criterion.labels.iter().flatten())
+            .map(|term| term.trim().to_string())
+            .filter(|term| !term.is_empty())
+            .collect()
+    }
+
+    /// Scores a model card against the criterion. Returns None if the card does
+    /// not reach the criterion's confidence threshold
+    pub fn score(&self, card: &PatraModelCard) -> Option<DiscoveryScore> {
+        let checks = self.checks(card);
+        let total = checks.len();
+        let matched_fields: Vec<String> = checks.into_iter()
+            .filter_map(|(field, matched)| matched.then_some(field))
+            .collect();
+
+        discovery_confidence(matched_fields.len(), total, self.threshold)
+            .map(|confidence| DiscoveryScore { confidence, matched_fields })
+    }
+
+    /// Each field of the criterion that Patra can evaluate and whether the
+    /// model card matches it
+    fn checks(&self, card: &PatraModelCard) -> Vec<(String, bool)> {
+        let criterion = self.criterion;
+        let ai_model = card.ai_model.clone().unwrap_or_default();
+        let keywords: Vec<String> = card.keywords().iter()
+            .map(|keyword| keyword.to_lowercase())
+            .collect();
+        let category = card.category.as_ref().or(card.categories.as_ref());
+
+        let mut checks: Vec<(String, bool)> = Vec::new();
+
+        if let Some(name) = &criterion.name {
+            checks.push((String::from("name"), contains(card.name.as_ref(), name)));
+        }
+
+        if let Some(version) = &criterion.version {
+            checks.push((String::from("version"), equals(card.version.as_ref().or(ai_model.version.as_ref()), version)));
+        }
+
+        if let Some(framework) = &criterion.framework {
+            checks.push((String::from("framework"), equals(ai_model.framework.as_ref(), framework)));
+        }
+
+        if let Some(model_type) = &criterion.model_type {
+            checks.push((String::from("model_type"), equals(ai_model.model_type.as_ref(), model_type)));
+        }
+
+        if let Some(license) = &criterion.license {
+            checks.push((String::from("license"), equals(ai_model.license.as_ref(), license)));
+        }
+
+        if let Some(task_types) = criterion.task_types.as_ref().filter(|tasks| !tasks.is_empty()) {
+            checks.push((String::from("task_types"), task_types.iter().any(|task| equals(category, task))));
+        }
+
+        // Every label is checked on its own so that cards sharing some of the
+        // labels rank above cards sharing none
+        for label in criterion.labels.iter().flatten() {
+            checks.push((format!("labels.{}", label), keywords.contains(&label.to_lowercase())));
+        }
+
+        let input_types: Vec<&String> = criterion.model_inputs.iter()
+            .flatten()
+            .filter_map(|input| input.data_type.as_ref())
+            .collect();
+        if !input_types.is_empty() {
+            checks.push((String::from("model_inputs"), input_types.into_iter().any(|data_type| equals(card.input_type.as_ref(), data_type))));
+        }
+
+        checks
+    }
+}
+
+fn equals(value: Option<&String>, expected: &str) -> bool {
+    value.is_some_and(|value| value.trim().eq_ignore_ascii_case(expected.trim()))
+}
+
+fn contains(value: Option<&String>, expected: &str) -> bool {
+    value.is_some_and(|value| value.to_lowercase().contains(&expected.trim().to_lowercase()))
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "discovery.test.rs"]
+mod discovery_test;
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod discovery_test {
+    use serde_json::{from_value, json, Value};
+    use clients::ClientError;
+    use shared::presentation::http::v1::dto::models::DiscoveryCriteriaBody;
+    use crate::discovery::DiscoveryQuery;
+    use crate::responses::PatraModelCard;
+
+    fn body(criteria: Value, confidence_threshold: Value) -> DiscoveryCriteriaBody {
+        from_value(json!({ "criteria": criteria, "confidence_threshold": confidence_threshold })).unwrap()
+    }
+
+    fn card() -> PatraModelCard {
+        from_value(json!({
+            "mc_id": 1,
+            "name": "MegaDetector",
+            "version": "5.0",
+            "keywords": "wildlife, camera traps",
+            "category": "object-detection",
+            "input_type": "image",
+            "ai_model": { "framework": "pytorch", "license": "MIT" },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_search_terms() {
+        let request_body = body(json!([
+            { "name": "megadetector", "framework": "pytorch", "task_types": ["object-detection"], "labels": ["wildlife"], "license": "MIT" },
+            { "version": "5.0" },
+        ]), Value::Null);
+
+        let queries = DiscoveryQuery::build(&request_body).unwrap();
+
+        // Only the descriptive fields are searched on
+        assert_eq!(queries[0].search_terms(), vec!["megadetector", "pytorch", "object-detection", "wildlife"]);
+        assert!(queries[1].search_terms().is_empty());
+    }
+
+    #[test]
+    fn test_searches() {
+        let request_body = body(json!([
+            { "name": "megadetector", "framework": "pytorch" },
+            { "framework": "PyTorch", "labels": ["wildlife"] },
+        ]), Value::Null);
+        let queries = DiscoveryQuery::build(&request_body).unwrap();
+
+        // Each term is searched once
+        assert_eq!(DiscoveryQuery::searches(&queries), vec![
+            Some(String::from("megadetector")),
+            Some(String::from("pytorch")),
+            Some(String::from("wildlife")),
+        ]);
+
+        // A criterion without search terms needs every model card
+        let request_body = body(json!([{ "name": "megadetector" }, { "version": "5.0" }]), Value::Null);
+        let queries = DiscoveryQuery::build(&request_body).unwrap();
+        assert_eq!(DiscoveryQuery::searches(&queries), vec![None]);
+
+        assert_eq!(DiscoveryQuery::searches(&[]), vec![None]);
+    }
+
+    #[test]
+    fn test_score() {
+        let request_body = body(json!([
+            { "name": "megadetector", "framework": "pytorch", "labels": ["wildlife", "birds"], "license": "apache-2.0" },
+            { "version": "5.0", "model_inputs": [{ "data_type": "image" }] },
+            { "framework": "tensorflow" },
+            { "inference_hardware": { "cpus": 4 } },
+        ]), Value::Null);
+
+        let queries = DiscoveryQuery::build(&request_body).unwrap();
+        let card = card();
+
+        let score = queries[0].score(&card).unwrap();
+        assert_eq!(score.confidence, 0.6);
+        assert_eq!(score.matched_fields, vec!["name", "framework", "labels.wildlife"]);
+
+        assert_eq!(queries[1].score(&card).unwrap().confidence, 1.0);
+        assert!(queries[2].score(&card).is_none());
+
+        // Patra cards carry no hardware so the criterion is skipped
+        assert_eq!(queries.len(), 3);
+    }
+
+    #[test]
+    fn test_confidence_threshold() {
+        let criteria = json!([
+            { "name": "megadetector", "license": "apache-2.0" },
+            { "framework": "pytorch", "license": "apache-2.0" },
+        ]);
+        let card = card();
+
+        // A single threshold applies to every criterion
+        let request_body = body(criteria.clone(), json!(["0.75"]));
+        let queries = DiscoveryQuery::build(&request_body).unwrap();
+        assert!(queries.iter().all(|query| query.score(&card).is_none()));
+
+        // Otherwise there is one threshold per criterion
+        let request_body = body(criteria.clone(), json!(["0.5", "0.75"]));
+        let queries = DiscoveryQuery::build(&request_body).unwrap();
+        assert!(queries[0].score(&card).is_some());
+        assert!(queries[1].score(&card).is_none());
+
+        for threshold in [json!(["0.5", "0.5", "0.5"]), json!(["high"]), json!(["1.5"])] {
+            let request_body = body(criteria.clone(), threshold);
+            assert!(matches!(DiscoveryQuery::build(&request_body), Err(ClientError::BadRequest { .. })));
+        }
+    }
+}
@@ -1,2 +1,6 @@
 pub mod client;
-pub(crate) mod utils;
\ No newline at end of file
+pub mod constants;
+pub mod discovery;
+pub mod model_card;
+pub mod responses;
+pub(crate) mod utils;
@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use clients::{ClientError, ClientErrorScope};
+use serde::Serialize;
+use serde_json::{json, Value};
+use shared::domain::entities::model_metadata::{Accelerator, HardwareRequirements, ModelIO, ModelMetadata};
+
+/// A model card as submitted to Patra's upload endpoint. Fields beyond Patra's
+/// core schema (hardware, io and regulatory standards) are stored alongside the
+/// card so that no metadata is lost in publication
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct NewPatraModelCard {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub short_description: String,
+    pub full_description: String,
+    /// Comma-separated keywords
+    pub keywords: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    pub ai_model: NewPatraAiModel,
+    /// Software the model depends on at inference time
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub requirements: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bias_analysis: Option<Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub training_datasets: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub model_inputs: Vec<PatraModelIO>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub model_outputs: Vec<PatraModelIO>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inference_hardware: Option<PatraHardwareRequirements>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub training_hardware: Option<PatraHardwareRequirements>,
+    /// Ex. HIPAA
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub regulatory_standards: Vec<String>,
+}
+
+/// The AI model described by a submitted model card
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct NewPatraAiModel {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framework: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inference_labels: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inference_precision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub training_precision: Option<String>,
+    /// Performance and energy metrics keyed by the name of the metric
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub model_metrics: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PatraModelIO {
+    pub data_type: Option<String>,
+    pub shape: Option<Vec<i32>>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PatraAccelerator {
+    pub accelerator_type: String,
+    pub memory_gb: Option<i32>,
+    pub cores: Option<i32>,
+    /// Firmware and software the accelerator requires. Ex. cuda==12.1
+    pub system_requirements: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PatraHardwareRequirements {
+    pub cpus: Option<i32>,
+    pub memory_gb: Option<i32>,
+    pub disk_gb: Option<i32>,
+    pub accelerators: Vec<PatraAccelerator>,
+    pub architectures: Vec<String>,
+}
+
+impl From<&ModelIO> for PatraModelIO {
+    fn from(value: &ModelIO) -> Self {
+        Self {
+            data_type: value.data_type.clone(),
+            shape: value.shape.clone(),
+        }
+    }
+}
+
+impl From<&Accelerator> for PatraAccelerator {
+    fn from(value: &Accelerator) -> Self {
+        Self {
+            accelerator_type: value.accelerator_type.clone(),
+            memory_gb: value.memory_gb,
+            cores: value.cores,
+            system_requirements: value.system_requirements.iter()
+                .map(|requirement| format!("{}=={}", requirement.name, requirement.version))
+                .collect(),
+        }
+    }
+}
+
+impl From<&HardwareRequirements> for PatraHardwareRequirements {
+    fn from(value: &HardwareRequirements) -> Self {
+        Self {
+            cpus: value.cpus,
+            memory_gb: value.memory_gb,
+            disk_gb: value.disk_gb,
+            accelerators: value.accelerators.iter()
+                .flatten()
+                .map(PatraAccelerator::from)
+                .collect(),
+            architectures: value.architectures.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Builds the Patra model card of a model from its metadata. Patra requires
+/// every model card to have a name
+pub fn build_model_card(metadata: &ModelMetadata) -> Result<NewPatraModelCard, ClientError> {
+    let name = metadata.name.clone()
+        .filter(|name| !name.trim().is_empty())
+        .ok_or(ClientError::BadRequest {
+            msg: String::from("Model metadata must have a name to be published to Patra"),
+            scope: ClientErrorScope::Client
+        })?;
+
+    let tasks = metadata.task_types.clone().unwrap_or_default();
+    let short_description = match tasks.is_empty() {
+        true => name.clone(),
+        false => format!("{} for {}", name, tasks.join(", ")),
+    };
+
+    let keywords: Vec<String> = metadata.labels.iter()
+        .flatten()
+        .chain(tasks.iter())
+        .cloned()
+        .collect();
+
+    let model_inputs: Vec<PatraModelIO> = metadata.model_inputs.iter()
+        .flatten()
+        .map(PatraModelIO::from)
+        .collect();
+
+    let card = NewPatraModelCard {
+        version: metadata.version.clone(),
+        short_description: short_description.clone(),
+        full_description: full_description(metadata),
+        keywords: keywords.join(","),
+        input_type: model_inputs.first().and_then(|input| input.data_type.clone()),
+        category: tasks.first().cloned(),
+        ai_model: NewPatraAiModel {
+            name: name.clone(),
+            version: metadata.version.clone(),
+            description: short_description,
+            location: metadata.image.clone(),
+            license: metadata.license.clone(),
+            framework: metadata.framework.clone(),
+            model_type: metadata.model_type.clone(),
+            inference_labels: metadata.label_map.clone(),
+            inference_precision: metadata.inference_precision.clone(),
+            training_precision: metadata.training_precision.clone(),
+            model_metrics: model_metrics(metadata),
+        },
+        requirements: metadata.inference_software_dependencies.clone().unwrap_or_default(),
+        bias_analysis: metadata.bias_evaluation_score
+            .map(|score| json!({ "bias_evaluation_score": score })),
+        training_datasets: metadata.pretraining_datasets.iter()
+            .chain(metadata.finetuning_datasets.iter())
+            .flatten()
+            .cloned()
+            .collect(),
+        model_inputs,
+        model_outputs: metadata.model_outputs.iter()
+            .flatten()
+            .map(PatraModelIO::from)
+            .collect(),
+        inference_hardware: metadata.inference_hardware.as_ref().map(PatraHardwareRequirements::from),
+        training_hardware: metadata.training_hardware.as_ref().map(PatraHardwareRequirements::from),
+        regulatory_standards: metadata.regulatory.clone().unwrap_or_default(),
+        name,
+    };
+
+    Ok(card)
+}
+
+fn model_metrics(metadata: &ModelMetadata) -> BTreeMap<String, Value> {
+    let metrics: Vec<(&str, Option<Value>)> = vec![
+        ("inference_max_latency_ms", metadata.inference_max_latency_ms.map(Value::from)),
+        ("inference_min_throughput", metadata.inference_min_throughput.map(Value::from)),
+        ("inference_max_compute_utilization_percentage", metadata.inference_max_compute_utilization_percentage.map(Value::from)),
+        ("inference_max_memory_usage_mb", metadata.inference_max_memory_usage_mb.map(Value::from)),
+        ("inference_max_energy_consumption_watts", metadata.inference_max_energy_consumption_watts.map(Value::from)),
+        ("training_max_energy_consumption_watts", metadata.training_max_energy_consumption_watts.map(Value::from)),
+        ("training_time", metadata.training_time.map(Value::from)),
+    ];
+
+    metrics.into_iter()
+        .filter_map(|(name, value)| value.map(|value| (String::from(name), value)))
+        .collect()
+}
+
+/// Summarizes the characteristics of the model that Patra has no dedicated
+/// field for
+fn full_description(metadata: &ModelMetadata) -> String {
+    let details: Vec<(&str, Option<String>)> = vec![
+        ("Model type", metadata.model_type.clone()),
+        ("Framework", metadata.framework.clone()),
+        ("Tasks", metadata.task_types.as_ref().map(|tasks| tasks.join(", "))),
+        ("Multi-modal", metadata.multi_modal.map(|value| value.to_string())),
+        ("Pretrained", metadata.pretrained.map(|value| value.to_string())),
+        ("Pruned", metadata.pruned.map(|value| value.to_string())),
+        ("Slimmed", metadata.slimmed.map(|value| value.to_string())),
+        ("Edge optimized", metadata.edge_optimized.map(|value| value.to_string())),
+        ("Quantization aware", metadata.quantization_aware.map(|value| value.to_string())),
+        ("Supports quantization", metadata.supports_quantization.map(|value| value.to_string())),
+        ("Distributed inference", metadata.inference_distributed.map(|value| value.to_string())),
+        ("Distributed training", metadata.training_distributed.map(|value| value.to_string())),
+        ("Regulatory standards", metadata.regulatory.as_ref().map(|standards| standards.join(", "))),
+    ];
+
+    details.into_iter()
+        .filter_map(|(label, value)| value.map(|value| format!("{}: {}", label, value)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "model_card.test.rs"]
+mod model_card_test;
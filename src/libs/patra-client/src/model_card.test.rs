@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod model_card_test {
+    use serde_json::{json, to_value};
+    use clients::ClientError;
+    use shared::domain::entities::model_metadata::{
+        Accelerator, HardwareRequirements, ModelIO, ModelMetadata, SystemRequirement,
+    };
+    use crate::model_card::build_model_card;
+
+    fn metadata() -> ModelMetadata {
+        ModelMetadata {
+            name: Some(String::from("MegaDetector")),
+            model_type: Some(String::from("cnn")),
+            version: Some(String::from("5.0")),
+            framework: Some(String::from("pytorch")),
+            image: Some(String::from("ghcr.io/icicle/megadetector:5.0")),
+            labels: Some(vec![String::from("wildlife")]),
+            label_map: Some(json!({ "0": "animal", "1": "person" })),
+            multi_modal: Some(false),
+            model_inputs: Some(vec![ModelIO { data_type: Some(String::from("image")), shape: Some(vec![3, 640, 640]) }]),
+            model_outputs: Some(vec![ModelIO { data_type: Some(String::from("bounding_boxes")), shape: None }]),
+            task_types: Some(vec![String::from("object-detection")]),
+            inference_precision: Some(String::from("fp16")),
+            inference_hardware: Some(HardwareRequirements {
+                cpus: Some(4),
+                memory_gb: Some(16),
+                disk_gb: None,
+                accelerators: Some(vec![Accelerator {
+                    accelerator_type: String::from("gpu"),
+                    memory_gb: Some(8),
+                    cores: None,
+                    system_requirements: vec![SystemRequirement { name: String::from("cuda"), version: String::from("12.1") }],
+                }]),
+                architectures: Some(vec![String::from("x86_64")]),
+            }),
+            inference_software_dependencies: Some(vec![String::from("torch==2.1.0")]),
+            inference_max_energy_consumption_watts: None,
+            inference_max_latency_ms: Some(120),
+            inference_min_throughput: None,
+            inference_max_compute_utilization_percentage: None,
+            inference_max_memory_usage_mb: Some(2048),
+            inference_distributed: None,
+            training_time: None,
+            training_precision: None,
+            training_hardware: None,
+            pretraining_datasets: Some(vec![String::from("lila-camera-traps")]),
+            finetuning_datasets: None,
+            edge_optimized: Some(true),
+            quantization_aware: None,
+            supports_quantization: None,
+            pretrained: None,
+            pruned: None,
+            slimmed: None,
+            training_distributed: None,
+            training_max_energy_consumption_watts: None,
+            regulatory: Some(vec![String::from("HIPAA")]),
+            license: Some(String::from("MIT")),
+            bias_evaluation_score: Some(3),
+        }
+    }
+
+    #[test]
+    fn test_build_model_card() {
+        let card = to_value(build_model_card(&metadata()).unwrap()).unwrap();
+
+        assert_eq!(card["name"], "MegaDetector");
+        assert_eq!(card["short_description"], "MegaDetector for object-detection");
+        assert_eq!(card["keywords"], "wildlife,object-detection");
+        assert_eq!(card["category"], "object-detection");
+        assert_eq!(card["input_type"], "image");
+        assert_eq!(card["requirements"], json!(["torch==2.1.0"]));
+        assert_eq!(card["bias_analysis"], json!({ "bias_evaluation_score": 3 }));
+        assert_eq!(card["regulatory_standards"], json!(["HIPAA"]));
+        assert_eq!(card["training_datasets"], json!(["lila-camera-traps"]));
+        assert_eq!(card["model_inputs"], json!([{ "data_type": "image", "shape": [3, 640, 640] }]));
+        assert_eq!(card["inference_hardware"], json!({
+            "cpus": 4,
+            "memory_gb": 16,
+            "disk_gb": null,
+            "accelerators": [{ "accelerator_type": "gpu", "memory_gb": 8, "cores": null, "system_requirements": ["cuda==12.1"] }],
+            "architectures": ["x86_64"],
+        }));
+        assert!(card.get("training_hardware").is_none());
+        assert!(card["full_description"].as_str().unwrap().contains("Edge optimized: true"));
+
+        let ai_model = &card["ai_model"];
+        assert_eq!(ai_model["location"], "ghcr.io/icicle/megadetector:5.0");
+        assert_eq!(ai_model["license"], "MIT");
+        assert_eq!(ai_model["inference_labels"], json!({ "0": "animal", "1": "person" }));
+        assert_eq!(ai_model["model_metrics"], json!({ "inference_max_latency_ms": 120, "inference_max_memory_usage_mb": 2048 }));
+    }
+
+    #[test]
+    fn test_build_model_card_requires_name() {
+        let mut metadata = metadata();
+        metadata.name = Some(String::from(" "));
+
+        assert!(matches!(build_model_card(&metadata), Err(ClientError::BadRequest { .. })));
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shared::presentation::http::v1::responses::ModelSummary;
+
+/// The AI model described by a Patra model card
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct PatraAiModel {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub owner: Option<String>,
+    pub location: Option<String>,
+    pub license: Option<String>,
+    pub framework: Option<String>,
+    pub model_type: Option<String>,
+    pub test_accuracy: Option<f64>,
+}
+
+/// A model card as returned by the Patra server. Listing returns a subset of
+/// these fields so every field is optional
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct PatraModelCard {
+    /// Id of the model card. Patra returns either a string or a number
+    pub mc_id: Option<Value>,
+    pub id: Option<Value>,
+    pub external_id: Option<Value>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub author: Option<String>,
+    pub short_description: Option<String>,
+    pub full_description: Option<String>,
+    /// Comma-separated string or array of keywords
+    pub keywords: Option<Value>,
+    pub category: Option<String>,
+    pub categories: Option<String>,
+    pub input_type: Option<String>,
+    pub ai_model: Option<PatraAiModel>,
+}
+
+impl PatraModelCard {
+    /// The id of the model card, whichever field Patra returned it in
+    pub fn card_id(&self) -> Option<String> {
+        [&self.mc_id, &self.external_id, &self.id]
+            .into_iter()
+            .flatten()
+            .find_map(id_string)
+    }
+
+    pub fn keywords(&self) -> Vec<String> {
+        let keywords: Vec<String> = match &self.keywords {
+            Some(Value::String(keywords)) => keywords.split(",")
+                .map(|keyword| keyword.trim().to_string())
+                .collect(),
+            Some(Value::Array(keywords)) => keywords.iter()
+                .filter_map(|keyword| keyword.as_str())
+                .map(|keyword| keyword.trim().to_string())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        keywords.into_iter()
+            .filter(|keyword| !keyword.is_empty())
+            .collect()
+    }
+
+    /// Maps the model card into the summary shared by every platform. The
+    /// platform is the name the client is registered under
+    pub fn into_summary(self, platform: &str) -> ModelSummary {
+        let id = self.card_id()
+            .or(self.name.clone())
+            .unwrap_or_default();
+        let tags = self.keywords();
+        let ai_model = self.ai_model.unwrap_or_default();
+
+        ModelSummary {
+            platform: String::from(platform),
+            name: self.name.unwrap_or(id.clone()),
+            author: self.author.or(ai_model.owner),
+            description: self.short_description.or(self.full_description),
+            version: self.version.or(ai_model.version),
+            license: ai_model.license,
+            task: self.category.or(self.categories),
+            framework: ai_model.framework,
+            tags,
+            id,
+            ..Default::default()
+        }
+    }
+}
+
+/// Response of Patra's model card upload endpoint
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct PatraUploadResponse {
+    pub message: Option<String>,
+    /// Id of the uploaded model card. Patra returns it in either field
+    pub model_card_id: Option<Value>,
+    pub mc_id: Option<Value>,
+    /// Id of the AI model described by the model card
+    pub model_id: Option<Value>,
+}
+
+impl PatraUploadResponse {
+    /// The ids Patra assigned to the uploaded model card, keyed by the kind of id
+    pub fn published_ids(&self) -> HashMap<String, String> {
+        let card_id = [&self.model_card_id, &self.mc_id]
+            .into_iter()
+            .flatten()
+            .find_map(id_string);
+
+        [("model_card_id", card_id), ("model_id", self.model_id.as_ref().and_then(id_string))]
+            .into_iter()
+            .filter_map(|(kind, id)| id.map(|id| (String::from(kind), id)))
+            .collect()
+    }
+}
+
+/// Patra returns ids as either strings or numbers
+fn id_string(id: &Value) -> Option<String> {
+    match id {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "responses.test.rs"]
+mod responses_test;
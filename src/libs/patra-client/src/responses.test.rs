@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod responses_test {
+    use serde_json::{from_value, json};
+    use crate::responses::{PatraModelCard, PatraUploadResponse};
+
+    #[test]
+    fn test_model_card_to_summary() {
+        let card: PatraModelCard = from_value(json!({
+            "external_id": "uiuc-icicle-megadetector",
+            "name": "MegaDetector",
+            "version": "5.0",
+            "author": "ICICLE",
+            "short_description": "Detects animals in camera trap images",
+            "full_description": "A longer description",
+            "keywords": "wildlife, camera traps, ",
+            "category": "classification",
+            "ai_model": {
+                "license": "MIT",
+                "framework": "pytorch",
+                "test_accuracy": 0.92
+            }
+        })).unwrap();
+
+        let summary = card.into_summary("patra");
+
+        assert_eq!(summary.id, "uiuc-icicle-megadetector");
+        assert_eq!(summary.platform, "patra");
+        assert_eq!(summary.name, "MegaDetector");
+        assert_eq!(summary.description, Some(String::from("Detects animals in camera trap images")));
+        assert_eq!(summary.license, Some(String::from("MIT")));
+        assert_eq!(summary.framework, Some(String::from("pytorch")));
+        assert_eq!(summary.task, Some(String::from("classification")));
+        assert_eq!(summary.tags, vec![String::from("wildlife"), String::from("camera traps")]);
+    }
+
+    #[test]
+    fn test_model_card_numeric_id() {
+        let card: PatraModelCard = from_value(json!({
+            "mc_id": 42,
+            "name": "ResNet",
+            "keywords": ["vision"]
+        })).unwrap();
+
+        let summary = card.into_summary("patra-staging");
+
+        assert_eq!(summary.id, "42");
+        assert_eq!(summary.platform, "patra-staging");
+        assert_eq!(summary.tags, vec![String::from("vision")]);
+        assert_eq!(summary.license, None);
+    }
+
+    #[test]
+    fn test_upload_response_published_ids() {
+        let upload: PatraUploadResponse = from_value(json!({
+            "message": "Successfully uploaded the model card",
+            "model_card_id": 42,
+            "model_id": "megadetector-5.0",
+        })).unwrap();
+
+        let ids = upload.published_ids();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids["model_card_id"], "42");
+        assert_eq!(ids["model_id"], "megadetector-5.0");
+
+        let upload: PatraUploadResponse = from_value(json!({ "mc_id": "mc-1" })).unwrap();
+        assert_eq!(upload.published_ids().get("model_card_id").map(String::as_str), Some("mc-1"));
+    }
+}
@@ -1,13 +1,53 @@
-use clients::{ClientError, ClientErrorScope};
-use serde_json::{Value, from_str};
-use reqwest::blocking::Response;
+use clients::{error_message_from_body, ClientError, ClientErrorScope};
+use serde::de::DeserializeOwned;
+use serde_json::from_str;
+use reqwest::Response;
+use reqwest::header::RETRY_AFTER;
+
+/// Deserializes the body of a successful response. Unsuccessful responses are
+/// mapped onto the matching client error instead
+pub(crate) async fn deserialize_response_body<T: DeserializeOwned>(response: Response) -> Result<T, ClientError> {
+    if !response.status().is_success() {
+        return Err(response_error(response).await)
+    }
 
-pub(crate) fn deserialize_response_body(response: Response) -> Result<Value, ClientError> {
     response
         .text()
+        .await
         .map_err(|err| ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client })
         .and_then(|text| {
-            from_str::<Value>(&text.trim())
+            from_str::<T>(&text.trim())
                 .map_err(|err| ClientError::Internal { msg: format!("Error deserializing response: {}", err.to_string()), scope: ClientErrorScope::Client })
         })
-}
\ No newline at end of file
+}
+
+/// Maps an unsuccessful response from Patra onto the matching client error
+pub(crate) async fn response_error(response: Response) -> ClientError {
+    let status = response.status();
+    let retry_after = response.headers().get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from);
+    let body = response.text().await.unwrap_or_default();
+
+    let reason = error_message_from_body(&body)
+        .unwrap_or(status.canonical_reason().unwrap_or("Unknown error").to_string());
+
+    ClientError::from_upstream_status(
+        status.as_u16(),
+        format!("Patra responded with status {}: {}", status.as_u16(), reason),
+        retry_after,
+    )
+}
+
+/// Maps an error sending a request to Patra onto a client error
+pub(crate) fn request_error(err: reqwest::Error) -> ClientError {
+    if err.is_connect() || err.is_timeout() {
+        return ClientError::Unavailable(err.to_string())
+    }
+
+    if err.is_builder() || err.is_body() {
+        return ClientError::BadRequest { msg: err.to_string(), scope: ClientErrorScope::Client }
+    }
+
+    ClientError::Internal { msg: err.to_string(), scope: ClientErrorScope::Client }
+}
@@ -11,6 +11,8 @@ pub struct IngestArtifactInput {
     pub artifact_type: ArtifactType,
     pub platform: String,
     pub platform_artifact_id: String,
+    /// Tag, branch or commit of the artifact to ingest
+    pub revision: Option<String>,
     pub webhook_url: Option<String>,
     pub serialized_client_request: Vec<u8>,
 }
@@ -0,0 +1,62 @@
+pub mod inputs_to_entities;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DatasetFeature {
+    pub name: String,
+    pub data_type: Option<String>,
+    pub description: Option<String>,
+    pub nullable: Option<bool>
+}
+
+#[derive(Debug, Clone)]
+pub struct DatasetSplit {
+    pub name: String,
+    pub num_rows: Option<i64>,
+    pub num_bytes: Option<i64>
+}
+
+#[derive(Debug, Clone)]
+pub struct DatasetProvenance {
+    pub source_platform: Option<String>,
+    pub source_id: Option<String>,
+    pub source_url: Option<String>,
+    pub revision: Option<String>,
+    pub creators: Option<Vec<String>>,
+    pub citation: Option<String>,
+    pub collection_method: Option<String>
+}
+
+#[derive(Debug, Clone)]
+pub struct DatasetMetadata {
+    // General fields
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub modalities: Option<Vec<String>>,
+    pub task_types: Option<Vec<String>>,
+    pub format: Option<String>,
+
+    /// Arbitrary labels
+    pub labels: Option<Vec<String>>,
+
+    /// Structure fields
+    pub schema: Option<Vec<DatasetFeature>>,
+    pub splits: Option<Vec<DatasetSplit>>,
+    pub num_rows: Option<i64>,
+    pub size_bytes: Option<i64>,
+
+    /// Provenance fields
+    pub provenance: Option<DatasetProvenance>,
+
+    /// Regulatory and Compliance Fields
+    pub license: Option<String>,
+    pub regulatory: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateDatasetMetadata {
+    pub artifact_id: Uuid,
+    pub metadata: DatasetMetadata,
+}
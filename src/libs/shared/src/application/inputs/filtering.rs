@@ -0,0 +1,36 @@
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FilterOperation {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    In,
+    Nin,
+    /// Case-insensitive match of the values that contain the filter value
+    Pattern
+}
+
+#[derive(Debug, Clone)]
+pub struct Filter {
+    pub field: String,
+    pub operation: FilterOperation,
+    pub value: String
+}
+
+#[derive(Debug, Clone)]
+pub enum Order {
+    Asc,
+    Desc
+}
+
+#[derive(Debug, Clone)]
+pub struct ListAll {
+    pub limit: u64,
+    pub offset: u64,
+    pub fields: Vec<String>,
+    pub filters: Option<Vec<Filter>>,
+    pub sort_by: Option<String>,
+    pub order_by: Option<Order>
+}
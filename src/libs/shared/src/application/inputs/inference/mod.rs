@@ -4,42 +4,7 @@ use std::collections::hash_map::HashMap;
 use serde_json::Value;
 use openapiv3::OpenAPI;
 pub use crate::application::inputs::model_metadata::ModelMetadata;
-
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub enum FilterOperation {
-    Eq,
-    Ne,
-    Lt,
-    Lte,
-    Gt,
-    Gte,
-    In,
-    Nin,
-    Pattern
-}
-
-#[derive(Debug)]
-pub struct Filter {
-    pub field: String,
-    pub operation: FilterOperation,
-    pub value: String
-}
-
-#[derive(Debug)]
-pub enum Order {
-    Asc,
-    Desc
-}
-
-#[derive(Debug)]
-pub struct ListAll {
-    pub limit: u64,
-    pub offset: u64,
-    pub fields: Vec<String>,
-    pub filters: Option<Vec<Filter>>,
-    pub sort_by: Option<String>,
-    pub order_by: Option<Order>
-}
+pub use crate::application::inputs::filtering::{FilterOperation, Filter, Order, ListAll};
 
 pub type Labels = HashMap<String, String>;
 
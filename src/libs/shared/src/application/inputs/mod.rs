@@ -1,4 +1,6 @@
 pub mod artifacts;
 pub mod artifact_publication;
 pub mod model_metadata;
-pub mod inference;
\ No newline at end of file
+pub mod inference;
+pub mod dataset_metadata;
+pub mod filtering;
\ No newline at end of file
@@ -1,2 +1,3 @@
 pub mod repositories;
-pub mod events;
\ No newline at end of file
+pub mod events;
+pub mod webhooks;
\ No newline at end of file
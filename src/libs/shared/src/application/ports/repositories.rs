@@ -2,8 +2,12 @@ use crate::domain::entities::artifact::Artifact;
 use crate::domain::entities::artifact_ingestion::ArtifactIngestion;
 use crate::domain::entities::artifact_publication::ArtifactPublication;
 use crate::domain::entities::model_metadata::ModelMetadata;
+use crate::domain::entities::dataset_metadata::DatasetMetadata;
+use crate::domain::entities::webhook_delivery::WebhookDelivery;
 use crate::application::errors::ApplicationError;
 use crate::application::inputs::model_metadata::CreateModelMetadata;
+use crate::application::inputs::dataset_metadata::CreateDatasetMetadata;
+use crate::application::inputs::filtering::{Filter, ListAll};
 use uuid::Uuid;
 use async_trait::async_trait;
 
@@ -13,6 +17,9 @@ pub trait ArtifactRepository: Send + Sync {
     async fn update(&self, ingestion: &Artifact) -> Result<(), ApplicationError>;
     async fn find_by_id(&self, id: &Uuid) -> Result<Option<Artifact>, ApplicationError>;
     async fn list_all(&self) -> Result<Vec<Artifact>, ApplicationError>;
+    /// Models that were uploaded or have finished ingesting along with their
+    /// metadata, if any. Newest first
+    async fn list_ingested_models(&self, limit: u64, offset: u64) -> Result<Vec<(Artifact, Option<ModelMetadata>)>, ApplicationError>;
     async fn update_path(&self, artifact: &Artifact) -> Result<(), ApplicationError>;
 }
 
@@ -29,14 +36,33 @@ pub trait ArtifactIngestionRepository: Send + Sync {
 pub trait ModelMetadataRepository: Send + Sync {
     async fn save(&self, input: &CreateModelMetadata) -> Result<(), ApplicationError>;
     async fn find_by_artifact_id(&self, artifact_id: &Uuid) -> Result<Option<ModelMetadata>, ApplicationError>;
-    // async fn update(&self, metadata: &ModelMetadata) -> Result<(), ApplicationError>;
-    // async fn list(&self) -> Result<Vec<ModelMetadata>, ApplicationError>;
+    /// Replaces the metadata of an artifact in a single operation. Returns the
+    /// replaced metadata or None if the artifact has no metadata
+    async fn update(&self, input: &CreateModelMetadata) -> Result<Option<ModelMetadata>, ApplicationError>;
+    async fn list(&self) -> Result<Vec<ModelMetadata>, ApplicationError>;
+    async fn find(&self, input: &ListAll) -> Result<Vec<(Uuid, ModelMetadata)>, ApplicationError>;
+    /// Metadata matching any of the filters
+    async fn find_any(&self, filters: &[Filter], limit: u64) -> Result<Vec<(Uuid, ModelMetadata)>, ApplicationError>;
+    async fn delete_by_artifact_id(&self, artifact_id: &Uuid) -> Result<(), ApplicationError>;
+}
+
+#[async_trait]
+pub trait DatasetMetadataRepository: Send + Sync {
+    async fn save(&self, input: &CreateDatasetMetadata) -> Result<(), ApplicationError>;
+    async fn find_by_artifact_id(&self, artifact_id: &Uuid) -> Result<Option<DatasetMetadata>, ApplicationError>;
 }
 
 #[async_trait]
 pub trait ArtifactPublicationRepository: Send + Sync {
     async fn save(&self, publication: &ArtifactPublication) -> Result<(), ApplicationError>;
+    async fn update(&self, publication: &ArtifactPublication) -> Result<(), ApplicationError>;
     async fn update_status(&self, ingestion: &ArtifactPublication) -> Result<(), ApplicationError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<ArtifactPublication>, ApplicationError>;
-    // async fn find_by_artifact_id(&self, artifact_id: Uuid) -> Result<Vec<ArtifactPublication>, ApplicationError>;
+    async fn find_by_artifact_id(&self, artifact_id: Uuid) -> Result<Vec<ArtifactPublication>, ApplicationError>;
+}
+
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    async fn save(&self, delivery: &WebhookDelivery) -> Result<(), ApplicationError>;
+    async fn find_by_resource_id(&self, resource_id: Uuid) -> Result<Vec<WebhookDelivery>, ApplicationError>;
 }
\ No newline at end of file
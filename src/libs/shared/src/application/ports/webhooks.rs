@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use thiserror::Error;
+use uuid::Uuid;
+use crate::domain::entities::timestamp::TimeStamp;
+use crate::domain::entities::webhook_delivery::WebhookEventType;
+
+#[derive(Debug, Error)]
+pub enum WebhookNotifierError {
+    #[error("Webhook serialization error: {0}")]
+    SerializationError(String),
+
+    #[error("Webhook transport error: {0}")]
+    TransportError(String),
+
+    #[error("Webhook url responded with unexpected status code {0}")]
+    UnexpectedStatus(u16),
+}
+
+impl WebhookNotifierError {
+    /// The status code returned by the webhook url if a response was received
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::UnexpectedStatus(code) => Some(code.clone()),
+            _ => None
+        }
+    }
+}
+
+/// A status transition of an ArtifactIngestion or ArtifactPublication that
+/// should be reported to the webhook url provided by the requester
+#[derive(Clone, Debug)]
+pub struct WebhookEvent {
+    pub id: Uuid,
+    pub event_type: WebhookEventType,
+    pub resource_id: Uuid,
+    pub artifact_id: Uuid,
+    pub status: String,
+    pub last_message: Option<String>,
+    pub occurred_at: TimeStamp,
+}
+
+impl WebhookEvent {
+    pub fn new(
+        event_type: WebhookEventType,
+        resource_id: Uuid,
+        artifact_id: Uuid,
+        status: String,
+        last_message: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type,
+            resource_id,
+            artifact_id,
+            status,
+            last_message,
+            occurred_at: TimeStamp::now(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait WebhookNotifier: Send + Sync {
+    /// Delivers the event to the webhook url. Returns the status code of the
+    /// response on success
+    async fn notify(&self, url: &str, event: &WebhookEvent) -> Result<u16, WebhookNotifierError>;
+}
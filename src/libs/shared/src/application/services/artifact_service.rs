@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::future::Future;
@@ -7,11 +8,15 @@ use crate::application::errors::ApplicationError;
 use crate::application::inputs::artifacts::{DownloadArtifactInput, IngestArtifactInput, UploadArtifactInput};
 use crate::application::inputs::artifact_publication::PublishArtifactInput;
 use crate::application::ports::events::{Event, EventPublisher, EventPublisherError, IngestArtifactEventPayload, PublishArtifactEventPayload};
-use crate::application::ports::repositories::{ArtifactIngestionRepository, ArtifactPublicationRepository, ArtifactRepository, ModelMetadataRepository};
-use crate::domain::entities::artifact::{Artifact, ArtifactType as ArtifactTypeEntity};
+use crate::application::ports::repositories::{ArtifactIngestionRepository, ArtifactPublicationRepository, ArtifactRepository, DatasetMetadataRepository, ModelMetadataRepository, WebhookDeliveryRepository};
+use crate::application::ports::webhooks::{WebhookEvent, WebhookNotifier};
+use crate::application::services::webhook_service::WebhookService;
+use crate::domain::entities::artifact::{Artifact, ArtifactProvenance, ArtifactType as ArtifactTypeEntity};
 use crate::domain::entities::artifact_ingestion::{ArtifactIngestion, ArtifactIngestionError, ArtifactIngestionFailureReason, ArtifactIngestionStatus};
 use crate::domain::entities::artifact_publication::{ArtifactPublication, ArtifactPublicationStatus, ArtifactPublicationError, ArtifactPublicationFailureReason};
+use crate::domain::entities::dataset_metadata::DatasetMetadata;
 use crate::domain::entities::model_metadata::ModelMetadata;
+use crate::domain::entities::webhook_delivery::WebhookEventType;
 use crate::domain::services::{
     ArtifactService as DomainArtifactService,
     ArtifactServiceError as DomainArtifactServiceError};
@@ -70,7 +75,9 @@ pub struct ArtifactService {
     ingestion_repo: Arc<dyn ArtifactIngestionRepository>,
     publication_repo: Arc<dyn ArtifactPublicationRepository>,
     metadata_repo: Arc<dyn ModelMetadataRepository>,
+    dataset_metadata_repo: Arc<dyn DatasetMetadataRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    webhook_service: WebhookService,
 }
 
 impl ArtifactService {
@@ -96,17 +103,54 @@ impl ArtifactService {
         ingestion_repo: Arc<dyn ArtifactIngestionRepository>,
         publication_repo: Arc<dyn ArtifactPublicationRepository>,
         metadata_repo: Arc<dyn ModelMetadataRepository>,
+        dataset_metadata_repo: Arc<dyn DatasetMetadataRepository>,
         event_publisher: Arc<dyn EventPublisher>,
+        webhook_notifier: Arc<dyn WebhookNotifier>,
+        webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository>,
     ) -> Self {
         Self {
             artifact_repo,
             ingestion_repo,
             publication_repo,
             metadata_repo,
+            dataset_metadata_repo,
             event_publisher,
+            webhook_service: WebhookService::new(webhook_notifier, webhook_delivery_repo),
         }
     }
 
+    /// Notifies the ingestion's webhook url (if any) of its current status
+    fn notify_ingestion_webhook(&self, ingestion: &ArtifactIngestion) {
+        if let Some(url) = &ingestion.webhook_url {
+            self.notify_webhook(url, WebhookEvent::new(
+                WebhookEventType::ArtifactIngestionStatusChanged,
+                ingestion.id.clone(),
+                ingestion.artifact_id.clone(),
+                String::from(ingestion.status.clone()),
+                ingestion.last_message.clone(),
+            ));
+        }
+    }
+
+    /// Notifies the publication's webhook url (if any) of its current status
+    fn notify_publication_webhook(&self, publication: &ArtifactPublication) {
+        if let Some(url) = &publication.webhook_url {
+            self.notify_webhook(url, WebhookEvent::new(
+                WebhookEventType::ArtifactPublicationStatusChanged,
+                publication.id.clone(),
+                publication.artifact_id.clone(),
+                String::from(publication.status.clone()),
+                publication.last_message.clone(),
+            ));
+        }
+    }
+
+    /// Deliveries run in the background and never hold up or fail the status
+    /// change itself
+    fn notify_webhook(&self, url: &str, event: WebhookEvent) {
+        self.webhook_service.dispatch(url.to_string(), event);
+    }
+
     /// Creates an artifact publication
     pub async fn submit_artifact_publication(&self, input: PublishArtifactInput) -> Result<ArtifactPublication, ArtifactServiceError> {
         // Closure for fetching the artifact
@@ -117,18 +161,22 @@ impl ArtifactService {
             .map_err(|err| ArtifactServiceError::RepoError(err))?;
 
         // Check that the artifact exists
-        if maybe_artifact.is_none() {
-            return Err(ArtifactServiceError::MissingArtifact("Artifact must exist in order to publish it".into()))
-        }
+        let artifact = match maybe_artifact {
+            Some(a) => a,
+            None => return Err(ArtifactServiceError::MissingArtifact("Artifact must exist in order to publish it".into()))
+        };
 
-        // Fetch artifact metadata
-        let _ = self.find_metadata_by_artifact_id(&input.artifact_id)
-            .await?;
+        // Check that the artifact has metadata of its type
+        match artifact.artifact_type {
+            ArtifactTypeEntity::Model => { self.find_metadata_by_artifact_id(&input.artifact_id).await?; },
+            ArtifactTypeEntity::Dataset => { self.find_dataset_metadata_by_artifact_id(&input.artifact_id).await?; },
+        };
 
         // Instantiate the ArtifactPublication
         let mut publication = ArtifactPublication::new(
             input.artifact_id,
             input.target_platform,
+            input.webhook_url.clone(),
         );
 
         // Closure for saving the publication
@@ -189,6 +237,20 @@ impl ArtifactService {
         }
     }
 
+    pub async fn find_dataset_metadata_by_artifact_id(&self, artifact_id: &Uuid) -> Result<DatasetMetadata, ArtifactServiceError> {
+        // Closure for fetching the metadata for this artifact
+        let find_metadata = || self.dataset_metadata_repo.find_by_artifact_id(&artifact_id);
+
+        // Find the metadata with retries
+        let maybe_metadata = retry_async(find_metadata, &Self::REPO_RETRY_POLICY).await
+            .map_err(|err| ArtifactServiceError::RepoError(err))?;
+
+        match maybe_metadata {
+            Some(m) => Ok(m),
+            None => Err(ArtifactServiceError::MissingMetadata("Dataset must have an associated metadata in order to publish it. Create metadata for this dataset then try again".into()))
+        }
+    }
+
     pub async fn change_publication_status_by_publication_id(
         &self,
         publication_id: Uuid,
@@ -218,6 +280,29 @@ impl ArtifactService {
 
         let update_publication = || self.publication_repo.update_status(&publication);
 
+        retry_async(update_publication, &Self::REPO_RETRY_POLICY).await
+            .map_err(|err| ArtifactServiceError::RepoError(err))?;
+
+        self.notify_publication_webhook(&publication);
+
+        Ok(())
+    }
+
+    /// Records the ids the target platform assigned to what was published
+    pub async fn record_publication_published_ids(
+        &self,
+        publication_id: Uuid,
+        published_ids: HashMap<String, String>
+    ) -> Result<(), ArtifactServiceError> {
+        let mut publication = match self.find_publication_by_publication_id(publication_id).await? {
+            Some(p) => p,
+            None => return Err(ArtifactServiceError::NotFound(format!("Cannot find any record of ArtifactPublication '{}'.", publication_id)))
+        };
+
+        publication.record_published_ids(published_ids)?;
+
+        let update_publication = || self.publication_repo.update(&publication);
+
         retry_async(update_publication, &Self::REPO_RETRY_POLICY).await
             .map_err(|err| ArtifactServiceError::RepoError(err))?;
 
@@ -233,6 +318,17 @@ impl ArtifactService {
         return Ok(maybe_publication)
     }
 
+    pub async fn find_publications_by_artifact_id(&self, artifact_id: Uuid) -> Result<Vec<ArtifactPublication>, ArtifactServiceError> {
+        self.ensure_artifact_exists(&artifact_id).await?;
+
+        let find_publications = || self.publication_repo.find_by_artifact_id(artifact_id);
+
+        let publications = retry_async(find_publications, &Self::REPO_RETRY_POLICY).await
+            .map_err(|err| ArtifactServiceError::RepoError(err))?;
+
+        return Ok(publications)
+    }
+
     pub async fn submit_artifact_ingestion(&self, input: IngestArtifactInput) -> Result<ArtifactIngestion, ArtifactServiceError> {
         let artifact = Artifact::new(ArtifactTypeEntity::from(input.artifact_type.clone()));
         
@@ -248,6 +344,12 @@ impl ArtifactService {
             input.platform.clone(),
             input.webhook_url.clone()
         );
+
+        ingestion.set_provenance(ArtifactProvenance::new(
+            input.platform.clone(),
+            input.platform_artifact_id.clone(),
+            input.revision.clone()
+        ));
         
         // Closure for saving the ingestion
         let save_ingestion = || self.ingestion_repo.save(&ingestion);
@@ -353,6 +455,30 @@ impl ArtifactService {
 
         let update_ingestion = || self.ingestion_repo.update_status(&ingestion);
 
+        retry_async(update_ingestion, &Self::REPO_RETRY_POLICY).await
+            .map_err(|err| ArtifactServiceError::RepoError(err))?;
+
+        self.notify_ingestion_webhook(&ingestion);
+
+        Ok(())
+    }
+
+    /// Records the commit sha that the ingestion's requested revision resolved
+    /// to when the artifact files were downloaded
+    pub async fn record_ingestion_resolved_revision(
+        &self,
+        ingestion_id: Uuid,
+        resolved_revision: String
+    ) -> Result<(), ArtifactServiceError> {
+        let mut ingestion = match self.find_ingestion_by_ingestion_id(ingestion_id).await? {
+            Some(i) => i,
+            None => return Err(ArtifactServiceError::NotFound(format!("Cannot find any record of ArtifactIngestion '{}'.", ingestion_id)))
+        };
+
+        ingestion.set_resolved_revision(resolved_revision)?;
+
+        let update_ingestion = || self.ingestion_repo.update(&ingestion);
+
         retry_async(update_ingestion, &Self::REPO_RETRY_POLICY).await
             .map_err(|err| ArtifactServiceError::RepoError(err))?;
 
@@ -368,6 +494,30 @@ impl ArtifactService {
         return Ok(maybe_ingestion)
     }
 
+    pub async fn find_ingestions_by_artifact_id(&self, artifact_id: Uuid) -> Result<Vec<ArtifactIngestion>, ArtifactServiceError> {
+        self.ensure_artifact_exists(&artifact_id).await?;
+
+        let find_ingestions = || self.ingestion_repo.find_by_artifact_id(artifact_id);
+
+        let ingestions = retry_async(find_ingestions, &Self::REPO_RETRY_POLICY).await
+            .map_err(|err| ArtifactServiceError::RepoError(err))?;
+
+        return Ok(ingestions)
+    }
+
+    /// Returns a NotFound error if no artifact exists with the provided id
+    async fn ensure_artifact_exists(&self, artifact_id: &Uuid) -> Result<(), ArtifactServiceError> {
+        let find_artifact = || self.artifact_repo.find_by_id(artifact_id);
+
+        let maybe_artifact = retry_async(find_artifact, &Self::REPO_RETRY_POLICY).await
+            .map_err(|err| ArtifactServiceError::RepoError(err))?;
+
+        match maybe_artifact {
+            Some(_) => Ok(()),
+            None => Err(ArtifactServiceError::NotFound(format!("Cannot find any record of Artifact '{}'", artifact_id)))
+        }
+    }
+
     pub async fn finish_artifact_ingestion(&self, artifact_path: PathBuf, artifact: &mut Artifact, ingestion: &mut ArtifactIngestion) -> Result<(), ArtifactServiceError> {
         // Check if the artifact path actually exists
         if !artifact_path.exists() {
@@ -394,6 +544,8 @@ impl ArtifactService {
         retry_async(update, &Self::REPO_RETRY_POLICY).await
             .map_err(|err| ArtifactServiceError::RepoError(err))?;
 
+        self.notify_ingestion_webhook(ingestion);
+
         Ok(())
     }
 
@@ -493,6 +645,12 @@ impl ArtifactService {
             None => return Err(ArtifactServiceError::NotFound("Artifact not found".into()))
         };
 
+        // Models and datasets are served from their own APIs, so an artifact of
+        // a different type is treated as if it does not exist
+        if artifact.artifact_type != ArtifactTypeEntity::from(input.artifact_type) {
+            return Err(ArtifactServiceError::NotFound("Artifact not found".into()))
+        }
+
         let path = artifact.path.clone().ok_or_else(|| ArtifactServiceError::NotFound("Artifact path is not set".into()))?;
 
         Ok(path)
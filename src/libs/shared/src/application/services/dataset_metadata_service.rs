@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use crate::retry::{retry_async, RetryPolicy, FixedBackoff, Retry};
+use crate::application::errors::ApplicationError;
+use crate::application::ports::repositories::{ArtifactRepository, DatasetMetadataRepository};
+use crate::application::inputs::dataset_metadata::CreateDatasetMetadata;
+use crate::domain::entities::dataset_metadata::DatasetMetadata;
+use crate::domain::services::{
+    DatasetMetadataService as DatasetMetadataDomainService,
+    DatasetMetadataServiceError as DatasetMetadataDomainServiceError
+};
+use thiserror::Error;
+use once_cell::sync::Lazy;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum DatasetMetadataServiceError {
+    #[error("Repository error: {0}")]
+    RepoError(#[from] ApplicationError),
+
+    #[error("Artifact not found: {0}")]
+    ArtifactNotFound(String),
+
+    #[error("Metadata not found: {0}")]
+    MetadataNotFound(String),
+
+    #[error("{0}")]
+    DomainServiceError(#[from] DatasetMetadataDomainServiceError),
+
+    #[error("Metadata already exists for Artifact '{0}'")]
+    DuplicateMetadataError(String),
+}
+
+pub struct DatasetMetadataService {
+    dataset_metadata_repo: Arc<dyn DatasetMetadataRepository>,
+    artifact_repo: Arc<dyn ArtifactRepository>
+}
+
+impl DatasetMetadataService {
+    const REPO_RETRY_POLICY: Lazy<RetryPolicy> = Lazy::new(|| RetryPolicy::FixedBackoff(
+        FixedBackoff {
+            retries: Retry::NTimes(3),
+            delay: 50,
+        }
+    ));
+
+    pub fn new(
+        dataset_metadata_repo: Arc<dyn DatasetMetadataRepository>,
+        artifact_repo: Arc<dyn ArtifactRepository>
+    ) -> Self {
+        Self {
+            dataset_metadata_repo,
+            artifact_repo
+        }
+    }
+
+    pub async fn create_metadata(&self, input: CreateDatasetMetadata) -> Result<(), DatasetMetadataServiceError> {
+        // Get the artifact_id from the input
+        let artifact_id = input.artifact_id.clone();
+
+        // Convert from service input to domain entitiy
+        let metadata = DatasetMetadata::try_from(input.metadata.clone())?;
+
+        let find_artifact = || self.artifact_repo.find_by_id(&artifact_id);
+
+        // Find the artifact by id
+        let artifact = retry_async(find_artifact, &Self::REPO_RETRY_POLICY)
+            .await?
+            .ok_or_else(|| DatasetMetadataServiceError::ArtifactNotFound(format!("Artifact with id {} does not exist", &artifact_id)))?;
+
+        // Determine if we are allowed to create the metadata for this artifact
+        DatasetMetadataDomainService::create(&artifact, metadata)?;
+
+        // Ensure no metadata already exists for this artifact
+        let find_metadata = || self.dataset_metadata_repo.find_by_artifact_id(&artifact_id);
+
+        let maybe_metadata = retry_async(find_metadata, &Self::REPO_RETRY_POLICY)
+            .await?;
+
+        if maybe_metadata.is_some() {
+            return Err(DatasetMetadataServiceError::DuplicateMetadataError(artifact_id.to_string()));
+        };
+
+        let create_metadata = || self.dataset_metadata_repo.save(&input);
+
+        retry_async(create_metadata, &Self::REPO_RETRY_POLICY)
+            .await?;
+
+        return Ok(())
+    }
+
+    pub async fn get_metadata(&self, artifact_id: Uuid) -> Result<DatasetMetadata, DatasetMetadataServiceError> {
+        let find_metadata = || self.dataset_metadata_repo.find_by_artifact_id(&artifact_id);
+
+        retry_async(find_metadata, &Self::REPO_RETRY_POLICY)
+            .await?
+            .ok_or_else(|| DatasetMetadataServiceError::MetadataNotFound(format!("No metadata exists for artifact {}", &artifact_id)))
+    }
+}
@@ -1,2 +1,4 @@
 pub mod artifact_service;
-pub mod model_metadata_service;
\ No newline at end of file
+pub mod model_metadata_service;
+pub mod webhook_service;
+pub mod dataset_metadata_service;
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::retry::{retry_async, RetryPolicy, FixedBackoff, Retry};
 use crate::application::errors::ApplicationError;
 use crate::application::ports::repositories::{ArtifactRepository, ModelMetadataRepository};
 use crate::application::inputs::model_metadata::CreateModelMetadata;
+use crate::application::inputs::filtering::ListAll;
 use crate::domain::entities::model_metadata::ModelMetadata;
 use crate::domain::services::{
     ModelMetadataService as ModelMetadataDomainService,
@@ -10,6 +11,7 @@ use crate::domain::services::{
 };
 use thiserror::Error;
 use once_cell::sync::Lazy;
+use uuid::Uuid;
 // use crate::logging::GlobalLogger;
 
 #[derive(Debug, Error)]
@@ -20,6 +22,9 @@ pub enum ModelMetadataServiceError {
     #[error("Artifact not found: {0}")]
     ArtifactNotFound(String),
 
+    #[error("Metadata not found: {0}")]
+    MetadataNotFound(String),
+
     #[error("{0}")]
     DomainServiceError(#[from] ModelMetadataDomainServiceError),
 
@@ -85,4 +90,68 @@ impl ModelMetadataService {
 
         return Ok(())
     }
+
+    pub async fn get_metadata(&self, artifact_id: Uuid) -> Result<ModelMetadata, ModelMetadataServiceError> {
+        let find_metadata = || self.model_metadata_repo.find_by_artifact_id(&artifact_id);
+
+        retry_async(find_metadata, &Self::REPO_RETRY_POLICY)
+            .await?
+            .ok_or_else(|| ModelMetadataServiceError::MetadataNotFound(format!("No metadata exists for artifact {}", &artifact_id)))
+    }
+
+    /// Lists the metadata matching the filters of the input along with the id
+    /// of the artifact each belongs to
+    pub async fn list_metadata(&self, input: ListAll) -> Result<Vec<(Uuid, ModelMetadata)>, ModelMetadataServiceError> {
+        let find_metadata = || self.model_metadata_repo.find(&input);
+
+        let metadata = retry_async(find_metadata, &Self::REPO_RETRY_POLICY)
+            .await?;
+
+        Ok(metadata)
+    }
+
+    /// Replaces the metadata of an artifact. The input goes through the same
+    /// conversion and validation as on creation so invalid metadata is
+    /// rejected before anything is written
+    pub async fn update_metadata(&self, input: CreateModelMetadata) -> Result<(), ModelMetadataServiceError> {
+        let artifact_id = input.artifact_id.clone();
+
+        // Convert from service input to domain entitiy
+        let metadata = ModelMetadata::try_from(input.clone())?;
+
+        let find_artifact = || self.artifact_repo.find_by_id(&artifact_id);
+
+        let artifact = retry_async(find_artifact, &Self::REPO_RETRY_POLICY)
+            .await?
+            .ok_or_else(|| ModelMetadataServiceError::ArtifactNotFound(format!("Artifact with id {} does not exist", &artifact_id)))?;
+
+        // Determine if we are allowed to replace the metadata of this artifact
+        ModelMetadataDomainService::update(&artifact, metadata)?;
+
+        // The metadata is replaced only if it exists, in the same operation
+        let update_metadata = || self.model_metadata_repo.update(&input);
+
+        retry_async(update_metadata, &Self::REPO_RETRY_POLICY)
+            .await?
+            .ok_or_else(|| ModelMetadataServiceError::MetadataNotFound(format!("No metadata exists for artifact {}", &artifact_id)))?;
+
+        Ok(())
+    }
+
+    pub async fn delete_metadata(&self, artifact_id: Uuid) -> Result<(), ModelMetadataServiceError> {
+        // Ensure the metadata exists before deleting it
+        self.get_metadata(artifact_id).await?;
+
+        let delete_metadata = || self.model_metadata_repo.delete_by_artifact_id(&artifact_id);
+
+        retry_async(delete_metadata, &Self::REPO_RETRY_POLICY)
+            .await?;
+
+        Ok(())
+    }
 }
+
+// Unit tests
+#[cfg(test)]
+#[path = "model_metadata_service.test.rs"]
+mod model_metadata_service_test;
@@ -0,0 +1,256 @@
+#[cfg(test)]
+mod model_metadata_service_test {
+    use std::sync::{Arc, Mutex};
+    use async_trait::async_trait;
+    use uuid::Uuid;
+    use crate::application::errors::ApplicationError;
+    use crate::application::inputs::filtering::{Filter, ListAll};
+    use crate::application::inputs::model_metadata::{CreateModelMetadata, ModelMetadata as ModelMetadataInput};
+    use crate::application::ports::repositories::{ArtifactRepository, ModelMetadataRepository};
+    use crate::application::services::model_metadata_service::{ModelMetadataService, ModelMetadataServiceError};
+    use crate::domain::services::ModelMetadataServiceError as ModelMetadataDomainServiceError;
+    use crate::domain::entities::artifact::{Artifact, ArtifactType};
+    use crate::domain::entities::model_metadata::ModelMetadata;
+
+    #[derive(Default)]
+    struct InMemoryModelMetadataRepository {
+        metadata: Mutex<Vec<CreateModelMetadata>>,
+    }
+
+    #[async_trait]
+    impl ModelMetadataRepository for InMemoryModelMetadataRepository {
+        async fn save(&self, input: &CreateModelMetadata) -> Result<(), ApplicationError> {
+            self.metadata.lock().unwrap().push(input.clone());
+            Ok(())
+        }
+
+        async fn find_by_artifact_id(&self, artifact_id: &Uuid) -> Result<Option<ModelMetadata>, ApplicationError> {
+            self.metadata.lock().unwrap()
+                .iter()
+                .find(|m| &m.artifact_id == artifact_id)
+                .map(|m| ModelMetadata::try_from(m.clone()))
+                .transpose()
+        }
+
+        async fn update(&self, input: &CreateModelMetadata) -> Result<Option<ModelMetadata>, ApplicationError> {
+            let mut metadata = self.metadata.lock().unwrap();
+            match metadata.iter_mut().find(|m| m.artifact_id == input.artifact_id) {
+                Some(existing) => Ok(Some(ModelMetadata::try_from(std::mem::replace(existing, input.clone()))?)),
+                None => Ok(None)
+            }
+        }
+
+        async fn list(&self) -> Result<Vec<ModelMetadata>, ApplicationError> {
+            self.metadata.lock().unwrap()
+                .iter()
+                .map(|m| ModelMetadata::try_from(m.clone()))
+                .collect()
+        }
+
+        async fn find(&self, input: &ListAll) -> Result<Vec<(Uuid, ModelMetadata)>, ApplicationError> {
+            self.metadata.lock().unwrap()
+                .iter()
+                .skip(input.offset as usize)
+                .take(input.limit as usize)
+                .map(|m| Ok((m.artifact_id, ModelMetadata::try_from(m.clone())?)))
+                .collect()
+        }
+
+        async fn find_any(&self, _filters: &[Filter], limit: u64) -> Result<Vec<(Uuid, ModelMetadata)>, ApplicationError> {
+            self.find(&ListAll { limit, offset: 0, fields: Vec::new(), filters: None, sort_by: None, order_by: None }).await
+        }
+
+        async fn delete_by_artifact_id(&self, artifact_id: &Uuid) -> Result<(), ApplicationError> {
+            self.metadata.lock().unwrap().retain(|m| &m.artifact_id != artifact_id);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryArtifactRepository {
+        artifacts: Mutex<Vec<Artifact>>,
+    }
+
+    #[async_trait]
+    impl ArtifactRepository for InMemoryArtifactRepository {
+        async fn save(&self, artifact: &Artifact) -> Result<(), ApplicationError> {
+            self.artifacts.lock().unwrap().push(artifact.clone());
+            Ok(())
+        }
+
+        async fn update(&self, artifact: &Artifact) -> Result<(), ApplicationError> {
+            let mut artifacts = self.artifacts.lock().unwrap();
+            artifacts.retain(|a| a.id != artifact.id);
+            artifacts.push(artifact.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &Uuid) -> Result<Option<Artifact>, ApplicationError> {
+            Ok(self.artifacts.lock().unwrap().iter().find(|a| &a.id == id).cloned())
+        }
+
+        async fn list_all(&self) -> Result<Vec<Artifact>, ApplicationError> {
+            Ok(self.artifacts.lock().unwrap().clone())
+        }
+
+        async fn list_ingested_models(&self, limit: u64, offset: u64) -> Result<Vec<(Artifact, Option<ModelMetadata>)>, ApplicationError> {
+            Ok(self.artifacts.lock().unwrap()
+                .iter()
+                .filter(|a| a.artifact_type == ArtifactType::Model && a.is_fully_ingested())
+                .skip(offset as usize)
+                .take(limit as usize)
+                .map(|a| (a.clone(), None))
+                .collect())
+        }
+
+        async fn update_path(&self, artifact: &Artifact) -> Result<(), ApplicationError> {
+            self.update(artifact).await
+        }
+    }
+
+    /// An artifact of the given type that has been uploaded
+    fn artifact(artifact_type: ArtifactType) -> Artifact {
+        let mut artifact = Artifact::new(artifact_type);
+        artifact.set_path("/path/to/artifact".into());
+        artifact
+    }
+
+    fn metadata(artifact_id: Uuid, name: &str) -> CreateModelMetadata {
+        CreateModelMetadata {
+            artifact_id,
+            metadata: ModelMetadataInput {
+                name: Some(name.into()),
+                model_type: None,
+                version: None,
+                framework: Some("pytorch".into()),
+                image: None,
+                labels: None,
+                label_map: None,
+                multi_modal: None,
+                model_inputs: None,
+                model_outputs: None,
+                task_types: None,
+                inference_precision: None,
+                inference_hardware: None,
+                inference_software_dependencies: None,
+                inference_max_energy_consumption_watts: None,
+                inference_max_latency_ms: None,
+                inference_min_throughput: None,
+                inference_max_compute_utilization_percentage: None,
+                inference_max_memory_usage_mb: None,
+                inference_distributed: None,
+                training_time: None,
+                training_precision: None,
+                training_hardware: None,
+                pretraining_datasets: None,
+                finetuning_datasets: None,
+                edge_optimized: None,
+                quantization_aware: None,
+                supports_quantization: None,
+                pretrained: None,
+                pruned: None,
+                slimmed: None,
+                training_distributed: None,
+                training_max_energy_consumption_watts: None,
+                regulatory: None,
+                license: None,
+                bias_evaluation_score: None,
+            }
+        }
+    }
+
+    fn service(repo: Arc<InMemoryModelMetadataRepository>, artifacts: Vec<Artifact>) -> ModelMetadataService {
+        ModelMetadataService::new(repo, Arc::new(InMemoryArtifactRepository { artifacts: Mutex::new(artifacts) }))
+    }
+
+    #[tokio::test]
+    async fn test_update_replaces_metadata() {
+        let repo = Arc::new(InMemoryModelMetadataRepository::default());
+        let model = artifact(ArtifactType::Model);
+        repo.save(&metadata(model.id, "before")).await.unwrap();
+
+        service(repo.clone(), vec![model.clone()]).update_metadata(metadata(model.id, "after")).await
+            .expect("Update should succeed");
+
+        let updated = service(repo, Vec::new()).get_metadata(model.id).await.unwrap();
+        assert_eq!(updated.name, Some("after".into()));
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_metadata() {
+        let repo = Arc::new(InMemoryModelMetadataRepository::default());
+        let model = artifact(ArtifactType::Model);
+
+        let result = service(repo, vec![model.clone()]).update_metadata(metadata(model.id, "name")).await;
+        assert!(matches!(result, Err(ModelMetadataServiceError::MetadataNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_artifact() {
+        let repo = Arc::new(InMemoryModelMetadataRepository::default());
+        let artifact_id = Uuid::new_v4();
+        repo.save(&metadata(artifact_id, "before")).await.unwrap();
+
+        let result = service(repo, Vec::new()).update_metadata(metadata(artifact_id, "after")).await;
+        assert!(matches!(result, Err(ModelMetadataServiceError::ArtifactNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_validates_artifact() {
+        let repo = Arc::new(InMemoryModelMetadataRepository::default());
+        let dataset = artifact(ArtifactType::Dataset);
+        let pending = Artifact::new(ArtifactType::Model);
+        for artifact_id in [dataset.id, pending.id] {
+            repo.save(&metadata(artifact_id, "before")).await.unwrap();
+        }
+
+        let service = service(repo, vec![dataset.clone(), pending.clone()]);
+
+        let result = service.update_metadata(metadata(dataset.id, "after")).await;
+        assert!(matches!(result, Err(ModelMetadataServiceError::DomainServiceError(ModelMetadataDomainServiceError::InvalidArtifactType))));
+
+        let result = service.update_metadata(metadata(pending.id, "after")).await;
+        assert!(matches!(result, Err(ModelMetadataServiceError::DomainServiceError(ModelMetadataDomainServiceError::ArtifactNotReady))));
+
+        // Rejected updates leave the metadata untouched
+        assert_eq!(service.get_metadata(dataset.id).await.unwrap().name, Some("before".into()));
+    }
+
+    #[tokio::test]
+    async fn test_delete_metadata() {
+        let repo = Arc::new(InMemoryModelMetadataRepository::default());
+        let artifact_id = Uuid::new_v4();
+        repo.save(&metadata(artifact_id, "name")).await.unwrap();
+
+        service(repo.clone(), Vec::new()).delete_metadata(artifact_id).await
+            .expect("Delete should succeed");
+
+        let result = service(repo.clone(), Vec::new()).get_metadata(artifact_id).await;
+        assert!(matches!(result, Err(ModelMetadataServiceError::MetadataNotFound(_))));
+
+        let result = service(repo, Vec::new()).delete_metadata(artifact_id).await;
+        assert!(matches!(result, Err(ModelMetadataServiceError::MetadataNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_metadata_paginates() {
+        let repo = Arc::new(InMemoryModelMetadataRepository::default());
+        let artifact_ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for (i, artifact_id) in artifact_ids.iter().enumerate() {
+            repo.save(&metadata(*artifact_id, &format!("model-{}", i))).await.unwrap();
+        }
+
+        let result = service(repo, Vec::new()).list_metadata(ListAll {
+            limit: 2,
+            offset: 1,
+            fields: Vec::new(),
+            filters: None,
+            sort_by: None,
+            order_by: None,
+        }).await.expect("Listing should succeed");
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].0, artifact_ids[1]);
+        assert_eq!(result[1].1.name, Some("model-2".into()));
+    }
+}
\ No newline at end of file
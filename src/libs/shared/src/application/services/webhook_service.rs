@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU16, Ordering};
+use crate::retry::{retry_async, RetryPolicy, ExponentionalBackoff, Retry, Jitter};
+use crate::application::ports::repositories::WebhookDeliveryRepository;
+use crate::application::ports::webhooks::{WebhookEvent, WebhookNotifier, WebhookNotifierError};
+use crate::domain::entities::webhook_delivery::WebhookDelivery;
+use crate::logging::GlobalLogger;
+use thiserror::Error;
+use once_cell::sync::Lazy;
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Error)]
+pub enum WebhookServiceError {
+    #[error("Webhook delivery failed: {0}")]
+    DeliveryFailed(#[from] WebhookNotifierError),
+}
+
+#[derive(Clone)]
+pub struct WebhookService {
+    notifier: Arc<dyn WebhookNotifier>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl WebhookService {
+    const DELIVERY_RETRY_POLICY: Lazy<RetryPolicy> = Lazy::new(|| RetryPolicy::ExponentionalBackoff(
+        ExponentionalBackoff {
+            retries: Retry::NTimes(3),
+            delay: 200,
+            base: Some(2),
+            max_delay: 2000,
+            jitter: Some(Jitter::Full)
+        }
+    ));
+
+    pub fn new(
+        notifier: Arc<dyn WebhookNotifier>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            notifier,
+            delivery_repo,
+        }
+    }
+
+    /// Delivers an event in the background so that a slow or unreachable
+    /// webhook url never holds up the caller. Failures are logged
+    pub fn dispatch(&self, url: String, event: WebhookEvent) -> JoinHandle<()> {
+        let service = self.clone();
+
+        tokio::spawn(async move {
+            if let Err(err) = service.deliver(&url, &event).await {
+                GlobalLogger::error(format!("Failed to deliver webhook event '{}' to '{}': {}", &event.id, &url, err.to_string()).as_str());
+            }
+        })
+    }
+
+    /// Delivers an event to the webhook url with retries. Every attempt is
+    /// recorded as a WebhookDelivery whether it succeeds or not
+    pub async fn deliver(&self, url: &str, event: &WebhookEvent) -> Result<(), WebhookServiceError> {
+        let attempt = AtomicU16::new(0);
+        let attempt = &attempt;
+
+        // Closure for a single delivery attempt
+        let deliver = || async move {
+            let mut delivery = WebhookDelivery::new(
+                event.id.clone(),
+                event.event_type.clone(),
+                event.resource_id.clone(),
+                url.to_string(),
+                attempt.fetch_add(1, Ordering::SeqCst) + 1,
+            );
+
+            let result = self.notifier.notify(url, event).await;
+
+            match &result {
+                Ok(status_code) => delivery.succeed(status_code.clone()),
+                Err(err) => delivery.fail(err.status_code(), err.to_string())
+            };
+
+            // A failure to record the attempt should not affect the delivery itself
+            if let Err(err) = self.delivery_repo.save(&delivery).await {
+                GlobalLogger::error(format!("Failed to record webhook delivery '{}': {}", &delivery.id, err.to_string()).as_str());
+            }
+
+            result
+        };
+
+        retry_async(deliver, &Self::DELIVERY_RETRY_POLICY).await
+            .map_err(|err| WebhookServiceError::DeliveryFailed(err))?;
+
+        Ok(())
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "webhook_service.test.rs"]
+mod webhook_service_test;
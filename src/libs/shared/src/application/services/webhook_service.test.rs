@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod webhook_service_test {
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::{AtomicU16, Ordering};
+    use std::time::{Duration, Instant};
+    use async_trait::async_trait;
+    use uuid::Uuid;
+    use crate::application::errors::ApplicationError;
+    use crate::application::ports::repositories::WebhookDeliveryRepository;
+    use crate::application::ports::webhooks::{WebhookEvent, WebhookNotifier, WebhookNotifierError};
+    use crate::application::services::webhook_service::{WebhookService, WebhookServiceError};
+    use crate::domain::entities::webhook_delivery::{WebhookDelivery, WebhookEventType};
+
+    /// Fails with a 503 until `failures` attempts have been made
+    struct FlakyNotifier {
+        failures: u16,
+        calls: AtomicU16,
+    }
+
+    #[async_trait]
+    impl WebhookNotifier for FlakyNotifier {
+        async fn notify(&self, _url: &str, _event: &WebhookEvent) -> Result<u16, WebhookNotifierError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call < self.failures {
+                return Err(WebhookNotifierError::UnexpectedStatus(503))
+            }
+
+            Ok(200)
+        }
+    }
+
+    /// Takes a while to respond, like an overloaded webhook endpoint
+    struct SlowNotifier;
+
+    #[async_trait]
+    impl WebhookNotifier for SlowNotifier {
+        async fn notify(&self, _url: &str, _event: &WebhookEvent) -> Result<u16, WebhookNotifierError> {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            Ok(200)
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryWebhookDeliveryRepository {
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for InMemoryWebhookDeliveryRepository {
+        async fn save(&self, delivery: &WebhookDelivery) -> Result<(), ApplicationError> {
+            self.deliveries.lock().unwrap().push(delivery.clone());
+            Ok(())
+        }
+
+        async fn find_by_resource_id(&self, resource_id: Uuid) -> Result<Vec<WebhookDelivery>, ApplicationError> {
+            Ok(self.deliveries.lock().unwrap()
+                .iter()
+                .filter(|delivery| delivery.resource_id == resource_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn event() -> WebhookEvent {
+        WebhookEvent::new(
+            WebhookEventType::ArtifactPublicationStatusChanged,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Pending".into(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_records_every_attempt_until_success() {
+        let repo = Arc::new(InMemoryWebhookDeliveryRepository::default());
+        let service = WebhookService::new(
+            Arc::new(FlakyNotifier { failures: 2, calls: AtomicU16::new(0) }),
+            repo.clone(),
+        );
+        let event = event();
+
+        service.deliver("http://localhost/webhook", &event).await
+            .expect("Delivery should succeed on the third attempt");
+
+        let deliveries = repo.find_by_resource_id(event.resource_id).await.unwrap();
+        assert_eq!(deliveries.len(), 3);
+        assert_eq!(deliveries.iter().map(|d| d.attempt).collect::<Vec<u16>>(), vec![1, 2, 3]);
+        assert!(deliveries.iter().all(|d| d.event_id == event.id));
+        assert_eq!(deliveries[0].status_code, Some(503));
+        assert!(!deliveries[0].succeeded);
+        assert!(deliveries[2].succeeded);
+        assert!(deliveries[2].error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_retries_are_exhausted() {
+        let repo = Arc::new(InMemoryWebhookDeliveryRepository::default());
+        let service = WebhookService::new(
+            Arc::new(FlakyNotifier { failures: u16::MAX, calls: AtomicU16::new(0) }),
+            repo.clone(),
+        );
+        let event = event();
+
+        let result = service.deliver("http://localhost/webhook", &event).await;
+        assert!(matches!(result, Err(WebhookServiceError::DeliveryFailed(WebhookNotifierError::UnexpectedStatus(503)))));
+
+        // The initial attempt plus 3 retries
+        let deliveries = repo.find_by_resource_id(event.resource_id).await.unwrap();
+        assert_eq!(deliveries.len(), 4);
+        assert!(deliveries.iter().all(|d| !d.succeeded));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_does_not_wait_for_delivery() {
+        let repo = Arc::new(InMemoryWebhookDeliveryRepository::default());
+        let service = WebhookService::new(Arc::new(SlowNotifier), repo.clone());
+        let event = event();
+
+        let started = Instant::now();
+        let delivery = service.dispatch(String::from("http://localhost/webhook"), event.clone());
+        assert!(started.elapsed() < Duration::from_millis(100));
+        assert!(repo.find_by_resource_id(event.resource_id).await.unwrap().is_empty());
+
+        delivery.await.unwrap();
+        let deliveries = repo.find_by_resource_id(event.resource_id).await.unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert!(deliveries[0].succeeded);
+    }
+}
@@ -8,6 +8,30 @@ pub enum ArtifactType {
     Dataset,
 }
 
+/// Where an ingested artifact came from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArtifactProvenance {
+    /// The platform the artifact was ingested from
+    pub platform: String,
+    /// Id of the artifact on the source platform
+    pub source_id: String,
+    /// The tag, branch or commit requested for the ingestion
+    pub revision: Option<String>,
+    /// Commit sha the revision resolved to when the files were downloaded
+    pub resolved_revision: Option<String>,
+}
+
+impl ArtifactProvenance {
+    pub fn new(platform: String, source_id: String, revision: Option<String>) -> Self {
+        Self {
+            platform,
+            source_id,
+            revision,
+            resolved_revision: None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Artifact {
     pub id: Uuid,
@@ -15,6 +39,8 @@ pub struct Artifact {
     pub path: Option<PathBuf>,
     pub created_at: TimeStamp,
     pub last_modified: TimeStamp,
+    /// Only set for artifacts that were ingested from a platform
+    pub provenance: Option<ArtifactProvenance>,
 }
 
 impl Artifact {
@@ -25,7 +51,8 @@ impl Artifact {
             path: None,
             artifact_type: r#type,
             created_at: now.clone(),
-            last_modified: now.clone()
+            last_modified: now.clone(),
+            provenance: None,
         }
     }
 
@@ -36,6 +63,13 @@ impl Artifact {
         self.touch();
     }
 
+    pub fn set_provenance(&mut self, provenance: ArtifactProvenance) {
+        self.provenance = Some(provenance);
+
+        // Update last modified
+        self.touch();
+    }
+
     pub fn is_fully_ingested(&self) -> bool {
         self.path.is_some()
     }
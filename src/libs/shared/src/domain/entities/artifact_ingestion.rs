@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 use uuid::Uuid;
 use thiserror::Error;
+use crate::domain::entities::artifact::ArtifactProvenance;
 use crate::domain::entities::timestamp::TimeStamp;
 
 #[derive(Debug, Error)]
@@ -9,7 +10,10 @@ pub enum ArtifactIngestionError {
     InvalidStatusTransition(String, String),
 
     #[error("Artifact path error: {0}")]
-    ArtifactPath(String)
+    ArtifactPath(String),
+
+    #[error("Provenance error: {0}")]
+    Provenance(String),
 }
 
 // Private type alias to make things less verbose
@@ -26,6 +30,7 @@ pub struct ArtifactIngestion {
     pub last_modified: TimeStamp,
     pub artifact_path: Option<PathBuf>,
     pub webhook_url: Option<String>,
+    pub provenance: Option<ArtifactProvenance>,
 }
 
 /// Represent the ingestion
@@ -42,6 +47,7 @@ impl ArtifactIngestion {
             last_modified: now.clone(),
             artifact_path: None,
             webhook_url,
+            provenance: None,
         }
     }
 
@@ -109,6 +115,32 @@ impl ArtifactIngestion {
         return Ok(())
     }
 
+    pub fn set_provenance(&mut self, provenance: ArtifactProvenance) {
+        self.provenance = Some(provenance);
+
+        // Updates last modified
+        self.touch();
+    }
+
+    /// Records the commit sha that the requested revision resolved to
+    pub fn set_resolved_revision(&mut self, resolved_revision: String) -> Result<(), IngestionError> {
+        if self.status != Status::Downloaded {
+            return Err(IngestionError::Provenance("This ingestion's resolved revision can only be set while the ingestion has a status of Downloaded".into()))
+        }
+
+        let provenance = match self.provenance.as_mut() {
+            Some(p) => p,
+            None => return Err(IngestionError::Provenance("Cannot set the resolved revision of an ingestion without provenance".into()))
+        };
+
+        provenance.resolved_revision = Some(resolved_revision);
+
+        // Updates last modified
+        self.touch();
+
+        Ok(())
+    }
+
     /// Changes the status. Returns an error if invalid status transition is detected
     pub fn change_status(&mut self, new_status: Status) -> Result<(), IngestionError> {
         if !Self::is_valid_status_transition(&self.status, &new_status) {
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod artifact_ingestion_test {
     use uuid::Uuid;
+    use crate::domain::entities::artifact::ArtifactProvenance;
     use crate::domain::entities::artifact_ingestion::{ArtifactIngestion, ArtifactIngestionStatus};
 
     #[test]
@@ -80,4 +81,36 @@ mod artifact_ingestion_test {
         assert!(result.is_err());
         assert!(ingestion.artifact_path.is_none());
     }
+
+    #[test]
+    fn test_set_resolved_revision() {
+        let test_id = Uuid::parse_str("a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8")
+            .expect("Failed to parse UUID");
+
+        let mut ingestion = ArtifactIngestion::new(test_id, "huggingface".into(), None);
+
+        // Provenance is required to record a resolved revision
+        ingestion.change_status(ArtifactIngestionStatus::Pending).unwrap();
+        ingestion.change_status(ArtifactIngestionStatus::Downloading).unwrap();
+        ingestion.change_status(ArtifactIngestionStatus::Downloaded).unwrap();
+        assert!(ingestion.set_resolved_revision("abc123".into()).is_err());
+
+        ingestion.set_provenance(ArtifactProvenance::new("huggingface".into(), "org/model".into(), Some("v1.0".into())));
+        assert!(ingestion.set_resolved_revision("abc123".into()).is_ok());
+
+        let provenance = ingestion.provenance.unwrap();
+        assert_eq!(provenance.revision, Some(String::from("v1.0")));
+        assert_eq!(provenance.resolved_revision, Some(String::from("abc123")));
+    }
+
+    #[test]
+    fn test_set_resolved_revision_before_download() {
+        let test_id = Uuid::parse_str("a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8")
+            .expect("Failed to parse UUID");
+
+        let mut ingestion = ArtifactIngestion::new(test_id, "huggingface".into(), None);
+        ingestion.set_provenance(ArtifactProvenance::new("huggingface".into(), "org/model".into(), None));
+
+        assert!(ingestion.set_resolved_revision("abc123".into()).is_err());
+    }
 }
\ No newline at end of file
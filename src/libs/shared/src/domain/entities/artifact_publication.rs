@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use uuid::Uuid;
 use crate::domain::entities::timestamp::TimeStamp;
 use thiserror::Error;
@@ -5,7 +6,10 @@ use thiserror::Error;
 #[derive(Debug, Error)]
 pub enum ArtifactPublicationError {
     #[error("Invalid status transition: {0}")]
-    InvalidStatusTransition(String)
+    InvalidStatusTransition(String),
+
+    #[error("Published ids error: {0}")]
+    PublishedIds(String)
 }
 
 pub struct ArtifactPublication  {
@@ -17,11 +21,15 @@ pub struct ArtifactPublication  {
     pub attempts: u8,
     pub created_at: TimeStamp,
     pub last_modified: TimeStamp,
+    pub webhook_url: Option<String>,
+    /// Ids the target platform assigned to what was published, keyed by the
+    /// kind of id. Ex. {"model_card_id": "1234"}
+    pub published_ids: HashMap<String, String>,
 }
 
 /// Represents the life cycle of an attempt to publish an artifact
 impl ArtifactPublication {
-    pub fn new(artifact_id: Uuid, target_platform: String) -> Self {
+    pub fn new(artifact_id: Uuid, target_platform: String, webhook_url: Option<String>) -> Self {
         let now = TimeStamp::now();
         Self {
             id: Uuid::new_v4(),
@@ -32,6 +40,8 @@ impl ArtifactPublication {
             attempts: 0,
             created_at: now.clone(),
             last_modified: now.clone(),
+            webhook_url,
+            published_ids: HashMap::new(),
         }
     }
 
@@ -51,6 +61,20 @@ impl ArtifactPublication {
         Ok(self)
     }
 
+    /// Records the ids the target platform returned for the published artifact
+    /// or metadata. Ids can only be recorded while the publication is publishing
+    pub fn record_published_ids(&mut self, ids: HashMap<String, String>) -> Result<&mut Self, ArtifactPublicationError> {
+        if !(self.status == Status::PublishingArtifact || self.status == Status::PublishingMetadata) {
+            return Err(ArtifactPublicationError::PublishedIds(format!("Ids can only be recorded while an ArtifactPublication has a status of PublishingArtifact or PublishingMetadata. Current status: {}", self.status.kind())))
+        }
+
+        self.published_ids.extend(ids);
+
+        self.touch();
+
+        Ok(self)
+    }
+
     /// Updates last modified to the UTC timestamp
     fn touch(&mut self) {
         self.last_modified = TimeStamp::now()
@@ -159,6 +183,12 @@ impl ArtifactPublicationStatus {
     }
 }
 
+impl From<Status> for String {
+    fn from(value: Status) -> Self {
+        value.kind().into()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArtifactPublicationFailureReason {
     FailedToQueue(String),
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod artifact_publication_test {
+    use std::collections::HashMap;
     use uuid::Uuid;
     use crate::domain::entities::artifact_publication::{
         ArtifactPublication,
@@ -12,6 +13,7 @@ mod artifact_publication_test {
         let publication = ArtifactPublication::new(
             artifact_id,
             "platform".into(),
+            None,
         );
         
         assert!(publication.artifact_id == artifact_id);
@@ -26,6 +28,7 @@ mod artifact_publication_test {
         let mut publication = ArtifactPublication::new(
             Uuid::new_v4(),
             "platform".into(),
+            None,
         );
 
         let last_modified_before = publication.last_modified.clone();
@@ -48,6 +51,7 @@ mod artifact_publication_test {
         let mut publication = ArtifactPublication::new(
             Uuid::new_v4(),
             "platform".into(),
+            None,
         );
 
         let maybe_publication = publication.change_status(&ArtifactPublicationStatus::Pending)
@@ -95,9 +99,33 @@ mod artifact_publication_test {
         let mut publication = ArtifactPublication::new(
             Uuid::new_v4(),
             "platform".into(),
+            None,
         );
 
         let maybe_publication = publication.change_status(&ArtifactPublicationStatus::Finished);
         assert!(maybe_publication.is_err())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_record_published_ids() {
+        let mut publication = ArtifactPublication::new(
+            Uuid::new_v4(),
+            "patra".into(),
+            None,
+        );
+
+        let ids = HashMap::from([(String::from("model_card_id"), String::from("1234"))]);
+
+        // Ids can only be recorded while publishing
+        assert!(publication.record_published_ids(ids.clone()).is_err());
+        assert!(publication.published_ids.is_empty());
+
+        publication.change_status(&ArtifactPublicationStatus::Pending).unwrap();
+        publication.change_status(&ArtifactPublicationStatus::PublishingMetadata).unwrap();
+        publication.record_published_ids(ids).unwrap();
+        publication.record_published_ids(HashMap::from([(String::from("model_id"), String::from("5678"))])).unwrap();
+
+        assert_eq!(publication.published_ids.get("model_card_id").map(String::as_str), Some("1234"));
+        assert_eq!(publication.published_ids.get("model_id").map(String::as_str), Some("5678"));
+    }
+}
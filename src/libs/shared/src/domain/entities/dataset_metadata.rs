@@ -0,0 +1,53 @@
+/// A single column or field of a dataset
+pub struct DatasetFeature {
+    pub name: String,
+    pub data_type: Option<String>,
+    pub description: Option<String>,
+    pub nullable: Option<bool>
+}
+
+/// A named partition of a dataset. Ex. train, validation, test
+pub struct DatasetSplit {
+    pub name: String,
+    pub num_rows: Option<i64>,
+    pub num_bytes: Option<i64>
+}
+
+/// Where the dataset came from and how it was produced
+pub struct DatasetProvenance {
+    pub source_platform: Option<String>,
+    pub source_id: Option<String>,
+    pub source_url: Option<String>,
+    pub revision: Option<String>,
+    pub creators: Option<Vec<String>>,
+    pub citation: Option<String>,
+    pub collection_method: Option<String>
+}
+
+pub struct DatasetMetadata {
+    // General fields
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    /// The kinds of data in the dataset. Ex. text, image, audio, tabular
+    pub modalities: Option<Vec<String>>,
+    pub task_types: Option<Vec<String>>,
+    /// File format of the dataset files. Ex. parquet, csv, jsonl
+    pub format: Option<String>,
+
+    /// Arbitrary labels
+    pub labels: Option<Vec<String>>,
+
+    /// Structure fields
+    pub schema: Option<Vec<DatasetFeature>>,
+    pub splits: Option<Vec<DatasetSplit>>,
+    pub num_rows: Option<i64>,
+    pub size_bytes: Option<i64>,
+
+    /// Provenance fields
+    pub provenance: Option<DatasetProvenance>,
+
+    /// Regulatory and Compliance Fields
+    pub license: Option<String>,
+    pub regulatory: Option<Vec<String>>,
+}
@@ -4,4 +4,6 @@ pub mod timestamp;
 pub mod artifact_publication;
 pub mod model_metadata;
 pub mod model_metadata_new;
-pub mod inference;
\ No newline at end of file
+pub mod inference;
+pub mod webhook_delivery;
+pub mod dataset_metadata;
\ No newline at end of file
@@ -0,0 +1,72 @@
+use uuid::Uuid;
+use crate::domain::entities::timestamp::TimeStamp;
+
+/// The kind of resource whose status change triggered a webhook
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebhookEventType {
+    ArtifactIngestionStatusChanged,
+    ArtifactPublicationStatusChanged,
+}
+
+impl From<WebhookEventType> for String {
+    fn from(value: WebhookEventType) -> Self {
+        match value {
+            WebhookEventType::ArtifactIngestionStatusChanged => "artifact.ingestion.status_changed".into(),
+            WebhookEventType::ArtifactPublicationStatusChanged => "artifact.publication.status_changed".into(),
+        }
+    }
+}
+
+/// Represents a single attempt to deliver a webhook event to a webhook url
+#[derive(Clone, Debug)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    pub resource_id: Uuid,
+    pub url: String,
+    pub attempt: u16,
+    pub status_code: Option<u16>,
+    pub succeeded: bool,
+    pub error: Option<String>,
+    pub created_at: TimeStamp,
+}
+
+impl WebhookDelivery {
+    pub fn new(
+        event_id: Uuid,
+        event_type: WebhookEventType,
+        resource_id: Uuid,
+        url: String,
+        attempt: u16,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            event_type,
+            resource_id,
+            url,
+            attempt,
+            status_code: None,
+            succeeded: false,
+            error: None,
+            created_at: TimeStamp::now(),
+        }
+    }
+
+    /// Records the outcome of a delivery attempt that received a successful
+    /// response from the webhook url
+    pub fn succeed(&mut self, status_code: u16) {
+        self.status_code = Some(status_code);
+        self.succeeded = true;
+        self.error = None;
+    }
+
+    /// Records the outcome of a delivery attempt that failed either before
+    /// or after a response was received
+    pub fn fail(&mut self, status_code: Option<u16>, error: String) {
+        self.status_code = status_code;
+        self.succeeded = false;
+        self.error = Some(error);
+    }
+}
@@ -4,6 +4,7 @@ use thiserror::Error;
 
 use super::entities::artifact::ArtifactType;
 use super::entities::model_metadata::ModelMetadata;
+use super::entities::dataset_metadata::DatasetMetadata;
 
 #[derive(Debug, Error)]
 pub enum ArtifactServiceError {
@@ -23,6 +24,12 @@ impl ArtifactService {
         match &ingestion.artifact_path {
             Some(path) => {
                 artifact.set_path(path.clone());
+
+                // Carry the ingestion's provenance over to the artifact
+                if let Some(provenance) = &ingestion.provenance {
+                    artifact.set_provenance(provenance.clone());
+                }
+
                 Ok(artifact)
             },
             None => {
@@ -57,4 +64,42 @@ impl ModelMetadataService {
 
         return Ok(());
     }
-}
\ No newline at end of file
+
+    /// Metadata can only replace the metadata of an artifact it could have
+    /// been created for
+    pub fn update(artifact: &Artifact, metadata: ModelMetadata) -> Result<(), ModelMetadataServiceError> {
+        Self::create(artifact, metadata)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DatasetMetadataServiceError {
+    #[error("Cannot create metadata for an artifact that is not fully ingested")]
+    ArtifactNotReady,
+
+    #[error("Invalid artifact type. Artifact must be of type 'Dataset'")]
+    InvalidArtifactType
+}
+
+pub struct DatasetMetadataService {}
+
+impl DatasetMetadataService {
+    /// Verifies the the artifact is fully ingested or uploaded and that it
+    /// is a dataset
+    pub fn create(artifact: &Artifact, _metadata: DatasetMetadata) -> Result<(), DatasetMetadataServiceError> {
+        if !artifact.is_fully_ingested() {
+            return Err(DatasetMetadataServiceError::ArtifactNotReady);
+        }
+
+        if artifact.artifact_type != ArtifactType::Dataset {
+            return Err(DatasetMetadataServiceError::InvalidArtifactType);
+        }
+
+        return Ok(());
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "services.test.rs"]
+mod services_test;
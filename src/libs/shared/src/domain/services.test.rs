@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod services_test {
+    use crate::domain::entities::artifact::{Artifact, ArtifactType};
+    use crate::domain::entities::dataset_metadata::DatasetMetadata;
+    use crate::domain::services::{DatasetMetadataService, DatasetMetadataServiceError};
+
+    fn metadata() -> DatasetMetadata {
+        DatasetMetadata {
+            name: Some("dataset".into()),
+            version: None,
+            description: None,
+            modalities: Some(vec!["text".into()]),
+            task_types: None,
+            format: None,
+            labels: None,
+            schema: None,
+            splits: None,
+            num_rows: None,
+            size_bytes: None,
+            provenance: None,
+            license: None,
+            regulatory: None,
+        }
+    }
+
+    #[test]
+    fn test_create_dataset_metadata() {
+        let mut artifact = Artifact::new(ArtifactType::Dataset);
+        artifact.set_path("/path/to/artifact".into());
+
+        assert!(DatasetMetadataService::create(&artifact, metadata()).is_ok());
+    }
+
+    #[test]
+    fn test_create_dataset_metadata_artifact_not_ready() {
+        let artifact = Artifact::new(ArtifactType::Dataset);
+
+        assert!(matches!(
+            DatasetMetadataService::create(&artifact, metadata()),
+            Err(DatasetMetadataServiceError::ArtifactNotReady)
+        ));
+    }
+
+    #[test]
+    fn test_create_dataset_metadata_invalid_artifact_type() {
+        let mut artifact = Artifact::new(ArtifactType::Model);
+        artifact.set_path("/path/to/artifact".into());
+
+        assert!(matches!(
+            DatasetMetadataService::create(&artifact, metadata()),
+            Err(DatasetMetadataServiceError::InvalidArtifactType)
+        ));
+    }
+}
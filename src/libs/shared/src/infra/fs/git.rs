@@ -1,8 +1,12 @@
 use crate::infra::system::validate_system_dependencies;
 use crate::logging::GlobalLogger;
+use crate::presentation::http::v1::dto::headers::Headers;
+use crate::presentation::http::v1::dto::Parameters;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde_json::Value;
 use std::process::Command;
 use std::fs::{create_dir_all, read_dir};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 use crate::infra::system::SystemError;
 
@@ -17,11 +21,181 @@ pub enum GitError {
     #[error("Error pulling branch of git repository: {0}")]
     Pull(String),
 
+    #[error("Error checking out revision of git repository: {0}")]
+    Checkout(String),
+
+    #[error("Error resolving revision of git repository: {0}")]
+    RevParse(String),
+
     #[error("Git LFS error listing large files: {0}")]
     LfsList(String),
 
     #[error("Git LFS error pulling large files: {0}")]
     LfsPull(String),
+
+    #[error("Invalid git credentials: {0}")]
+    Auth(String),
+}
+
+/// Environment variable with the directory that SSH deploy keys are mounted in.
+/// Requests reference a key by its file name in this directory so that private
+/// keys are never sent over the API. A request may only use the keys configured
+/// for the remote it clones from
+pub const GIT_SSH_KEYS_DIR_ENV_VAR: &str = "GIT_SSH_KEYS_DIR";
+
+/// Environment variable that opts into trusting the key of an SSH host on
+/// first use when the SSH keys directory has no known hosts file. Must be
+/// "true" to take effect
+pub const GIT_SSH_ACCEPT_NEW_HOST_KEYS_ENV_VAR: &str = "GIT_SSH_ACCEPT_NEW_HOST_KEYS";
+
+/// Name of the known hosts file in the SSH keys directory. Hosts missing from
+/// it are rejected
+const KNOWN_HOSTS_FILE_NAME: &str = "known_hosts";
+
+/// How git authenticates with the remote of a repository
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum GitAuth {
+    /// Public repositories
+    #[default]
+    None,
+    /// Token sent in an `Authorization: Bearer` header over HTTP(S)
+    Bearer(String),
+    /// Username and token (or password) sent in an `Authorization: Basic`
+    /// header over HTTP(S). Required by hosts like GitHub and GitLab, which
+    /// reject bearer tokens for git operations
+    Basic {
+        username: String,
+        token: String,
+    },
+    /// Private key of an SSH deploy key. Used by ssh remotes
+    SshKey {
+        key_path: PathBuf,
+        /// None only when trusting new host keys on first use was opted into
+        known_hosts_path: Option<PathBuf>,
+    },
+}
+
+impl GitAuth {
+    /// Resolves the auth of a request from its Authorization header and its
+    /// `auth_mode`, `username` and `ssh_key_secret` params. The token in the
+    /// Authorization header takes precedence over the configured token. The
+    /// SSH key secret must be one of the keys configured for the remote
+    pub fn from_request(
+        headers: &Headers,
+        params: Option<&Parameters>,
+        configured_token: Option<String>,
+        configured_ssh_keys: &[String],
+    ) -> Result<Self, GitError> {
+        let param = |name: &str| match params.and_then(|params| params.get(name)) {
+            Some(Value::String(value)) => Some(value.clone()),
+            Some(value) => Some(value.to_string()),
+            None => None,
+        };
+
+        let token = headers.bearer_token()
+            .map_err(|err| GitError::Auth(format!("Invalid Authorization header: {}", err)))?;
+
+        Self::resolve(
+            param("auth_mode").as_deref(),
+            param("username"),
+            param("ssh_key_secret"),
+            token.or(configured_token),
+            configured_ssh_keys,
+        )
+    }
+
+    /// Resolves the auth mode of a request. When no mode is provided, it is
+    /// inferred from the credentials: an SSH key secret selects `ssh`, a
+    /// username selects `basic` and otherwise the access token, if any, is
+    /// sent as a bearer token
+    pub fn resolve(
+        mode: Option<&str>,
+        username: Option<String>,
+        ssh_key_secret: Option<String>,
+        access_token: Option<String>,
+        configured_ssh_keys: &[String],
+    ) -> Result<Self, GitError> {
+        let mode = match mode {
+            Some(mode) => mode.trim().to_lowercase(),
+            None if ssh_key_secret.is_some() => String::from("ssh"),
+            None if username.is_some() => String::from("basic"),
+            None => String::from("bearer"),
+        };
+
+        match mode.as_str() {
+            "none" => Ok(Self::None),
+            "bearer" => Ok(access_token.map(Self::Bearer).unwrap_or_default()),
+            "basic" => {
+                let username = username.filter(|username| !username.is_empty())
+                    .ok_or(GitError::Auth("Basic auth requires a username".into()))?;
+                let token = access_token
+                    .ok_or(GitError::Auth("Basic auth requires an access token".into()))?;
+
+                Ok(Self::Basic { username, token })
+            },
+            "ssh" => {
+                let secret = ssh_key_secret
+                    .ok_or(GitError::Auth("SSH auth requires the name of an SSH key secret".into()))?;
+
+                Self::ssh_key_from_secret(&secret, configured_ssh_keys)
+            },
+            mode => Err(GitError::Auth(format!("Unsupported auth mode '{}'. Expected one of none, bearer, basic or ssh", mode))),
+        }
+    }
+
+    /// Resolves a reference to an SSH deploy key mounted in the directory set
+    /// by `GIT_SSH_KEYS_DIR`. Every key is mounted in the same directory, so
+    /// only the keys configured for the remote may be referenced
+    pub fn ssh_key_from_secret(secret: &str, configured_ssh_keys: &[String]) -> Result<Self, GitError> {
+        let keys_dir = std::env::var(GIT_SSH_KEYS_DIR_ENV_VAR)
+            .map_err(|_| GitError::Auth(format!("SSH auth is not configured. {} missing from environment variables", GIT_SSH_KEYS_DIR_ENV_VAR)))?;
+
+        let accept_new_host_keys = std::env::var(GIT_SSH_ACCEPT_NEW_HOST_KEYS_ENV_VAR)
+            .map(|value| value.trim().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+
+        Self::ssh_key_in(Path::new(&keys_dir), secret, configured_ssh_keys, accept_new_host_keys)
+    }
+
+    pub(crate) fn ssh_key_in(
+        keys_dir: &Path,
+        secret: &str,
+        configured_ssh_keys: &[String],
+        accept_new_host_keys: bool,
+    ) -> Result<Self, GitError> {
+        // The secret must name a file directly in the keys directory
+        let is_file_name = !secret.is_empty()
+            && secret != "."
+            && secret != ".."
+            && secret != KNOWN_HOSTS_FILE_NAME
+            && !secret.contains(['/', '\\']);
+        if !is_file_name {
+            return Err(GitError::Auth(format!("Invalid SSH key secret '{}'", secret)))
+        }
+
+        if !configured_ssh_keys.iter().any(|key| key == secret) {
+            return Err(GitError::Auth(format!("SSH key secret '{}' is not configured for this remote", secret)))
+        }
+
+        let key_path = keys_dir.join(secret);
+        if !key_path.is_file() {
+            return Err(GitError::Auth(format!("SSH key secret '{}' does not exist", secret)))
+        }
+
+        // Without known hosts, the host cannot be verified unless trusting it
+        // on first use was explicitly opted into
+        let known_hosts_path = Some(keys_dir.join(KNOWN_HOSTS_FILE_NAME))
+            .filter(|path| path.is_file());
+        if known_hosts_path.is_none() && !accept_new_host_keys {
+            return Err(GitError::Auth(format!(
+                "SSH auth requires a {} file in the SSH keys directory. Set {}=true to trust hosts on first use instead",
+                KNOWN_HOSTS_FILE_NAME,
+                GIT_SSH_ACCEPT_NEW_HOST_KEYS_ENV_VAR
+            )))
+        }
+
+        Ok(Self::SshKey { key_path, known_hosts_path })
+    }
 }
 
 #[derive(Clone)]
@@ -84,17 +258,16 @@ impl PreparedRepository {
         // Set the current work directory
         cmd.current_dir(&self.path);
         
-        // Extend the headers on the clone command with the provided access token
-        if let Some(access_token) = &params.access_token {
-            cmd.arg("-c")
-                .arg(format!("http.extraHeader=\"Authorization: Bearer {}\"", access_token));
-        }
+        // Authenticate the clone command with the provided credentials
+        authorize(&mut cmd, &params.auth);
 
-        // Add the branch to clone
+        // Add the branch or tag to clone. Commits cannot be cloned directly so
+        // they are checked out after the clone
         cmd.arg("clone");
-        if let Some(branch) = params.branch{
+        let commit = params.revision.clone().filter(|revision| is_commit_sha(revision));
+        if let Some(revision) = params.revision.filter(|revision| !is_commit_sha(revision)) {
             cmd.arg("--branch")
-                .arg(branch)
+                .arg(revision)
                 .arg("--single-branch");
         }
 
@@ -111,6 +284,10 @@ impl PreparedRepository {
         match output.status.code() {
             Some(code) => {
                 if code == 0 {
+                    if let Some(commit) = commit {
+                        self.checkout(&commit)?;
+                    }
+
                     return Ok(self)
                 }
                 
@@ -133,18 +310,40 @@ impl PreparedRepository {
         // Set the current work directory
         cmd.current_dir(&self.path);
         
-        // Extend the headers on the clone command with the provided access token
-        if let Some(access_token) = &params.access_token {
-            cmd.arg("-c")
-                .arg(format!("http.extraHeader=\"Authorization: Bearer {}\"", access_token));
+        // Authenticate the clone command with the provided credentials
+        authorize(&mut cmd, &params.auth);
+
+        // Commits are fetched and checked out rather than pulled
+        if let Some(commit) = params.revision.clone().filter(|revision| is_commit_sha(revision)) {
+            cmd.arg("fetch")
+                .arg("origin")
+                .arg(&commit);
+
+            let output = cmd.output()
+                .map_err(|err| {
+                    GlobalLogger::debug(format!("Error running `git fetch`: {}", err.to_string()).as_str());
+                    GitError::Pull(err.to_string())
+                })?;
+
+            // Checking out a commit that was never fetched fails with an
+            // unhelpful error, so report why the fetch failed instead
+            if !output.status.success() {
+                return Err(GitError::Pull(format!(
+                    "Failed to fetch commit '{}': {}",
+                    commit,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )))
+            }
+
+            return self.checkout(&commit)
         }
 
         cmd.arg("pull");
         
-        // Add the branch to clone
-        if let Some(branch) = params.branch {
+        // Add the branch to pull
+        if let Some(revision) = params.revision {
             cmd.arg("origin")
-                .arg(branch);
+                .arg(revision);
         }
 
         // Run the command
@@ -157,6 +356,44 @@ impl PreparedRepository {
         Ok(self)
     }
 
+    fn checkout(&self, revision: &str) -> Result<&Self, GitError> {
+        let output = Command::new("git")
+            .current_dir(&self.path)
+            .arg("checkout")
+            .arg("--detach")
+            .arg(revision)
+            .output()
+            .map_err(|err| {
+                GlobalLogger::debug(format!("Error running `git checkout`: {}", err.to_string()).as_str());
+                GitError::Checkout(err.to_string())
+            })?;
+
+        if !output.status.success() {
+            return Err(GitError::Checkout(String::from_utf8_lossy(&output.stderr).into_owned()))
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the sha of the commit currently checked out
+    pub fn head_revision(&self) -> Result<String, GitError> {
+        let output = Command::new("git")
+            .current_dir(&self.path)
+            .arg("rev-parse")
+            .arg("HEAD")
+            .output()
+            .map_err(|err| {
+                GlobalLogger::debug(format!("Error running `git rev-parse`: {}", err.to_string()).as_str());
+                GitError::RevParse(err.to_string())
+            })?;
+
+        if !output.status.success() {
+            return Err(GitError::RevParse(String::from_utf8_lossy(&output.stderr).into_owned()))
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
     /// Clones a git repository if it does not exist in the cache. Pull the repository
     /// if it does exist.
     pub fn clone_or_pull_repo(&self, params: GitCloneOrPullParams) -> Result<&Self, GitError> {
@@ -168,8 +405,8 @@ impl PreparedRepository {
         // Pull the repo at the target path if it exists otherwise clone
         if self.path.exists() && contains_files {
             self.pull(GitPullParams {
-                branch: params.branch,
-                access_token: params.access_token
+                revision: params.revision,
+                auth: params.auth
             })
                 .map_err(|err| err)?;
 
@@ -177,8 +414,8 @@ impl PreparedRepository {
         }
 
         self.clone(GitCloneParams {
-            branch: params.branch,
-            access_token: params.access_token
+            revision: params.revision,
+            auth: params.auth
         })
             .map_err(|err| err)?;
 
@@ -186,27 +423,79 @@ impl PreparedRepository {
     }
 }
 
+/// Authenticates a git command. Tokens are added to the http headers sent by
+/// git through its environment config rather than a `-c` argument so that they
+/// do not appear on the command line of the process
+pub(crate) fn authorize(cmd: &mut Command, auth: &GitAuth) {
+    let header = match auth {
+        GitAuth::None => return,
+        GitAuth::Bearer(token) => format!("Authorization: Bearer {}", token),
+        GitAuth::Basic { username, token } => format!(
+            "Authorization: Basic {}",
+            BASE64_STANDARD.encode(format!("{}:{}", username, token))
+        ),
+        GitAuth::SshKey { key_path, known_hosts_path } => {
+            cmd.env("GIT_SSH_COMMAND", ssh_command(key_path, known_hosts_path.as_deref()));
+            return
+        },
+    };
+
+    cmd.env("GIT_CONFIG_COUNT", "1")
+        .env("GIT_CONFIG_KEY_0", "http.extraHeader")
+        .env("GIT_CONFIG_VALUE_0", header);
+}
+
+/// The ssh command git runs for ssh remotes. Only the deploy key is offered and
+/// ssh never prompts, as there is no one to answer
+fn ssh_command(key_path: &Path, known_hosts_path: Option<&Path>) -> String {
+    let host_key_checking = match known_hosts_path {
+        Some(path) => format!("-o UserKnownHostsFile={} -o StrictHostKeyChecking=yes", shell_quote(path)),
+        None => String::from("-o StrictHostKeyChecking=accept-new"),
+    };
+
+    format!(
+        "ssh -i {} -o IdentitiesOnly=yes -o BatchMode=yes {}",
+        shell_quote(key_path),
+        host_key_checking
+    )
+}
+
+/// Git runs `GIT_SSH_COMMAND` through the shell
+fn shell_quote(path: &Path) -> String {
+    format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"))
+}
+
+/// Whether a revision is a full commit sha (sha-1 or sha-256) rather than the
+/// name of a branch or tag
+pub fn is_commit_sha(revision: &str) -> bool {
+    (revision.len() == 40 || revision.len() == 64)
+        && revision.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 pub struct PrepareRepositoryParams {
     pub target_dir: String
 }
 
 pub struct GitCloneOrPullParams {
-    pub branch: Option<String>,
-    pub access_token: Option<String>,
+    /// Branch, tag or commit sha
+    pub revision: Option<String>,
+    pub auth: GitAuth,
 }
 
 pub struct GitCloneParams {
-    pub branch: Option<String>,
-    pub access_token: Option<String>,
+    /// Branch, tag or commit sha
+    pub revision: Option<String>,
+    pub auth: GitAuth,
 }
 
 pub struct GitPullParams {
-    pub branch: Option<String>,
-    pub access_token: Option<String>,
+    /// Branch, tag or commit sha
+    pub revision: Option<String>,
+    pub auth: GitAuth,
 }
 
 pub struct GitLfsPullLargeFilesParams {
-    pub access_token: Option<String>,
+    pub auth: GitAuth,
     pub include_paths: Option<Vec<String>>,
     pub exclude_paths: Option<Vec<String>>
 }
@@ -261,11 +550,11 @@ impl GitLfsRepository {
         
         let mut cmd = Command::new("git");
 
-        // Extend the headers on the clone command with the provided access token
-        if let Some(access_token) = &params.access_token {
-            cmd.arg("-c")
-                .arg(format!("http.extraHeader=\"Authorization: Bearer {}\"", access_token));
-        }
+        // Large files are pulled into the repository the pointers were listed from
+        cmd.current_dir(&self.repo.path);
+
+        // Authenticate the pull command with the provided credentials
+        authorize(&mut cmd, &params.auth);
         
         cmd.arg("lfs")
             .arg("pull")
@@ -285,16 +574,18 @@ pub struct SyncGitRepositoryParams {
     pub name: String,
     pub remote_base_url: String,
     pub target_dir: String,
-    pub branch: Option<String>,
-    pub access_token: Option<String>,
+    /// Branch, tag or commit sha
+    pub revision: Option<String>,
+    pub auth: GitAuth,
 }
 
 pub struct SyncLfsRepositoryParams {
     pub name: String,
     pub remote_base_url: String,
     pub target_dir: String,
-    pub branch: Option<String>,
-    pub access_token: Option<String>,
+    /// Branch, tag or commit sha
+    pub revision: Option<String>,
+    pub auth: GitAuth,
     pub include_paths: Option<Vec<String>>,
     pub exclude_paths: Option<Vec<String>>,
 }
@@ -320,8 +611,8 @@ impl<T: SyncGitRepository> SyncGitRepositoryImpl for T {
             })?;
 
         prepared_repo.clone_or_pull_repo(GitCloneOrPullParams {
-            branch: params.branch,
-            access_token: params.access_token
+            revision: params.revision,
+            auth: params.auth
         })?;
 
         Ok(prepared_repo)
@@ -332,8 +623,8 @@ impl<T: SyncGitRepository> SyncGitRepositoryImpl for T {
             name: params.name.clone(),
             remote_base_url: params.remote_base_url.clone(),
             target_dir: params.target_dir.clone(),
-            branch: params.branch.clone(),
-            access_token: params.access_token.clone()
+            revision: params.revision.clone(),
+            auth: params.auth.clone()
         })?;
 
         let git_lfs_repo = GitLfsRepository::from_prepared_git_repo(
@@ -341,11 +632,16 @@ impl<T: SyncGitRepository> SyncGitRepositoryImpl for T {
         )?;
         
         git_lfs_repo.pull(GitLfsPullLargeFilesParams {
-            access_token: params.access_token.clone(),
+            auth: params.auth.clone(),
             include_paths: params.include_paths.clone(),
             exclude_paths: params.exclude_paths.clone()
         })?;
 
         Ok(git_lfs_repo)
     }
-}
\ No newline at end of file
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "git.test.rs"]
+mod git_test;
\ No newline at end of file
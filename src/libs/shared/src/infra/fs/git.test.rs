@@ -0,0 +1,274 @@
+#[cfg(test)]
+mod git_test {
+    use std::ffi::OsStr;
+    use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use serde_json::json;
+    use crate::presentation::http::v1::dto::headers::Headers;
+    use crate::presentation::http::v1::dto::Parameters;
+    use crate::infra::fs::git::{
+        authorize, is_commit_sha, GitAuth, GitCloneOrPullParams, GitError, GitRepository,
+        PrepareRepositoryParams,
+    };
+
+    /// Runs git in a directory and returns its trimmed stdout
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com", "-c", "init.defaultBranch=main"])
+            .args(args)
+            .output()
+            .unwrap();
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// Creates a bare repository named `model` in the `remote` directory of a
+    /// new test directory with a single commit on main. Returns the test
+    /// directory and the sha of the commit
+    fn local_remote(name: &str) -> (PathBuf, String) {
+        let root = std::env::temp_dir().join(format!("git-auth-test-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&root);
+        let remote_dir = root.join("remote");
+        let work_dir = root.join("work");
+        create_dir_all(&remote_dir).unwrap();
+        create_dir_all(&work_dir).unwrap();
+
+        git(&remote_dir, &["init", "--bare", "model.git"]);
+        git(&work_dir, &["init"]);
+        write(work_dir.join("README.md"), "# Model").unwrap();
+        git(&work_dir, &["add", "README.md"]);
+        git(&work_dir, &["commit", "-m", "Initial commit"]);
+        git(&work_dir, &["push", remote_dir.join("model.git").to_str().unwrap(), "HEAD:refs/heads/main"]);
+        let sha = git(&work_dir, &["rev-parse", "HEAD"]);
+
+        (root, sha)
+    }
+
+    /// Clones (or pulls) the main branch of the `model` repository and returns
+    /// the revision checked out
+    fn sync(remote_base_url: String, target_dir: &Path, auth: GitAuth) -> Result<String, GitError> {
+        let repo = GitRepository::new(remote_base_url, String::from("model"))
+            .prepare(PrepareRepositoryParams { target_dir: target_dir.to_string_lossy().to_string() })?;
+
+        repo.clone_or_pull_repo(GitCloneOrPullParams { revision: Some(String::from("main")), auth })?;
+
+        repo.head_revision()
+    }
+
+    fn env<'a>(cmd: &'a Command, key: &str) -> Option<&'a OsStr> {
+        cmd.get_envs()
+            .find(|(name, _)| *name == OsStr::new(key))
+            .and_then(|(_, value)| value)
+    }
+
+    #[test]
+    fn test_is_commit_sha() {
+        assert!(is_commit_sha("0123456789abcdef0123456789abcdef01234567"));
+        assert!(is_commit_sha(&"a".repeat(64)));
+        assert!(!is_commit_sha("main"));
+        assert!(!is_commit_sha("v1.0.0"));
+        assert!(!is_commit_sha("0123456"));
+        assert!(!is_commit_sha("0123456789abcdef0123456789abcdef0123456g"));
+    }
+
+    #[test]
+    fn test_authorize_keeps_token_off_command_line() {
+        let mut cmd = Command::new("git");
+        authorize(&mut cmd, &GitAuth::Bearer(String::from("hf_secret")));
+        cmd.arg("clone").arg("https://huggingface.co/org/model.git");
+
+        assert!(cmd.get_args().all(|arg| !arg.to_string_lossy().contains("hf_secret")));
+        let envs: Vec<(&OsStr, Option<&OsStr>)> = cmd.get_envs().collect();
+        assert!(envs.contains(&(OsStr::new("GIT_CONFIG_KEY_0"), Some(OsStr::new("http.extraHeader")))));
+        assert!(envs.contains(&(OsStr::new("GIT_CONFIG_VALUE_0"), Some(OsStr::new("Authorization: Bearer hf_secret")))));
+
+        let mut cmd = Command::new("git");
+        authorize(&mut cmd, &GitAuth::None);
+        assert_eq!(cmd.get_envs().count(), 0);
+    }
+
+    #[test]
+    fn test_authorize_basic_and_ssh() {
+        let mut cmd = Command::new("git");
+        authorize(&mut cmd, &GitAuth::Basic { username: String::from("user"), token: String::from("secret") });
+        cmd.arg("clone").arg("https://github.com/org/model.git");
+
+        assert!(cmd.get_args().all(|arg| !arg.to_string_lossy().contains("secret")));
+        assert_eq!(env(&cmd, "GIT_CONFIG_KEY_0"), Some(OsStr::new("http.extraHeader")));
+        assert_eq!(env(&cmd, "GIT_CONFIG_VALUE_0"), Some(OsStr::new("Authorization: Basic dXNlcjpzZWNyZXQ=")));
+
+        let mut cmd = Command::new("git");
+        authorize(&mut cmd, &GitAuth::SshKey {
+            key_path: PathBuf::from("/keys/deploy key"),
+            known_hosts_path: Some(PathBuf::from("/keys/known_hosts")),
+        });
+
+        assert_eq!(env(&cmd, "GIT_CONFIG_VALUE_0"), None);
+        assert_eq!(
+            env(&cmd, "GIT_SSH_COMMAND"),
+            Some(OsStr::new("ssh -i '/keys/deploy key' -o IdentitiesOnly=yes -o BatchMode=yes -o UserKnownHostsFile='/keys/known_hosts' -o StrictHostKeyChecking=yes"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_auth_mode() {
+        let token = || Some(String::from("token"));
+
+        assert_eq!(GitAuth::resolve(None, None, None, None, &[]).unwrap(), GitAuth::None);
+        assert_eq!(GitAuth::resolve(None, None, None, token(), &[]).unwrap(), GitAuth::Bearer(String::from("token")));
+        assert_eq!(
+            GitAuth::resolve(None, Some(String::from("user")), None, token(), &[]).unwrap(),
+            GitAuth::Basic { username: String::from("user"), token: String::from("token") }
+        );
+        assert_eq!(GitAuth::resolve(Some("none"), None, None, token(), &[]).unwrap(), GitAuth::None);
+
+        assert!(matches!(GitAuth::resolve(Some("basic"), None, None, token(), &[]), Err(GitError::Auth(_))));
+        assert!(matches!(GitAuth::resolve(Some("basic"), Some(String::from("user")), None, None, &[]), Err(GitError::Auth(_))));
+        assert!(matches!(GitAuth::resolve(Some("ssh"), None, None, None, &[]), Err(GitError::Auth(_))));
+        assert!(matches!(GitAuth::resolve(Some("kerberos"), None, None, token(), &[]), Err(GitError::Auth(_))));
+    }
+
+    #[test]
+    fn test_auth_from_request() {
+        let bearer = Headers::new(vec![(String::from("Authorization"), String::from("Bearer request-token"))]);
+        let no_headers = Headers::new(Vec::new());
+        let configured = || Some(String::from("configured-token"));
+
+        // The token of the request takes precedence over the configured token
+        assert_eq!(
+            GitAuth::from_request(&bearer, None, configured(), &[]).unwrap(),
+            GitAuth::Bearer(String::from("request-token"))
+        );
+        assert_eq!(
+            GitAuth::from_request(&no_headers, None, configured(), &[]).unwrap(),
+            GitAuth::Bearer(String::from("configured-token"))
+        );
+
+        let params: Parameters = serde_json::from_value(json!({ "username": "user" })).unwrap();
+        assert_eq!(
+            GitAuth::from_request(&bearer, Some(&params), None, &[]).unwrap(),
+            GitAuth::Basic { username: String::from("user"), token: String::from("request-token") }
+        );
+
+        let params: Parameters = serde_json::from_value(json!({ "auth_mode": "none" })).unwrap();
+        assert_eq!(GitAuth::from_request(&bearer, Some(&params), configured(), &[]).unwrap(), GitAuth::None);
+
+        let malformed = Headers::new(vec![(String::from("Authorization"), String::from("Bearer"))]);
+        assert!(matches!(GitAuth::from_request(&malformed, None, None, &[]), Err(GitError::Auth(_))));
+    }
+
+    #[test]
+    fn test_ssh_key_secret_resolves_in_keys_dir() {
+        let keys_dir = std::env::temp_dir().join(format!("git-ssh-keys-test-{}", std::process::id()));
+        let _ = remove_dir_all(&keys_dir);
+        create_dir_all(&keys_dir).unwrap();
+        write(keys_dir.join("deploy_key"), "key").unwrap();
+        write(keys_dir.join("other_key"), "key").unwrap();
+        let configured = [String::from("deploy_key")];
+
+        // Hosts cannot be verified without known hosts unless trusting them on
+        // first use was opted into
+        assert!(matches!(GitAuth::ssh_key_in(&keys_dir, "deploy_key", &configured, false), Err(GitError::Auth(_))));
+        assert_eq!(
+            GitAuth::ssh_key_in(&keys_dir, "deploy_key", &configured, true).unwrap(),
+            GitAuth::SshKey { key_path: keys_dir.join("deploy_key"), known_hosts_path: None }
+        );
+
+        write(keys_dir.join("known_hosts"), "").unwrap();
+        assert_eq!(
+            GitAuth::ssh_key_in(&keys_dir, "deploy_key", &configured, false).unwrap(),
+            GitAuth::SshKey { key_path: keys_dir.join("deploy_key"), known_hosts_path: Some(keys_dir.join("known_hosts")) }
+        );
+
+        // Secrets can only reference keys in the keys directory
+        for secret in ["", "..", "../deploy_key", "/etc/passwd", "known_hosts", "missing_key"] {
+            assert!(matches!(GitAuth::ssh_key_in(&keys_dir, secret, &configured, false), Err(GitError::Auth(_))), "{}", secret);
+        }
+
+        // Keys mounted for other remotes cannot be referenced
+        assert!(matches!(GitAuth::ssh_key_in(&keys_dir, "other_key", &configured, false), Err(GitError::Auth(_))));
+        assert!(matches!(GitAuth::ssh_key_in(&keys_dir, "deploy_key", &[], false), Err(GitError::Auth(_))));
+
+        remove_dir_all(&keys_dir).unwrap();
+    }
+
+    #[test]
+    fn test_sync_local_repo_with_http_auth_modes() {
+        let (root, sha) = local_remote("http");
+        let remote_base_url = format!("file://{}", root.join("remote").display());
+
+        let modes = [
+            GitAuth::None,
+            GitAuth::Bearer(String::from("token")),
+            GitAuth::Basic { username: String::from("user"), token: String::from("token") },
+        ];
+
+        for (index, auth) in modes.into_iter().enumerate() {
+            let target_dir = root.join(format!("clone-{}", index));
+
+            // The first sync clones the repository and the second pulls it
+            assert_eq!(sync(remote_base_url.clone(), &target_dir, auth.clone()).unwrap(), sha);
+            assert_eq!(sync(remote_base_url.clone(), &target_dir, auth).unwrap(), sha);
+        }
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_pull_of_missing_commit_fails_on_fetch() {
+        let (root, sha) = local_remote("missing-commit");
+        let remote_base_url = format!("file://{}", root.join("remote").display());
+        let target_dir = root.join("clone");
+        assert_eq!(sync(remote_base_url.clone(), &target_dir, GitAuth::None).unwrap(), sha);
+
+        let repo = GitRepository::new(remote_base_url, String::from("model"))
+            .prepare(PrepareRepositoryParams { target_dir: target_dir.to_string_lossy().to_string() })
+            .unwrap();
+        let result = repo.clone_or_pull_repo(GitCloneOrPullParams {
+            revision: Some("0".repeat(40)),
+            auth: GitAuth::None,
+        });
+
+        match result {
+            Err(GitError::Pull(msg)) => assert!(msg.contains("Failed to fetch commit"), "{}", msg),
+            _ => panic!("Expected the fetch to fail"),
+        }
+
+        remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_sync_local_repo_with_ssh_deploy_key() {
+        let (root, sha) = local_remote("ssh");
+
+        // A stand-in for ssh that records its arguments and runs the remote
+        // command locally
+        let bin_dir = root.join("bin");
+        create_dir_all(&bin_dir).unwrap();
+        let ssh = bin_dir.join("ssh");
+        write(&ssh, "#!/bin/sh\necho \"$@\" > \"$(dirname \"$0\")/ssh-args\"\nfor last; do :; done\nexec sh -c \"$last\"\n").unwrap();
+        std::fs::set_permissions(&ssh, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let path = std::env::var("PATH").unwrap_or_default();
+        std::env::set_var("PATH", format!("{}:{}", bin_dir.display(), path));
+
+        let keys_dir = root.join("keys");
+        create_dir_all(&keys_dir).unwrap();
+        write(keys_dir.join("deploy_key"), "key").unwrap();
+        // No known hosts file, so the host is trusted on first use
+        let auth = GitAuth::ssh_key_in(&keys_dir, "deploy_key", &[String::from("deploy_key")], true).unwrap();
+
+        let remote_base_url = format!("ssh://localhost{}", root.join("remote").display());
+        assert_eq!(sync(remote_base_url, &root.join("clone"), auth).unwrap(), sha);
+
+        let ssh_args = read_to_string(bin_dir.join("ssh-args")).unwrap();
+        assert!(ssh_args.contains(&format!("-i {}", keys_dir.join("deploy_key").display())));
+        assert!(ssh_args.contains("IdentitiesOnly=yes"));
+        assert!(ssh_args.contains("StrictHostKeyChecking=accept-new"));
+
+        remove_dir_all(&root).unwrap();
+    }
+}
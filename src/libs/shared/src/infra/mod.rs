@@ -5,4 +5,5 @@
 pub mod system;
 pub mod fs;
 pub mod messaging;
-pub mod persistence;
\ No newline at end of file
+pub mod persistence;
+pub mod webhooks;
\ No newline at end of file
@@ -33,4 +33,6 @@ pub async fn get_db(params: ClientParams) -> Result<Database, Error> {
 pub const ARTIFACT_COLLECTION: &str = "ARTIFACTS";
 pub const ARTIFACT_INGESTION_COLLECTION: &str = "ARTIFACT_INGESTIONS";
 pub const MODEL_METADATA_COLLECTION: &str = "MODEL_METADATA";
-pub const ARTIFACT_PUBLICATION_COLLECTION: &str = "ARTIFACT_PUBLICATIONS";
\ No newline at end of file
+pub const DATASET_METADATA_COLLECTION: &str = "DATASET_METADATA";
+pub const ARTIFACT_PUBLICATION_COLLECTION: &str = "ARTIFACT_PUBLICATIONS";
+pub const WEBHOOK_DELIVERY_COLLECTION: &str = "WEBHOOK_DELIVERIES";
\ No newline at end of file
@@ -7,6 +7,14 @@ pub enum ArtifactType {
     Dataset
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ArtifactProvenance {
+    pub platform: String,
+    pub source_id: String,
+    pub revision: Option<String>,
+    pub resolved_revision: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Artifact {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -16,6 +24,7 @@ pub struct Artifact {
     pub artifact_type: ArtifactType,
     pub created_at: DateTime,
     pub last_modified: DateTime,
+    pub provenance: Option<ArtifactProvenance>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -28,4 +37,5 @@ pub struct UpdateArtifactPathRequest {
 pub struct UpdateArtifactRequest {
     pub path: String,
     pub last_modified: DateTime,
+    pub provenance: Option<ArtifactProvenance>,
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use mongodb::bson::{oid::ObjectId, DateTime, Uuid};
+use crate::infra::persistence::mongo::documents::artifact::ArtifactProvenance;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ArtifactIngestion {
@@ -14,6 +15,7 @@ pub struct ArtifactIngestion {
     pub last_modified: DateTime,
     pub artifact_path: Option<String>,
     pub webhook_url: Option<String>,
+    pub provenance: Option<ArtifactProvenance>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -30,6 +32,7 @@ pub struct UpdateArtifactIngestionRequest {
     pub last_modified: DateTime,
     pub artifact_path: Option<String>,
     pub webhook_url: Option<String>,
+    pub provenance: Option<ArtifactProvenance>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -13,7 +13,9 @@ impl From<&documents::ArtifactPublication> for entities::ArtifactPublication {
             target_platform: value.target_platform.clone(),
             created_at: TimeStamp::from(value.created_at.to_chrono()),
             last_modified: TimeStamp::from(value.last_modified.to_chrono()),
-            status: entities::ArtifactPublicationStatus::from(value.status.clone())
+            status: entities::ArtifactPublicationStatus::from(value.status.clone()),
+            webhook_url: value.webhook_url.clone(),
+            published_ids: value.published_ids.clone(),
         }
     }
 }
@@ -13,7 +13,9 @@ impl From<&entities::ArtifactPublication> for documents::ArtifactPublication {
             target_platform: value.target_platform.clone(),
             created_at: DateTime::from_chrono(value.created_at.into_inner()),
             last_modified: DateTime::from_chrono(value.last_modified.into_inner()),
-            status: documents::ArtifactPublicationStatus::from(value.status.clone())
+            status: documents::ArtifactPublicationStatus::from(value.status.clone()),
+            webhook_url: value.webhook_url.clone(),
+            published_ids: value.published_ids.clone(),
         }
     }
 }
@@ -58,4 +60,15 @@ impl From<&entities::ArtifactPublication> for documents::UpdateArtifactPublicati
             status: documents::ArtifactPublicationStatus::from(value.status.clone()),
         }
     }
+}
+
+impl From<&entities::ArtifactPublication> for documents::UpdateArtifactPublicationRequest {
+    fn from(value: &entities::ArtifactPublication) -> Self {
+        Self {
+            last_modified: DateTime::from_chrono(value.last_modified.into_inner()),
+            last_message: value.last_message.clone(),
+            status: documents::ArtifactPublicationStatus::from(value.status.clone()),
+            published_ids: value.published_ids.clone(),
+        }
+    }
 }
\ No newline at end of file
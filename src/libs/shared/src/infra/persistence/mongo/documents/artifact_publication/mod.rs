@@ -1,6 +1,7 @@
 pub mod entity_to_document;
 pub mod document_to_entity;
 
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use mongodb::bson::{DateTime, Uuid, oid::ObjectId};
@@ -52,6 +53,10 @@ pub struct ArtifactPublication  {
     pub attempts: u8,
     pub created_at: DateTime,
     pub last_modified: DateTime,
+    pub webhook_url: Option<String>,
+    /// Publications persisted before ids were recorded have no published ids
+    #[serde(default)]
+    pub published_ids: HashMap<String, String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -59,4 +64,11 @@ pub struct UpdateArtifactPublicationStatusRequest {
     pub status: ArtifactPublicationStatus,
     pub last_message: Option<String>,
     pub last_modified: DateTime,
+}
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UpdateArtifactPublicationRequest {
+    pub status: ArtifactPublicationStatus,
+    pub last_message: Option<String>,
+    pub last_modified: DateTime,
+    pub published_ids: HashMap<String, String>,
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use crate::infra::persistence::mongo::documents::dataset_metadata;
+use crate::domain::entities::dataset_metadata as domain;
+use crate::errors::Error;
+
+impl TryFrom<dataset_metadata::DatasetFeature> for domain::DatasetFeature {
+    type Error = Error;
+
+    fn try_from(value: dataset_metadata::DatasetFeature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            data_type: value.data_type,
+            description: value.description,
+            nullable: value.nullable
+        })
+    }
+}
+
+impl TryFrom<dataset_metadata::DatasetSplit> for domain::DatasetSplit {
+    type Error = Error;
+
+    fn try_from(value: dataset_metadata::DatasetSplit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            num_rows: value.num_rows,
+            num_bytes: value.num_bytes
+        })
+    }
+}
+
+impl TryFrom<dataset_metadata::DatasetProvenance> for domain::DatasetProvenance {
+    type Error = Error;
+
+    fn try_from(value: dataset_metadata::DatasetProvenance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_platform: value.source_platform,
+            source_id: value.source_id,
+            source_url: value.source_url,
+            revision: value.revision,
+            creators: value.creators,
+            citation: value.citation,
+            collection_method: value.collection_method
+        })
+    }
+}
+
+impl TryFrom<dataset_metadata::DatasetMetadata> for domain::DatasetMetadata {
+    type Error = Error;
+
+    fn try_from(value: dataset_metadata::DatasetMetadata) -> Result<Self, Self::Error> {
+        let mut schema = Vec::with_capacity(1);
+        for feature in value.schema.unwrap_or(Vec::with_capacity(0)) {
+            schema.push(domain::DatasetFeature::try_from(feature)?)
+        }
+
+        let mut splits = Vec::with_capacity(1);
+        for split in value.splits.unwrap_or(Vec::with_capacity(0)) {
+            splits.push(domain::DatasetSplit::try_from(split)?)
+        }
+
+        let provenance = value.provenance
+            .map(|p| domain::DatasetProvenance::try_from(p))
+            .transpose()?;
+
+        Ok(Self {
+            // General fields
+            name: value.name,
+            version: value.version,
+            description: value.description,
+            modalities: value.modalities,
+            task_types: value.task_types,
+            format: value.format,
+
+            // Arbitrary labels
+            labels: value.labels,
+
+            // Structure fields
+            schema: Some(schema),
+            splits: Some(splits),
+            num_rows: value.num_rows,
+            size_bytes: value.size_bytes,
+
+            // Provenance fields
+            provenance,
+
+            // Regulatory and Compliance Fields
+            license: value.license,
+            regulatory: value.regulatory
+        })
+    }
+}
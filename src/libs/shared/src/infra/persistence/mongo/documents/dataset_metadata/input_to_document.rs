@@ -0,0 +1,94 @@
+use crate::infra::persistence::mongo::documents::dataset_metadata;
+use crate::application::inputs::dataset_metadata as inputs;
+use crate::errors::Error;
+use mongodb::bson::Uuid;
+
+impl TryFrom<inputs::DatasetFeature> for dataset_metadata::DatasetFeature {
+    type Error = Error;
+
+    fn try_from(value: inputs::DatasetFeature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            data_type: value.data_type,
+            description: value.description,
+            nullable: value.nullable
+        })
+    }
+}
+
+impl TryFrom<inputs::DatasetSplit> for dataset_metadata::DatasetSplit {
+    type Error = Error;
+
+    fn try_from(value: inputs::DatasetSplit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            num_rows: value.num_rows,
+            num_bytes: value.num_bytes
+        })
+    }
+}
+
+impl TryFrom<inputs::DatasetProvenance> for dataset_metadata::DatasetProvenance {
+    type Error = Error;
+
+    fn try_from(value: inputs::DatasetProvenance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_platform: value.source_platform,
+            source_id: value.source_id,
+            source_url: value.source_url,
+            revision: value.revision,
+            creators: value.creators,
+            citation: value.citation,
+            collection_method: value.collection_method
+        })
+    }
+}
+
+impl TryFrom<&inputs::CreateDatasetMetadata> for dataset_metadata::DatasetMetadata {
+    type Error = Error;
+
+    fn try_from(value: &inputs::CreateDatasetMetadata) -> Result<Self, Self::Error> {
+        let mut schema = Vec::with_capacity(1);
+        for feature in value.metadata.schema.clone().unwrap_or(Vec::with_capacity(0)) {
+            schema.push(dataset_metadata::DatasetFeature::try_from(feature)?)
+        }
+
+        let mut splits = Vec::with_capacity(1);
+        for split in value.metadata.splits.clone().unwrap_or(Vec::with_capacity(0)) {
+            splits.push(dataset_metadata::DatasetSplit::try_from(split)?)
+        }
+
+        let provenance = value.metadata.provenance.clone()
+            .map(|p| dataset_metadata::DatasetProvenance::try_from(p))
+            .transpose()?;
+
+        Ok(Self {
+            _id: None,
+            artifact_id: Uuid::from_bytes(value.artifact_id.into_bytes()),
+
+            // General fields
+            name: value.metadata.name.clone(),
+            version: value.metadata.version.clone(),
+            description: value.metadata.description.clone(),
+            modalities: value.metadata.modalities.clone(),
+            task_types: value.metadata.task_types.clone(),
+            format: value.metadata.format.clone(),
+
+            // Arbitrary labels
+            labels: value.metadata.labels.clone(),
+
+            // Structure fields
+            schema: Some(schema),
+            splits: Some(splits),
+            num_rows: value.metadata.num_rows,
+            size_bytes: value.metadata.size_bytes,
+
+            // Provenance fields
+            provenance,
+
+            // Regulatory and Compliance Fields
+            license: value.metadata.license.clone(),
+            regulatory: value.metadata.regulatory.clone()
+        })
+    }
+}
@@ -0,0 +1,64 @@
+mod document_to_domain;
+mod input_to_document;
+
+use serde::{Serialize, Deserialize};
+use mongodb::bson::{Uuid, oid::ObjectId};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DatasetFeature {
+    pub name: String,
+    pub data_type: Option<String>,
+    pub description: Option<String>,
+    pub nullable: Option<bool>
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DatasetSplit {
+    pub name: String,
+    pub num_rows: Option<i64>,
+    pub num_bytes: Option<i64>
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DatasetProvenance {
+    pub source_platform: Option<String>,
+    pub source_id: Option<String>,
+    pub source_url: Option<String>,
+    pub revision: Option<String>,
+    pub creators: Option<Vec<String>>,
+    pub citation: Option<String>,
+    pub collection_method: Option<String>
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DatasetMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _id: Option<ObjectId>,
+
+    // Unique ID of the artifact that this metadata is related to
+    pub artifact_id: Uuid,
+
+    // General fields
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub modalities: Option<Vec<String>>,
+    pub task_types: Option<Vec<String>>,
+    pub format: Option<String>,
+
+    /// Arbitrary labels
+    pub labels: Option<Vec<String>>,
+
+    /// Structure fields
+    pub schema: Option<Vec<DatasetFeature>>,
+    pub splits: Option<Vec<DatasetSplit>>,
+    pub num_rows: Option<i64>,
+    pub size_bytes: Option<i64>,
+
+    /// Provenance fields
+    pub provenance: Option<DatasetProvenance>,
+
+    /// Regulatory and Compliance Fields
+    pub license: Option<String>,
+    pub regulatory: Option<Vec<String>>,
+}
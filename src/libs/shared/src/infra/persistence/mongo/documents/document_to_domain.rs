@@ -13,6 +13,17 @@ impl From<documents::artifact::ArtifactType> for entities::artifact::ArtifactTyp
     }
 }
 
+impl From<documents::artifact::ArtifactProvenance> for entities::artifact::ArtifactProvenance {
+    fn from(value: documents::artifact::ArtifactProvenance) -> Self {
+        Self {
+            platform: value.platform,
+            source_id: value.source_id,
+            revision: value.revision,
+            resolved_revision: value.resolved_revision,
+        }
+    }
+}
+
 impl From<documents::artifact::Artifact> for entities::artifact::Artifact {
     fn from(value: documents::artifact::Artifact) -> Self {
         let path = match value.path {
@@ -25,7 +36,8 @@ impl From<documents::artifact::Artifact> for entities::artifact::Artifact {
             artifact_type: entities::artifact::ArtifactType::from(value.artifact_type),
             last_modified: entities::timestamp::TimeStamp::from(value.last_modified.to_chrono()),
             created_at: entities::timestamp::TimeStamp::from(value.created_at.to_chrono()),
-            path
+            path,
+            provenance: value.provenance.map(entities::artifact::ArtifactProvenance::from),
         }
     }
 }
@@ -46,7 +58,8 @@ impl From<documents::artifact_ingestion::ArtifactIngestion> for entities::artifa
             last_message: value.last_message,
             platform: value.platform,
             status: entities::artifact_ingestion::ArtifactIngestionStatus::from(value.status),
-            webhook_url: value.webhook_url
+            webhook_url: value.webhook_url,
+            provenance: value.provenance.map(entities::artifact::ArtifactProvenance::from),
         }
     }
 }
@@ -12,6 +12,17 @@ impl From<entities::artifact::ArtifactType> for documents::artifact::ArtifactTyp
     }
 }
 
+impl From<entities::artifact::ArtifactProvenance> for documents::artifact::ArtifactProvenance {
+    fn from(value: entities::artifact::ArtifactProvenance) -> Self {
+        Self {
+            platform: value.platform,
+            source_id: value.source_id,
+            revision: value.revision,
+            resolved_revision: value.resolved_revision,
+        }
+    }
+}
+
 impl From<entities::artifact::Artifact> for documents::artifact::Artifact {
     fn from(value: entities::artifact::Artifact) -> Self {
         let path = match value.path {
@@ -25,7 +36,8 @@ impl From<entities::artifact::Artifact> for documents::artifact::Artifact {
             artifact_type: documents::artifact::ArtifactType::from(value.artifact_type),
             last_modified: DateTime::from_chrono(value.last_modified.into_inner()),
             created_at: DateTime::from_chrono(value.created_at.into_inner()),
-            path
+            path,
+            provenance: value.provenance.map(documents::artifact::ArtifactProvenance::from),
         }
     }
 }
@@ -48,7 +60,8 @@ impl From<entities::artifact_ingestion::ArtifactIngestion> for documents::artifa
             last_message: value.last_message,
             platform: value.platform,
             status: documents::artifact_ingestion::ArtifactIngestionStatus::from(value.status),
-            webhook_url: value.webhook_url
+            webhook_url: value.webhook_url,
+            provenance: value.provenance.map(documents::artifact::ArtifactProvenance::from),
         }
     }
 }
@@ -76,6 +89,7 @@ impl From<entities::artifact_ingestion::ArtifactIngestion> for documents::artifa
             status: documents::artifact_ingestion::ArtifactIngestionStatus::from(value.status),
             artifact_path,
             webhook_url: value.webhook_url,
+            provenance: value.provenance.map(documents::artifact::ArtifactProvenance::from),
         }
     }
 }
@@ -107,7 +121,8 @@ impl TryFrom<entities::artifact::Artifact> for documents::artifact::UpdateArtifa
 
         Ok(Self {
             last_modified: DateTime::from_chrono(value.last_modified.into_inner()),
-            path: path.to_string_lossy().into_owned()
+            path: path.to_string_lossy().into_owned(),
+            provenance: value.provenance.map(documents::artifact::ArtifactProvenance::from),
         })
     }
 }
@@ -1,6 +1,8 @@
 pub mod model_metadata;
+pub mod dataset_metadata;
 pub mod artifact;
 pub mod artifact_ingestion;
 pub mod artifact_publication;
+pub mod webhook_delivery;
 pub mod document_to_domain;
 pub mod domain_to_document;
\ No newline at end of file
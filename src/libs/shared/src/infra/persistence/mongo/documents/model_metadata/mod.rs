@@ -5,6 +5,73 @@ mod input_to_document;
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
 use mongodb::bson::{Uuid, oid::ObjectId};
+use crate::infra::persistence::mongo::filtering::FieldType;
+
+/// Fields of a ModelMetadata document that can be filtered, sorted and projected
+pub const MODEL_METADATA_FIELDS: [(&str, FieldType); 37] = [
+    ("artifact_id", FieldType::Uuid),
+    ("name", FieldType::String),
+    ("model_type", FieldType::String),
+    ("version", FieldType::String),
+    ("framework", FieldType::String),
+    ("image", FieldType::String),
+    ("labels", FieldType::String),
+    ("label_map", FieldType::Any),
+    ("multi_modal", FieldType::Bool),
+    ("model_inputs", FieldType::Document(&MODEL_IO_FIELDS)),
+    ("model_outputs", FieldType::Document(&MODEL_IO_FIELDS)),
+    ("task_types", FieldType::String),
+    ("inference_precision", FieldType::String),
+    ("inference_hardware", FieldType::Document(&HARDWARE_REQUIREMENTS_FIELDS)),
+    ("inference_software_dependencies", FieldType::String),
+    ("inference_max_energy_consumption_watts", FieldType::Int),
+    ("inference_max_latency_ms", FieldType::Int),
+    ("inference_min_throughput", FieldType::Int),
+    ("inference_max_compute_utilization_percentage", FieldType::Int),
+    ("inference_max_memory_usage_mb", FieldType::Int),
+    ("inference_distributed", FieldType::Bool),
+    ("training_time", FieldType::Int),
+    ("training_precision", FieldType::String),
+    ("training_hardware", FieldType::Document(&HARDWARE_REQUIREMENTS_FIELDS)),
+    ("pretraining_datasets", FieldType::String),
+    ("finetuning_datasets", FieldType::String),
+    ("edge_optimized", FieldType::Bool),
+    ("quantization_aware", FieldType::Bool),
+    ("supports_quantization", FieldType::Bool),
+    ("pretrained", FieldType::Bool),
+    ("pruned", FieldType::Bool),
+    ("slimmed", FieldType::Bool),
+    ("training_distributed", FieldType::Bool),
+    ("training_max_energy_consumption_watts", FieldType::Int),
+    ("regulatory", FieldType::String),
+    ("license", FieldType::String),
+    ("bias_evaluation_score", FieldType::Int),
+];
+
+const MODEL_IO_FIELDS: [(&str, FieldType); 2] = [
+    ("data_type", FieldType::String),
+    ("shape", FieldType::Int),
+];
+
+const HARDWARE_REQUIREMENTS_FIELDS: [(&str, FieldType); 5] = [
+    ("cpus", FieldType::Int),
+    ("memory_gb", FieldType::Int),
+    ("disk_gb", FieldType::Int),
+    ("accelerators", FieldType::Document(&ACCELERATOR_FIELDS)),
+    ("architectures", FieldType::String),
+];
+
+const ACCELERATOR_FIELDS: [(&str, FieldType); 4] = [
+    ("accelerator_type", FieldType::String),
+    ("memory_gb", FieldType::Int),
+    ("cores", FieldType::Int),
+    ("system_requirements", FieldType::Document(&SYSTEM_REQUIREMENT_FIELDS)),
+];
+
+const SYSTEM_REQUIREMENT_FIELDS: [(&str, FieldType); 2] = [
+    ("name", FieldType::String),
+    ("version", FieldType::String),
+];
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SystemRequirement {
@@ -96,4 +163,9 @@ pub struct ModelMetadata {
     pub regulatory: Option<Vec<String>>,
     pub license: Option<String>,
     pub bias_evaluation_score: Option<i8>,
-}
\ No newline at end of file
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "model_metadata.test.rs"]
+mod model_metadata_test;
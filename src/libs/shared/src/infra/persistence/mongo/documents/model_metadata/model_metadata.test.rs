@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod model_metadata_test {
+    use mongodb::bson::{to_document, Uuid};
+    use serde::Serialize;
+    use crate::infra::persistence::mongo::documents::model_metadata::{
+        Accelerator, HardwareRequirements, ModelIO, ModelMetadata, SystemRequirement, MODEL_METADATA_FIELDS,
+    };
+    use crate::infra::persistence::mongo::filtering::FieldType;
+
+    /// Names of the fields of a document, in the order they are serialized
+    fn document_fields<T: Serialize>(document: &T) -> Vec<String> {
+        to_document(document).unwrap()
+            .keys()
+            .filter(|key| *key != "_id")
+            .cloned()
+            .collect()
+    }
+
+    fn allowed_fields(fields: &[(&str, FieldType)]) -> Vec<String> {
+        fields.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    /// The fields of a subdocument that can be filtered on
+    fn nested_fields(fields: &[(&'static str, FieldType)], name: &str) -> &'static [(&'static str, FieldType)] {
+        match fields.iter().find(|(field, _)| *field == name) {
+            Some((_, FieldType::Document(nested))) => nested,
+            _ => panic!("'{}' should be a subdocument", name),
+        }
+    }
+
+    fn hardware_requirements() -> HardwareRequirements {
+        HardwareRequirements { cpus: None, memory_gb: None, disk_gb: None, accelerators: None, architectures: None }
+    }
+
+    fn model_metadata() -> ModelMetadata {
+        ModelMetadata {
+            _id: None,
+            artifact_id: Uuid::new(),
+            name: None,
+            model_type: None,
+            version: None,
+            framework: None,
+            image: None,
+            labels: None,
+            label_map: None,
+            multi_modal: None,
+            model_inputs: None,
+            model_outputs: None,
+            task_types: None,
+            inference_precision: None,
+            inference_hardware: None,
+            inference_software_dependencies: None,
+            inference_max_energy_consumption_watts: None,
+            inference_max_latency_ms: None,
+            inference_min_throughput: None,
+            inference_max_compute_utilization_percentage: None,
+            inference_max_memory_usage_mb: None,
+            inference_distributed: None,
+            training_time: None,
+            training_precision: None,
+            training_hardware: None,
+            pretraining_datasets: None,
+            finetuning_datasets: None,
+            edge_optimized: None,
+            quantization_aware: None,
+            supports_quantization: None,
+            pretrained: None,
+            pruned: None,
+            slimmed: None,
+            training_distributed: None,
+            training_max_energy_consumption_watts: None,
+            regulatory: None,
+            license: None,
+            bias_evaluation_score: None,
+        }
+    }
+
+    #[test]
+    fn test_allowed_fields_match_document() {
+        // Every field of the document, and only those, can be filtered on
+        assert_eq!(allowed_fields(&MODEL_METADATA_FIELDS), document_fields(&model_metadata()));
+
+        let hardware_fields = nested_fields(&MODEL_METADATA_FIELDS, "inference_hardware");
+        assert_eq!(hardware_fields, nested_fields(&MODEL_METADATA_FIELDS, "training_hardware"));
+        assert_eq!(allowed_fields(hardware_fields), document_fields(&hardware_requirements()));
+
+        let model_io_fields = nested_fields(&MODEL_METADATA_FIELDS, "model_inputs");
+        assert_eq!(model_io_fields, nested_fields(&MODEL_METADATA_FIELDS, "model_outputs"));
+        assert_eq!(allowed_fields(model_io_fields), document_fields(&ModelIO { data_type: None, shape: None }));
+
+        let accelerator_fields = nested_fields(hardware_fields, "accelerators");
+        assert_eq!(
+            allowed_fields(accelerator_fields),
+            document_fields(&Accelerator { accelerator_type: String::new(), memory_gb: None, cores: None, system_requirements: Vec::new() })
+        );
+
+        assert_eq!(
+            allowed_fields(nested_fields(accelerator_fields, "system_requirements")),
+            document_fields(&SystemRequirement { name: String::new(), version: String::new() })
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::domain::entities::webhook_delivery as entities;
+use crate::domain::entities::timestamp::TimeStamp;
+use crate::infra::persistence::mongo::documents::webhook_delivery as documents;
+use uuid::Uuid;
+
+impl From<&documents::WebhookDelivery> for entities::WebhookDelivery {
+    fn from(value: &documents::WebhookDelivery) -> Self {
+        Self {
+            id: Uuid::from_bytes(value.id.bytes()),
+            event_id: Uuid::from_bytes(value.event_id.bytes()),
+            event_type: entities::WebhookEventType::from(value.event_type.clone()),
+            resource_id: Uuid::from_bytes(value.resource_id.bytes()),
+            url: value.url.clone(),
+            attempt: value.attempt,
+            status_code: value.status_code,
+            succeeded: value.succeeded,
+            error: value.error.clone(),
+            created_at: TimeStamp::from(value.created_at.to_chrono()),
+        }
+    }
+}
+
+impl From<documents::WebhookEventType> for entities::WebhookEventType {
+    fn from(value: documents::WebhookEventType) -> Self {
+        match value {
+            documents::WebhookEventType::ArtifactIngestionStatusChanged => entities::WebhookEventType::ArtifactIngestionStatusChanged,
+            documents::WebhookEventType::ArtifactPublicationStatusChanged => entities::WebhookEventType::ArtifactPublicationStatusChanged,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::domain::entities::webhook_delivery as entities;
+use crate::infra::persistence::mongo::documents::webhook_delivery as documents;
+use mongodb::bson::{Uuid, DateTime};
+
+impl From<&entities::WebhookDelivery> for documents::WebhookDelivery {
+    fn from(value: &entities::WebhookDelivery) -> Self {
+        Self {
+            _id: None,
+            id: Uuid::from_bytes(value.id.into_bytes()),
+            event_id: Uuid::from_bytes(value.event_id.into_bytes()),
+            event_type: documents::WebhookEventType::from(value.event_type.clone()),
+            resource_id: Uuid::from_bytes(value.resource_id.into_bytes()),
+            url: value.url.clone(),
+            attempt: value.attempt,
+            status_code: value.status_code,
+            succeeded: value.succeeded,
+            error: value.error.clone(),
+            created_at: DateTime::from_chrono(value.created_at.into_inner()),
+        }
+    }
+}
+
+impl From<entities::WebhookEventType> for documents::WebhookEventType {
+    fn from(value: entities::WebhookEventType) -> Self {
+        match value {
+            entities::WebhookEventType::ArtifactIngestionStatusChanged => documents::WebhookEventType::ArtifactIngestionStatusChanged,
+            entities::WebhookEventType::ArtifactPublicationStatusChanged => documents::WebhookEventType::ArtifactPublicationStatusChanged,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+pub mod entity_to_document;
+pub mod document_to_entity;
+
+use serde::{Deserialize, Serialize};
+use mongodb::bson::{DateTime, Uuid, oid::ObjectId};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum WebhookEventType {
+    ArtifactIngestionStatusChanged,
+    ArtifactPublicationStatusChanged,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebhookDelivery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _id: Option<ObjectId>,
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    pub resource_id: Uuid,
+    pub url: String,
+    pub attempt: u16,
+    pub status_code: Option<u16>,
+    pub succeeded: bool,
+    pub error: Option<String>,
+    pub created_at: DateTime,
+}
@@ -0,0 +1,222 @@
+//! Translates application-level list inputs into mongo queries
+use crate::application::inputs::filtering::{Filter, FilterOperation, ListAll, Order};
+use crate::errors::Error;
+use mongodb::bson::{doc, Bson, Document, Regex, Uuid};
+use mongodb::options::FindOptions;
+
+/// Maximum length of the value of a pattern filter
+const MAX_PATTERN_LENGTH: usize = 256;
+
+/// The type of the values stored in a field of a document. Lists have the type
+/// of their elements since a list matches when any of its elements match
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldType {
+    String,
+    Int,
+    Double,
+    Bool,
+    Uuid,
+    /// A subdocument, or a list of subdocuments, with its own fields
+    Document(&'static [(&'static str, FieldType)]),
+    /// Arbitrary values. The type of a filter value is inferred from the value
+    Any,
+}
+
+/// Converts the raw string value of a filter into the type of the field it is
+/// compared against. "null" matches a missing value in any field
+fn parse_value(field: &str, field_type: FieldType, value: &str) -> Result<Bson, Error> {
+    let trimmed = value.trim();
+    let invalid = |expected: &str| Error::new(format!("Invalid value '{}' for field '{}'. Expected {}", value, field, expected));
+
+    if trimmed == "null" {
+        return Ok(Bson::Null);
+    }
+
+    match field_type {
+        FieldType::String => Ok(Bson::String(value.to_string())),
+        FieldType::Int => trimmed.parse::<i64>().map(Bson::Int64).map_err(|_| invalid("an integer")),
+        FieldType::Double => trimmed.parse::<f64>().map(Bson::Double).map_err(|_| invalid("a number")),
+        FieldType::Bool => match trimmed {
+            "true" => Ok(Bson::Boolean(true)),
+            "false" => Ok(Bson::Boolean(false)),
+            _ => Err(invalid("true or false"))
+        },
+        FieldType::Uuid => Uuid::parse_str(trimmed).map(Bson::from).map_err(|_| invalid("a uuid")),
+        FieldType::Document(_) => Err(invalid("one of its nested fields to be filtered on")),
+        FieldType::Any => Ok(infer_value(value)),
+    }
+}
+
+/// Converts the raw string value of a filter into the most specific bson type
+/// it can be parsed as
+fn infer_value(value: &str) -> Bson {
+    let trimmed = value.trim();
+    if let Ok(v) = trimmed.parse::<i64>() {
+        return Bson::Int64(v);
+    }
+
+    if let Ok(v) = trimmed.parse::<f64>() {
+        return Bson::Double(v);
+    }
+
+    match trimmed {
+        "true" => Bson::Boolean(true),
+        "false" => Bson::Boolean(false),
+        _ => Bson::String(value.to_string())
+    }
+}
+
+/// Ensures a field is one of the allowed fields and returns its type. Nested
+/// fields are allowed using dot notation (ex. inference_hardware.memory_gb)
+/// as long as each part is a field of the subdocument before it. Any field is
+/// allowed below a field of arbitrary values
+fn resolve_field(field: &str, allowed_fields: &[(&str, FieldType)]) -> Result<FieldType, Error> {
+    let invalid = || Error::new(format!("Invalid field '{}'", field));
+    if field.contains('$') || field.split('.').any(|part| part.is_empty()) {
+        return Err(invalid());
+    }
+
+    let mut fields = allowed_fields;
+    let mut field_type = FieldType::Any;
+    for (depth, part) in field.split('.').enumerate() {
+        if depth > 0 {
+            match field_type {
+                FieldType::Document(nested) => fields = nested,
+                FieldType::Any => return Ok(FieldType::Any),
+                _ => return Err(invalid())
+            }
+        }
+
+        field_type = fields.iter()
+            .find(|(name, _)| *name == part)
+            .map(|(_, field_type)| *field_type)
+            .ok_or_else(invalid)?;
+    }
+
+    Ok(field_type)
+}
+
+/// Escapes the characters that have a special meaning in a regular expression
+/// so that a pattern only matches values containing it as is
+fn escape_pattern(pattern: &str) -> String {
+    let mut escaped = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+fn build_condition(filter: &Filter, field_type: FieldType) -> Result<Bson, Error> {
+    let parse = |value: &str| parse_value(&filter.field, field_type, value);
+
+    let condition = match filter.operation {
+        FilterOperation::Eq => parse(&filter.value)?,
+        FilterOperation::Ne => Bson::Document(doc! { "$ne": parse(&filter.value)? }),
+        FilterOperation::Lt => Bson::Document(doc! { "$lt": parse(&filter.value)? }),
+        FilterOperation::Lte => Bson::Document(doc! { "$lte": parse(&filter.value)? }),
+        FilterOperation::Gt => Bson::Document(doc! { "$gt": parse(&filter.value)? }),
+        FilterOperation::Gte => Bson::Document(doc! { "$gte": parse(&filter.value)? }),
+        FilterOperation::In | FilterOperation::Nin => {
+            // Values for In and Nin are provided as a comma separated list
+            let values: Vec<Bson> = filter.value.split(',')
+                .map(|v| parse(v.trim()))
+                .collect::<Result<Vec<Bson>, Error>>()?;
+
+            let operator = if filter.operation == FilterOperation::In { "$in" } else { "$nin" };
+            Bson::Document(doc! { operator: values })
+        },
+        FilterOperation::Pattern => {
+            if filter.value.chars().count() > MAX_PATTERN_LENGTH {
+                return Err(Error::new(format!("Pattern for field '{}' must be at most {} characters", filter.field, MAX_PATTERN_LENGTH)))
+            }
+
+            Bson::Document(doc! {
+                "$regex": Regex { pattern: escape_pattern(&filter.value), options: String::from("i") }
+            })
+        },
+    };
+
+    Ok(condition)
+}
+
+/// Builds a filter document where every filter must match. Multiple filters
+/// on the same field are combined with $and
+pub fn build_filter(filters: &[Filter], allowed_fields: &[(&str, FieldType)]) -> Result<Document, Error> {
+    let mut conditions: Vec<Document> = Vec::with_capacity(filters.len());
+    for filter in filters {
+        let field_type = resolve_field(&filter.field, allowed_fields)?;
+
+        let mut condition = Document::new();
+        condition.insert(filter.field.clone(), build_condition(filter, field_type)?);
+        conditions.push(condition);
+    }
+
+    match conditions.len() {
+        0 => Ok(Document::new()),
+        1 => Ok(conditions.remove(0)),
+        _ => Ok(doc! { "$and": conditions })
+    }
+}
+
+/// Builds a filter document where at least one of the filters must match
+pub fn build_any_filter(filters: &[Filter], allowed_fields: &[(&str, FieldType)]) -> Result<Document, Error> {
+    let mut conditions: Vec<Document> = Vec::with_capacity(filters.len());
+    for filter in filters {
+        conditions.push(build_filter(std::slice::from_ref(filter), allowed_fields)?);
+    }
+
+    match conditions.len() {
+        0 => Ok(Document::new()),
+        1 => Ok(conditions.remove(0)),
+        _ => Ok(doc! { "$or": conditions })
+    }
+}
+
+/// Builds the pagination, sorting and projection options for a find. The
+/// fields in `always_include` are part of every projection
+pub fn build_find_options(input: &ListAll, allowed_fields: &[(&str, FieldType)], always_include: &[&str]) -> Result<FindOptions, Error> {
+    let mut options = FindOptions::builder()
+        .skip(Some(input.offset))
+        .limit(Some(input.limit as i64))
+        .build();
+
+    if let Some(sort_by) = &input.sort_by {
+        resolve_field(sort_by, allowed_fields)?;
+
+        let direction = match input.order_by {
+            Some(Order::Desc) => -1,
+            _ => 1
+        };
+
+        let mut sort = Document::new();
+        sort.insert(sort_by.clone(), direction);
+        options.sort = Some(sort);
+    }
+
+    if !input.fields.is_empty() {
+        let mut projection = Document::new();
+        // Nested fields project their whole top level field. A subdocument
+        // with only some of its fields would not deserialize
+        for field in &input.fields {
+            resolve_field(field, allowed_fields)?;
+            projection.insert(field.split('.').next().unwrap_or_default(), 1);
+        }
+
+        for field in always_include {
+            projection.insert(field.to_string(), 1);
+        }
+
+        options.projection = Some(projection);
+    }
+
+    Ok(options)
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "filtering.test.rs"]
+mod filtering_test;
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod filtering_test {
+    use mongodb::bson::{doc, Bson, Regex, Uuid};
+    use crate::application::inputs::filtering::{Filter, FilterOperation, ListAll, Order};
+    use crate::infra::persistence::mongo::filtering::{build_any_filter, build_filter, build_find_options, FieldType};
+
+    const HARDWARE_FIELDS: [(&str, FieldType); 2] = [
+        ("memory_gb", FieldType::Int),
+        ("accelerators", FieldType::Document(&[("accelerator_type", FieldType::String)])),
+    ];
+
+    const FIELDS: [(&str, FieldType); 8] = [
+        ("artifact_id", FieldType::Uuid),
+        ("framework", FieldType::String),
+        ("version", FieldType::String),
+        ("task_types", FieldType::String),
+        ("license", FieldType::String),
+        ("pretrained", FieldType::Bool),
+        ("label_map", FieldType::Any),
+        ("inference_hardware", FieldType::Document(&HARDWARE_FIELDS)),
+    ];
+
+    fn filter(field: &str, operation: FilterOperation, value: &str) -> Filter {
+        Filter { field: field.into(), operation, value: value.into() }
+    }
+
+    fn list_all() -> ListAll {
+        ListAll {
+            limit: 10,
+            offset: 20,
+            fields: Vec::new(),
+            filters: None,
+            sort_by: None,
+            order_by: None,
+        }
+    }
+
+    #[test]
+    fn test_build_filter_single() {
+        let filters = vec![filter("framework", FilterOperation::Eq, "pytorch")];
+
+        assert_eq!(build_filter(&filters, &FIELDS).unwrap(), doc! { "framework": "pytorch" });
+    }
+
+    #[test]
+    fn test_build_filter_parses_values() {
+        let filters = vec![
+            filter("inference_hardware.memory_gb", FilterOperation::Lte, "16"),
+            filter("license", FilterOperation::In, "mit, apache-2.0"),
+            filter("task_types", FilterOperation::Pattern, "^text"),
+        ];
+
+        assert_eq!(
+            build_filter(&filters, &FIELDS).unwrap(),
+            doc! { "$and": [
+                { "inference_hardware.memory_gb": { "$lte": Bson::Int64(16) } },
+                { "license": { "$in": ["mit", "apache-2.0"] } },
+                { "task_types": { "$regex": Regex { pattern: "\\^text".into(), options: "i".into() } } },
+            ]}
+        );
+    }
+
+    #[test]
+    fn test_build_filter_escapes_patterns() {
+        let filters = vec![filter("framework", FilterOperation::Pattern, "torch (2.*)|.+")];
+
+        assert_eq!(
+            build_filter(&filters, &FIELDS).unwrap(),
+            doc! { "framework": { "$regex": Regex { pattern: "torch \\(2\\.\\*\\)\\|\\.\\+".into(), options: "i".into() } } }
+        );
+
+        // Patterns are limited in length
+        let filters = vec![filter("framework", FilterOperation::Pattern, &"a".repeat(257))];
+        assert!(build_filter(&filters, &FIELDS).is_err());
+        let filters = vec![filter("framework", FilterOperation::Pattern, &"a".repeat(256))];
+        assert!(build_filter(&filters, &FIELDS).is_ok());
+    }
+
+    #[test]
+    fn test_build_filter_converts_values_to_field_type() {
+        let artifact_id = "0196b5a9-0e6f-7d43-a3d2-4c1a2b3c4d5e";
+        let filters = vec![
+            filter("version", FilterOperation::Eq, "1.0"),
+            filter("pretrained", FilterOperation::Eq, "true"),
+            filter("artifact_id", FilterOperation::Eq, artifact_id),
+            filter("label_map.0", FilterOperation::Eq, "1.5"),
+            filter("license", FilterOperation::Eq, "null"),
+        ];
+
+        assert_eq!(
+            build_filter(&filters, &FIELDS).unwrap(),
+            doc! { "$and": [
+                { "version": "1.0" },
+                { "pretrained": true },
+                { "artifact_id": Uuid::parse_str(artifact_id).unwrap() },
+                { "label_map.0": Bson::Double(1.5) },
+                { "license": Bson::Null },
+            ]}
+        );
+
+        for (field, value) in [("inference_hardware.memory_gb", "lots"), ("pretrained", "yes"), ("artifact_id", "1"), ("inference_hardware", "x")] {
+            let filters = vec![filter(field, FilterOperation::Eq, value)];
+            assert!(build_filter(&filters, &FIELDS).is_err(), "'{}' should not be accepted for '{}'", value, field);
+        }
+    }
+
+    #[test]
+    fn test_build_filter_rejects_unknown_fields() {
+        for field in ["_id", "$where", "framework.$gt", "inference_hardware.", "inference_hardware.gpus", "framework.name"] {
+            let filters = vec![filter(field, FilterOperation::Eq, "x")];
+            assert!(build_filter(&filters, &FIELDS).is_err(), "'{}' should be rejected", field);
+        }
+    }
+
+    #[test]
+    fn test_build_any_filter() {
+        let filters = vec![
+            filter("framework", FilterOperation::Eq, "pytorch"),
+            filter("license", FilterOperation::In, "mit"),
+        ];
+
+        assert_eq!(
+            build_any_filter(&filters, &FIELDS).unwrap(),
+            doc! { "$or": [
+                { "framework": "pytorch" },
+                { "license": { "$in": ["mit"] } },
+            ]}
+        );
+        assert!(build_any_filter(&[filter("_id", FilterOperation::Eq, "x")], &FIELDS).is_err());
+    }
+
+    #[test]
+    fn test_build_find_options() {
+        let mut input = list_all();
+        input.sort_by = Some("framework".into());
+        input.order_by = Some(Order::Desc);
+        input.fields = vec!["license".into()];
+
+        let options = build_find_options(&input, &FIELDS, &["artifact_id"]).unwrap();
+
+        assert_eq!(options.skip, Some(20));
+        assert_eq!(options.limit, Some(10));
+        assert_eq!(options.sort, Some(doc! { "framework": -1 }));
+        assert_eq!(options.projection, Some(doc! { "license": 1, "artifact_id": 1 }));
+
+        // Nested fields project the whole subdocument so it still deserializes
+        input.fields = vec!["inference_hardware.accelerators.accelerator_type".into()];
+        let options = build_find_options(&input, &FIELDS, &["artifact_id"]).unwrap();
+        assert_eq!(options.projection, Some(doc! { "inference_hardware": 1, "artifact_id": 1 }));
+    }
+
+    #[test]
+    fn test_build_find_options_rejects_unknown_sort_field() {
+        let mut input = list_all();
+        input.sort_by = Some("_id".into());
+
+        assert!(build_find_options(&input, &FIELDS, &[]).is_err());
+    }
+}
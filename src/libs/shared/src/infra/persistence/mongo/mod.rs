@@ -1,3 +1,4 @@
 pub mod database;
 pub mod documents;
-pub mod repositories;
\ No newline at end of file
+pub mod repositories;
+pub mod filtering;
\ No newline at end of file
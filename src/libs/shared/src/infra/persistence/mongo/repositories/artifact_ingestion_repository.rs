@@ -6,6 +6,7 @@ use crate::domain::entities;
 use mongodb::{
     bson::{
         doc,
+        to_bson,
         Uuid
     },
     Database,
@@ -49,6 +50,9 @@ impl application::ports::repositories::ArtifactIngestionRepository for ArtifactI
             "id": Uuid::from_bytes(*ingestion.id.as_bytes())
         };
         
+        let provenance = to_bson(&update.provenance)
+            .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?;
+
         let document = doc! {
             "$set": {
                 "status": String::from(update.status),
@@ -56,6 +60,7 @@ impl application::ports::repositories::ArtifactIngestionRepository for ArtifactI
                 "last_message": update.last_message,
                 "webhook_url": update.webhook_url,
                 "artifact_path": update.artifact_path,
+                "provenance": provenance,
             }
         };
 
@@ -1,11 +1,16 @@
 use crate::application::errors::ApplicationError;
-use crate::infra::persistence::mongo::database::ARTIFACT_COLLECTION;
+use crate::infra::persistence::mongo::database::{ARTIFACT_COLLECTION, MODEL_METADATA_COLLECTION};
 use crate::infra::persistence::mongo::documents::artifact::{Artifact, UpdateArtifactRequest, UpdateArtifactPathRequest};
+use crate::infra::persistence::mongo::documents::model_metadata::ModelMetadata;
 use crate::application;
 use crate::domain::entities;
 use mongodb::{
     bson::{
         doc,
+        from_bson,
+        from_document,
+        to_bson,
+        Bson,
         Uuid
     },
     Database,
@@ -58,6 +63,52 @@ impl application::ports::repositories::ArtifactRepository for ArtifactRepository
         Ok(artifacts)
     }
 
+    async fn list_ingested_models(&self, limit: u64, offset: u64) -> Result<Vec<(entities::artifact::Artifact, Option<entities::model_metadata::ModelMetadata>)>, ApplicationError> {
+        // Artifacts and their metadata are joined in a single query. Only a
+        // page of artifacts is joined
+        let pipeline = vec![
+            doc! { "$match": { "artifact_type": "Model", "path": { "$ne": null } } },
+            doc! { "$sort": { "created_at": -1, "_id": -1 } },
+            doc! { "$skip": offset as i64 },
+            doc! { "$limit": limit as i64 },
+            doc! { "$lookup": {
+                "from": MODEL_METADATA_COLLECTION,
+                "localField": "id",
+                "foreignField": "artifact_id",
+                "as": "metadata",
+            } },
+        ];
+
+        let mut cursor = self.read_collection.aggregate(pipeline, None)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        let mut models = Vec::new();
+        while let Some(mut model_doc) = cursor.try_next().await.map_err(|err| ApplicationError::RepoError(err.to_string()))? {
+            let metadata = match model_doc.remove("metadata") {
+                Some(Bson::Array(mut metadata)) if !metadata.is_empty() => {
+                    let metadata: ModelMetadata = from_bson(metadata.remove(0))
+                        .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?;
+
+                    Some(entities::model_metadata::ModelMetadata::try_from(metadata)
+                        .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?)
+                },
+                _ => None,
+            };
+
+            let artifact: Artifact = from_document(model_doc)
+                .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?;
+
+            models.push((
+                entities::artifact::Artifact::try_from(artifact)
+                    .map_err(|err| ApplicationError::RepoError(err.to_string()))?,
+                metadata,
+            ));
+        }
+
+        Ok(models)
+    }
+
     async fn find_by_id(&self, id: &uuid::Uuid) -> Result<Option<entities::artifact::Artifact>, ApplicationError> {
         let filter = doc! {
             "id": Uuid::from_bytes(*id.as_bytes()),
@@ -84,10 +135,14 @@ impl application::ports::repositories::ArtifactRepository for ArtifactRepository
             "id": Uuid::from_bytes(*artifact.id.as_bytes())
         };
         
+        let provenance = to_bson(&update.provenance)
+            .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?;
+
         let document = doc! {
             "$set": {
                 "last_modified": update.last_modified,
                 "path": update.path,
+                "provenance": provenance,
             }
         };
 
@@ -1,12 +1,16 @@
 mod model_metadata_repository;
+mod dataset_metadata_repository;
 mod artifact_ingestion_repository;
 mod artifact_repository;
 mod artifact_publication_repository;
+mod webhook_delivery_repository;
 
 pub use model_metadata_repository::ModelMetadataRepository;
+pub use dataset_metadata_repository::DatasetMetadataRepository;
 pub use artifact_ingestion_repository::ArtifactIngestionRepository;
 pub use artifact_repository::ArtifactRepository;
 pub use artifact_publication_repository::ArtifactPublicationRepository;
+pub use webhook_delivery_repository::WebhookDeliveryRepository;
 
 
 
@@ -4,6 +4,7 @@ use crate::domain::entities;
 use mongodb::{
     bson::{
         doc,
+        to_document,
         Uuid
     },
     Database,
@@ -11,9 +12,11 @@ use mongodb::{
 };
 use async_trait::async_trait;
 use futures::stream::TryStreamExt;
+use mongodb::options::FindOptions;
 
 use super::super::database::MODEL_METADATA_COLLECTION;
-use super::super::documents::model_metadata::ModelMetadata;
+use super::super::documents::model_metadata::{ModelMetadata, MODEL_METADATA_FIELDS};
+use super::super::filtering::{build_any_filter, build_filter, build_find_options};
 
 pub struct ModelMetadataRepository {
     read_collection: Collection<ModelMetadata>,
@@ -65,4 +68,115 @@ impl application::ports::repositories::ModelMetadataRepository for ModelMetadata
 
         Ok(maybe_metadata)
     }
-}
\ No newline at end of file
+
+    async fn update(&self, input: &application::inputs::model_metadata::CreateModelMetadata) -> Result<Option<entities::model_metadata::ModelMetadata>, ApplicationError> {
+        let document = ModelMetadata::try_from(input)
+            .map_err(|err| ApplicationError::ConvesionError(format!("Failed to convert from CreateModelInput to document::ModelMetadata: {}", err.to_string())))?;
+
+        let filter = doc! {
+            "artifact_id": Uuid::from_bytes(*input.artifact_id.as_bytes()),
+        };
+
+        // The document's _id is not serialized and every other field is, so
+        // setting the whole document replaces it while keeping the existing _id
+        let update = doc! {
+            "$set": to_document(&document)
+                .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?
+        };
+
+        let replaced = self.write_collection.find_one_and_update(filter, update, None)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        replaced
+            .map(|metadata_doc| domain::entities::model_metadata::ModelMetadata::try_from(metadata_doc)
+                .map_err(|err| ApplicationError::ConvesionError(err.to_string())))
+            .transpose()
+    }
+
+    async fn list(&self) -> Result<Vec<entities::model_metadata::ModelMetadata>, ApplicationError> {
+        let mut cursor = self.read_collection.find(None, None)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        let mut metadata: Vec<entities::model_metadata::ModelMetadata> = Vec::new();
+        while let Some(metadata_doc) = cursor.try_next()
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?
+        {
+            metadata.push(domain::entities::model_metadata::ModelMetadata::try_from(metadata_doc)
+                .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?);
+        }
+
+        Ok(metadata)
+    }
+
+    async fn find(&self, input: &application::inputs::filtering::ListAll) -> Result<Vec<(uuid::Uuid, entities::model_metadata::ModelMetadata)>, ApplicationError> {
+        let filter = build_filter(input.filters.as_deref().unwrap_or_default(), &MODEL_METADATA_FIELDS)
+            .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?;
+
+        // The artifact id is always projected so each result can be tied
+        // back to its artifact
+        let options = build_find_options(input, &MODEL_METADATA_FIELDS, &["artifact_id"])
+            .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?;
+
+        let mut cursor = self.read_collection.find(filter, options)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        let mut metadata: Vec<(uuid::Uuid, entities::model_metadata::ModelMetadata)> = Vec::new();
+        while let Some(metadata_doc) = cursor.try_next()
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?
+        {
+            let artifact_id = uuid::Uuid::from_bytes(metadata_doc.artifact_id.bytes());
+            metadata.push((
+                artifact_id,
+                domain::entities::model_metadata::ModelMetadata::try_from(metadata_doc)
+                    .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?
+            ));
+        }
+
+        Ok(metadata)
+    }
+
+    async fn find_any(&self, filters: &[application::inputs::filtering::Filter], limit: u64) -> Result<Vec<(uuid::Uuid, entities::model_metadata::ModelMetadata)>, ApplicationError> {
+        let filter = build_any_filter(filters, &MODEL_METADATA_FIELDS)
+            .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?;
+
+        let options = FindOptions::builder()
+            .limit(Some(limit as i64))
+            .build();
+
+        let mut cursor = self.read_collection.find(filter, options)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        let mut metadata: Vec<(uuid::Uuid, entities::model_metadata::ModelMetadata)> = Vec::new();
+        while let Some(metadata_doc) = cursor.try_next()
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?
+        {
+            let artifact_id = uuid::Uuid::from_bytes(metadata_doc.artifact_id.bytes());
+            metadata.push((
+                artifact_id,
+                domain::entities::model_metadata::ModelMetadata::try_from(metadata_doc)
+                    .map_err(|err| ApplicationError::ConvesionError(err.to_string()))?
+            ));
+        }
+
+        Ok(metadata)
+    }
+
+    async fn delete_by_artifact_id(&self, artifact_id: &uuid::Uuid) -> Result<(), ApplicationError> {
+        let filter = doc! {
+            "artifact_id": Uuid::from_bytes(*artifact_id.as_bytes()),
+        };
+
+        self.write_collection.delete_one(filter, None)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::application::errors::ApplicationError;
+use crate::infra::persistence::mongo::database::WEBHOOK_DELIVERY_COLLECTION;
+use crate::infra::persistence::mongo::documents::webhook_delivery::WebhookDelivery;
+use crate::application;
+use crate::domain::entities;
+use mongodb::{
+    bson::{
+        doc,
+        Uuid
+    },
+    Database,
+    Collection,
+};
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+
+pub struct WebhookDeliveryRepository {
+    read_collection: Collection<WebhookDelivery>,
+    write_collection: Collection<WebhookDelivery>
+}
+
+impl WebhookDeliveryRepository {
+    pub fn new(db: &Database) -> Self {
+        Self {
+            write_collection: db.collection(WEBHOOK_DELIVERY_COLLECTION),
+            read_collection: db.collection(WEBHOOK_DELIVERY_COLLECTION)
+        }
+    }
+}
+
+#[async_trait]
+impl application::ports::repositories::WebhookDeliveryRepository for WebhookDeliveryRepository {
+    async fn save(&self, delivery: &entities::webhook_delivery::WebhookDelivery) -> Result<(), ApplicationError> {
+        let document = WebhookDelivery::from(delivery);
+
+        self.write_collection.insert_one(&document, None)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_resource_id(&self, resource_id: uuid::Uuid) -> Result<Vec<entities::webhook_delivery::WebhookDelivery>, ApplicationError> {
+        let filter = doc! {
+            "resource_id": Uuid::from_bytes(*resource_id.as_bytes()),
+        };
+
+        let mut cursor = self.read_collection.find(filter, None)
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?;
+
+        let mut deliveries: Vec<entities::webhook_delivery::WebhookDelivery> = Vec::new();
+        while let Some(delivery_doc) = cursor.try_next()
+            .await
+            .map_err(|err| ApplicationError::RepoError(err.to_string()))?
+        {
+            deliveries.push(entities::webhook_delivery::WebhookDelivery::from(&delivery_doc));
+        }
+
+        Ok(deliveries)
+    }
+}
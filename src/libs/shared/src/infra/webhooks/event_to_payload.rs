@@ -0,0 +1,16 @@
+use crate::application::ports::webhooks::WebhookEvent;
+use crate::infra::webhooks::payloads;
+
+impl From<&WebhookEvent> for payloads::WebhookEventPayload {
+    fn from(value: &WebhookEvent) -> Self {
+        Self {
+            id: value.id.to_string(),
+            event_type: String::from(value.event_type.clone()),
+            resource_id: value.resource_id.to_string(),
+            artifact_id: value.artifact_id.to_string(),
+            status: value.status.clone(),
+            last_message: value.last_message.clone(),
+            occurred_at: String::from(value.occurred_at.clone()),
+        }
+    }
+}
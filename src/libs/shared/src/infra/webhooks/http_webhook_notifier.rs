@@ -0,0 +1,94 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use sha2::Sha256;
+use crate::application::ports::webhooks::{
+    WebhookEvent,
+    WebhookNotifier,
+    WebhookNotifierError
+};
+use crate::infra::webhooks::payloads::WebhookEventPayload;
+use crate::logging::GlobalLogger;
+
+pub const WEBHOOK_EVENT_HEADER: &str = "X-MLHub-Event";
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-MLHub-Delivery";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-MLHub-Signature-256";
+
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// Signs the body of a webhook request with HMAC-SHA256. The resulting value
+/// is sent in the signature header in the form `sha256=<hex digest>` so that
+/// receivers can verify the request originated from ML Hub
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC can take a key of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Delivers webhook events as signed JSON POST requests
+pub struct HttpWebhookNotifier {
+    client: Client,
+    secret: Option<String>,
+}
+
+impl HttpWebhookNotifier {
+    pub fn new(secret: Option<String>, timeout: Duration) -> Result<Self, WebhookNotifierError> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|err| WebhookNotifierError::TransportError(format!("Failed to build the webhook http client: {}", err)))?;
+
+        Ok(Self {
+            client,
+            secret
+        })
+    }
+
+    /// Reads the signing secret from the WEBHOOK_SIGNING_SECRET env var. Webhooks
+    /// are delivered unsigned if it is not set. Meant to be called once at
+    /// startup and shared, as each notifier holds its own http client
+    pub fn from_env() -> Result<Self, WebhookNotifierError> {
+        let secret = std::env::var("WEBHOOK_SIGNING_SECRET").ok();
+        if secret.is_none() {
+            GlobalLogger::warn("WEBHOOK_SIGNING_SECRET is not set. Webhook events will be delivered without a signature");
+        }
+
+        Self::new(secret, Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+    }
+}
+
+#[async_trait]
+impl WebhookNotifier for HttpWebhookNotifier {
+    async fn notify(&self, url: &str, event: &WebhookEvent) -> Result<u16, WebhookNotifierError> {
+        let body = serde_json::to_vec(&WebhookEventPayload::from(event))
+            .map_err(|err| WebhookNotifierError::SerializationError(err.to_string()))?;
+
+        let mut request = self.client.post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_EVENT_HEADER, String::from(event.event_type.clone()))
+            .header(WEBHOOK_DELIVERY_HEADER, event.id.to_string());
+
+        if let Some(secret) = &self.secret {
+            request = request.header(WEBHOOK_SIGNATURE_HEADER, sign_payload(secret, &body));
+        }
+
+        let response = request.body(body)
+            .send()
+            .await
+            .map_err(|err| WebhookNotifierError::TransportError(err.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(WebhookNotifierError::UnexpectedStatus(status.as_u16()))
+        }
+
+        Ok(status.as_u16())
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "http_webhook_notifier.test.rs"]
+mod http_webhook_notifier_test;
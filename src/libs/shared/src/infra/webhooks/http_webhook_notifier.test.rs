@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod http_webhook_notifier_test {
+    use std::sync::mpsc::Receiver;
+    use std::time::Duration;
+    use test_support::stub_server::{self, StubRequest, StubResponse};
+    use uuid::Uuid;
+    use crate::application::ports::webhooks::{WebhookEvent, WebhookNotifier, WebhookNotifierError};
+    use crate::domain::entities::webhook_delivery::WebhookEventType;
+    use crate::infra::webhooks::http_webhook_notifier::{
+        sign_payload,
+        HttpWebhookNotifier,
+        WEBHOOK_DELIVERY_HEADER,
+        WEBHOOK_EVENT_HEADER,
+        WEBHOOK_SIGNATURE_HEADER
+    };
+    use crate::infra::webhooks::payloads::WebhookEventPayload;
+
+    /// Starts a stub server that responds to every request with the provided
+    /// status code. Returns the webhook url and the receiver of the requests
+    fn start_stub_server(status: u16) -> (String, Receiver<StubRequest>) {
+        let (base_url, receiver) = stub_server::start(move |_| StubResponse {
+            status,
+            headers: vec![],
+            body: vec![],
+        });
+
+        (format!("{}/webhook", base_url), receiver)
+    }
+
+    fn event() -> WebhookEvent {
+        WebhookEvent::new(
+            WebhookEventType::ArtifactIngestionStatusChanged,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Downloading".into(),
+            Some("Download in progress".into()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_posts_signed_event() {
+        let (url, receiver) = start_stub_server(200);
+        let notifier = HttpWebhookNotifier::new(Some("secret".into()), Duration::from_secs(5)).unwrap();
+        let event = event();
+
+        let status_code = notifier.notify(&url, &event).await.expect("Delivery should succeed");
+        assert_eq!(status_code, 200);
+
+        let request = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        let payload: WebhookEventPayload = serde_json::from_slice(&request.body).unwrap();
+
+        assert_eq!(payload.id, event.id.to_string());
+        assert_eq!(payload.resource_id, event.resource_id.to_string());
+        assert_eq!(payload.event_type, "artifact.ingestion.status_changed");
+        assert_eq!(payload.status, "Downloading");
+        assert_eq!(request.header(WEBHOOK_EVENT_HEADER), Some("artifact.ingestion.status_changed"));
+        assert_eq!(request.header(WEBHOOK_DELIVERY_HEADER), Some(event.id.to_string().as_str()));
+        assert_eq!(request.header(WEBHOOK_SIGNATURE_HEADER), Some(sign_payload("secret", &request.body).as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_omits_signature_without_secret() {
+        let (url, receiver) = start_stub_server(204);
+        let notifier = HttpWebhookNotifier::new(None, Duration::from_secs(5)).unwrap();
+
+        notifier.notify(&url, &event()).await.expect("Delivery should succeed");
+
+        let request = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(request.header(WEBHOOK_SIGNATURE_HEADER).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unsuccessful_status_is_an_error() {
+        let (url, _receiver) = start_stub_server(500);
+        let notifier = HttpWebhookNotifier::new(Some("secret".into()), Duration::from_secs(5)).unwrap();
+
+        match notifier.notify(&url, &event()).await {
+            Err(WebhookNotifierError::UnexpectedStatus(500)) => {},
+            other => panic!("Expected UnexpectedStatus(500), got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn test_sign_payload() {
+        // Known HMAC-SHA256 test vector (RFC 4231 test case 2)
+        assert_eq!(
+            sign_payload("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
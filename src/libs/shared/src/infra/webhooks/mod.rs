@@ -0,0 +1,3 @@
+pub mod payloads;
+pub mod event_to_payload;
+pub mod http_webhook_notifier;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+
+/// The JSON body POSTed to a webhook url
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct WebhookEventPayload {
+    pub id: String,
+    pub event_type: String,
+    pub resource_id: String,
+    pub artifact_id: String,
+    pub status: String,
+    pub last_message: Option<String>,
+    pub occurred_at: String,
+}
@@ -9,6 +9,9 @@ use crate::presentation::http::v1::dto::Parameters;
 pub struct IngestArtifactBody {
     pub include_paths: Option<Vec<String>>,
     pub exclude_paths: Option<Vec<String>>,
+    /// Tag, branch or commit to ingest. Platforms that are not versioned
+    /// ignore it
+    pub revision: Option<String>,
     pub webhook_url: Option<String>,
     pub params: Option<Parameters>,
 }
@@ -24,10 +27,44 @@ pub struct PublishArtifactPath {
     pub artifact_id: String
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetArtifactPublicationPath {
+    pub publication_id: String
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ListArtifactPublicationsPath {
+    pub artifact_id: String
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetArtifactIngestionPath {
+    pub ingestion_id: String
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ListArtifactIngestionsPath {
+    pub artifact_id: String
+}
+
+/// Options for publishing an artifact. Platforms ignore the options they do
+/// not support
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PublishOptions {
+    /// Create the repository on the target platform if it does not exist
+    pub create_repo: Option<bool>,
+    /// Visibility of a created repository. Repositories are private by default
+    pub private: Option<bool>,
+    pub commit_message: Option<String>,
+    /// Branch to publish to. Defaults to the platform's default branch
+    pub branch: Option<String>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct PublishArtifactBody {
     pub target_platform: String,
-    pub webhook_url: Option<String>
+    pub webhook_url: Option<String>,
+    pub options: Option<PublishOptions>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
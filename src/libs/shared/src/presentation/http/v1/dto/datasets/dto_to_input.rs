@@ -0,0 +1,145 @@
+use serde_json::to_vec;
+use crate::presentation::http::v1::dto::datasets as dto;
+use crate::application::inputs::artifacts as artifact_inputs;
+use crate::application::inputs::dataset_metadata as inputs;
+use crate::errors::Error;
+use uuid::Uuid;
+
+impl TryFrom<dto::IngestDatasetRequest> for artifact_inputs::IngestArtifactInput {
+    type Error = Error;
+    fn try_from(value: dto::IngestDatasetRequest) -> Result<Self, Self::Error> {
+        let serialized_client_request = to_vec(&value)
+            .map_err(|err| Error::new(format!("Failed serialize the full client request: {}", err.to_string())))?;
+        
+        Ok(Self {
+            artifact_type: artifact_inputs::ArtifactType::Dataset,
+            platform: value.path.platform,
+            platform_artifact_id: value.path.dataset_id,
+            revision: value.body.revision,
+            webhook_url: value.body.webhook_url,
+            serialized_client_request
+        })
+    }
+}
+
+
+impl TryFrom<dto::UploadDatasetRequest> for artifact_inputs::UploadArtifactInput {
+    type Error = Error;
+    fn try_from(_value: dto::UploadDatasetRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            artifact_type: artifact_inputs::ArtifactType::Dataset
+        })
+    }
+}
+
+impl TryFrom<dto::DownloadDatasetRequest> for artifact_inputs::DownloadArtifactInput {
+    type Error = Error;
+    fn try_from(value: dto::DownloadDatasetRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            artifact_type: artifact_inputs::ArtifactType::Dataset,
+            artifact_id: value.path.artifact_id.clone()
+        })
+    }
+}
+
+impl TryFrom<dto::DatasetFeature> for inputs::DatasetFeature {
+    type Error = Error;
+
+    fn try_from(value: dto::DatasetFeature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            data_type: value.data_type,
+            description: value.description,
+            nullable: value.nullable
+        })
+    }
+}
+
+impl TryFrom<dto::DatasetSplit> for inputs::DatasetSplit {
+    type Error = Error;
+
+    fn try_from(value: dto::DatasetSplit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            num_rows: value.num_rows,
+            num_bytes: value.num_bytes
+        })
+    }
+}
+
+impl TryFrom<dto::DatasetProvenance> for inputs::DatasetProvenance {
+    type Error = Error;
+
+    fn try_from(value: dto::DatasetProvenance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_platform: value.source_platform,
+            source_id: value.source_id,
+            source_url: value.source_url,
+            revision: value.revision,
+            creators: value.creators,
+            citation: value.citation,
+            collection_method: value.collection_method
+        })
+    }
+}
+
+impl TryFrom<dto::CreateDatasetMetadata> for inputs::CreateDatasetMetadata {
+    type Error = Error;
+
+    fn try_from(value: dto::CreateDatasetMetadata) -> Result<Self, Self::Error> {
+        let metadata = inputs::DatasetMetadata::try_from(value.metadata)?;
+        let artifact_id = Uuid::parse_str(&value.artifact_id)
+            .map_err(|err| Self::Error::new(err.to_string()))?;
+
+        Ok(Self {
+            artifact_id,
+            metadata
+        })
+    }
+}
+
+impl TryFrom<dto::DatasetMetadata> for inputs::DatasetMetadata {
+    type Error = Error;
+
+    fn try_from(value: dto::DatasetMetadata) -> Result<Self, Self::Error> {
+        let mut schema = Vec::with_capacity(1);
+        for feature in value.schema.unwrap_or(Vec::with_capacity(0)) {
+            schema.push(inputs::DatasetFeature::try_from(feature)?)
+        }
+
+        let mut splits = Vec::with_capacity(1);
+        for split in value.splits.unwrap_or(Vec::with_capacity(0)) {
+            splits.push(inputs::DatasetSplit::try_from(split)?)
+        }
+
+        let provenance = value.provenance
+            .map(|p| inputs::DatasetProvenance::try_from(p))
+            .transpose()?;
+
+        Ok(Self {
+            // General fields
+            name: value.name,
+            version: value.version,
+            description: value.description,
+            modalities: value.modalities,
+            task_types: value.task_types,
+            format: value.format,
+
+            // Arbitrary labels
+            labels: value.labels,
+
+            // Structure fields
+            schema: Some(schema),
+            splits: Some(splits),
+            num_rows: value.num_rows,
+            size_bytes: value.size_bytes,
+
+            // Provenance fields
+            provenance,
+
+            // Regulatory and Compliance Fields
+            license: value.license,
+            regulatory: value.regulatory
+        })
+    }
+}
\ No newline at end of file
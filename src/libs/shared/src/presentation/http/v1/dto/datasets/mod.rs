@@ -1,12 +1,12 @@
+pub mod domain_to_dto;
+pub mod dto_to_input;
+
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use actix_multipart::Multipart;
 use bytes::Bytes;
 use crate::presentation::http::v1::dto::headers::Headers;
-use crate::presentation::http::v1::dto::artifacts::{
-    DownloadArtifactBody,
-    IngestArtifactBody
-};
+use crate::presentation::http::v1::dto::artifacts::IngestArtifactBody;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ListDatasetsPath {
@@ -19,7 +19,7 @@ pub struct GetDatasetPath {
     pub dataset_id: String
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct IngestDatasetPath {
     pub platform: String,
     pub dataset_id: String
@@ -27,8 +27,7 @@ pub struct IngestDatasetPath {
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct DownloadDatasetPath {
-    pub platform: String,
-    pub dataset_id: String
+    pub artifact_id: String
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -51,6 +50,7 @@ pub struct GetDatasetRequest {
     pub body: Bytes,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct IngestDatasetRequest {
     pub headers: Headers,
     pub path: IngestDatasetPath,
@@ -61,13 +61,82 @@ pub struct IngestDatasetRequest {
 pub struct DownloadDatasetRequest {
     pub headers: Headers,
     pub path: DownloadDatasetPath,
-    pub query: HashMap<String, String>,
-    pub body: DownloadArtifactBody,
 }
 
+pub struct UploadDatasetRequest {}
+
 pub struct PublishDatasetRequest {
     pub headers: Headers,
     pub path: PublishDatasetPath,
     pub query: HashMap<String, String>,
     pub payload: Multipart,
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CreateDatasetMetadataPath {
+    pub artifact_id: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetDatasetMetadataPath {
+    pub artifact_id: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatasetFeature {
+    pub name: String,
+    pub data_type: Option<String>,
+    pub description: Option<String>,
+    pub nullable: Option<bool>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatasetSplit {
+    pub name: String,
+    pub num_rows: Option<i64>,
+    pub num_bytes: Option<i64>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatasetProvenance {
+    pub source_platform: Option<String>,
+    pub source_id: Option<String>,
+    pub source_url: Option<String>,
+    pub revision: Option<String>,
+    pub creators: Option<Vec<String>>,
+    pub citation: Option<String>,
+    pub collection_method: Option<String>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DatasetMetadata {
+    // General fields
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub modalities: Option<Vec<String>>,
+    pub task_types: Option<Vec<String>>,
+    pub format: Option<String>,
+
+    /// Arbitrary labels
+    pub labels: Option<Vec<String>>,
+
+    /// Structure fields
+    pub schema: Option<Vec<DatasetFeature>>,
+    pub splits: Option<Vec<DatasetSplit>>,
+    pub num_rows: Option<i64>,
+    pub size_bytes: Option<i64>,
+
+    /// Provenance fields
+    pub provenance: Option<DatasetProvenance>,
+
+    /// Regulatory and Compliance Fields
+    pub license: Option<String>,
+    pub regulatory: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CreateDatasetMetadata {
+    pub artifact_id: String,
+    pub metadata: DatasetMetadata
+}
@@ -24,12 +24,12 @@ impl Headers {
     }
 
     // By the http standard, some headers can be set more than once, so
-    // we return an optional vector of strings
+    // we return an optional vector of strings. Header names are case-insensitive
     pub fn get_all_values(&self, name: &str) -> Option<Vec<String>> {
         let header_values = self
             .0
             .iter()
-            .filter(|(k, _)| k == &name)
+            .filter(|(k, _)| k.eq_ignore_ascii_case(name))
             .map(|(_, v)| v.clone())
             .collect::<Vec<_>>();
 
@@ -49,42 +49,35 @@ impl Headers {
         return None;
     }
 
-    pub fn validate_authorization_header(
-        &self,
-        auth_header_prefix: Option<&str>,
-    ) -> Result<(), AuthorizationHeaderError> {
-        // get authorization header if there isn't 1 return
-        let header_value = match self.get_first_value("Authorization") {
+    /// Extracts the token from a "Bearer <token>" authorization header. Returns
+    /// None if the request has no authorization header. The header name and the
+    /// scheme are matched case-insensitively
+    pub fn bearer_token(&self) -> Result<Option<String>, AuthorizationHeaderError> {
+        let header_value = match self.get_first_value("authorization") {
             Some(value) => value,
-            None => {
-                return Ok(());
-            }
+            None => return Ok(None),
         };
 
-        // use auth_header_prefix if one is given else use 'Bearer '
-        let prefix = match auth_header_prefix {
-            Some(value) => value,
-            None => "Bearer ",
-        };
-
-        // returns PrefixNotFound error if prefix string not found
-        let header_index = match header_value.find(prefix) {
-            Some(index) => index,
-            None => {
-                return Err(AuthorizationHeaderError::PrefixNotFound);
-            }
-        };
+        let header_value = header_value.trim();
+        let (scheme, token) = header_value.split_once(char::is_whitespace)
+            .unwrap_or((header_value, ""));
 
-        // prefix should be at index 0 malformed error
-        if header_index != 0 {
-            return Err(AuthorizationHeaderError::MalformedHeader(header_value));
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return Err(AuthorizationHeaderError::PrefixNotFound);
         }
 
-        if prefix.len() == header_value.len() {
+        let token = token.trim();
+        if token.is_empty() {
             return Err(AuthorizationHeaderError::NoValue);
         }
 
-        return Ok(());
+        // A token never contains whitespace. The header value is not included
+        // in the error as it contains the token
+        if token.contains(char::is_whitespace) {
+            return Err(AuthorizationHeaderError::MalformedHeader(String::from("expected 'Bearer <token>'")));
+        }
+
+        Ok(Some(token.to_string()))
     }
 }
 
@@ -101,4 +94,9 @@ pub enum AuthorizationHeaderError {
 
     #[error("Provided prefix not found")]
     PrefixNotFound,
-}
\ No newline at end of file
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "headers.test.rs"]
+mod headers_test;
@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod headers_test {
+    use crate::presentation::http::v1::dto::headers::{AuthorizationHeaderError, Headers};
+
+    fn headers(name: &str, value: &str) -> Headers {
+        Headers::new(vec![(String::from(name), String::from(value))])
+    }
+
+    #[test]
+    fn test_get_values_case_insensitive() {
+        let headers = headers("authorization", "Bearer hf_token");
+
+        assert_eq!(headers.get_first_value("Authorization"), Some(String::from("Bearer hf_token")));
+        assert_eq!(headers.get_all_values("AUTHORIZATION").map(|values| values.len()), Some(1));
+        assert_eq!(headers.get_first_value("Authroization"), None);
+    }
+
+    #[test]
+    fn test_bearer_token() {
+        assert_eq!(headers("Authorization", "Bearer hf_token").bearer_token().unwrap(), Some(String::from("hf_token")));
+        assert_eq!(headers("authorization", "bearer  hf_token ").bearer_token().unwrap(), Some(String::from("hf_token")));
+        assert_eq!(Headers::new(Vec::new()).bearer_token().unwrap(), None);
+
+        assert!(matches!(headers("authorization", "Basic dXNlcjpwYXNz").bearer_token(), Err(AuthorizationHeaderError::PrefixNotFound)));
+        assert!(matches!(headers("authorization", "Bearer ").bearer_token(), Err(AuthorizationHeaderError::NoValue)));
+
+        let err = headers("authorization", "Bearer hf_token extra").bearer_token().unwrap_err();
+        assert!(matches!(err, AuthorizationHeaderError::MalformedHeader(_)));
+        assert!(!err.to_string().contains("hf_token"));
+    }
+}
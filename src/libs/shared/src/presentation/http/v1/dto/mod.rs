@@ -6,6 +6,7 @@ pub mod artifacts;
 pub mod headers;
 pub mod filtering;
 pub mod archive;
+pub mod patch;
 
 use serde_json::Value;
 
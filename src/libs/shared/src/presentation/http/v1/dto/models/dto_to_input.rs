@@ -1,5 +1,7 @@
 use serde_json::to_vec;
 use crate::presentation::http::v1::dto::models as dto;
+use crate::presentation::http::v1::dto::filtering::ListAll;
+use crate::application::inputs::filtering as filtering_inputs;
 use crate::application::inputs::model_metadata as inputs;
 use crate::application::inputs::artifacts as artifact_inputs;
 use crate::errors::Error;
@@ -153,6 +155,7 @@ impl TryFrom<dto::IngestModelRequest> for artifact_inputs::IngestArtifactInput {
             artifact_type: artifact_inputs::ArtifactType::Model,
             platform: value.path.platform,
             platform_artifact_id: value.path.model_id,
+            revision: value.body.revision,
             webhook_url: value.body.webhook_url,
             serialized_client_request
         })
@@ -176,4 +179,26 @@ impl TryFrom<dto::DownloadModelRequest> for artifact_inputs::DownloadArtifactInp
             artifact_id: value.path.artifact_id.clone()
         })
     }
+}
+
+impl TryFrom<dto::ListModelMetadataQuery> for filtering_inputs::ListAll {
+    type Error = Error;
+
+    fn try_from(value: dto::ListModelMetadataQuery) -> Result<Self, Self::Error> {
+        let fields = value.fields.map(|fields| {
+            fields.split(',')
+                .map(|field| field.trim().to_string())
+                .filter(|field| !field.is_empty())
+                .collect()
+        });
+
+        Self::try_from(ListAll {
+            page: value.page,
+            page_size: value.page_size,
+            fields,
+            filters: None,
+            sort_by: value.sort_by,
+            order_by: value.order_by,
+        })
+    }
 }
\ No newline at end of file
@@ -6,12 +6,18 @@ use serde_json::Value;
 use std::collections::HashMap;
 use crate::presentation::http::v1::dto::headers::Headers;
 use crate::presentation::http::v1::dto::artifacts::IngestArtifactBody;
+use crate::presentation::http::v1::dto::filtering::Order;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ListModelsPath {
     pub platform: String
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetPlatformPath {
+    pub platform: String
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GetModelPath {
     pub platform: String,
@@ -75,6 +81,32 @@ pub struct CreateModelMetadataPath {
     pub artifact_id: String
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetModelMetadataPath {
+    pub artifact_id: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UpdateModelMetadataPath {
+    pub artifact_id: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DeleteModelMetadataPath {
+    pub artifact_id: String
+}
+
+/// Query parameters for listing model metadata. Fields to project are
+/// provided as a comma separated list (ex. ?fields=name,framework)
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ListModelMetadataQuery {
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+    pub fields: Option<String>,
+    pub sort_by: Option<String>,
+    pub order_by: Option<Order>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SystemRequirement {
     pub name: String,
@@ -171,4 +203,41 @@ pub struct CreateModelMetadata {
 pub struct DiscoveryCriteriaBody {
     pub criteria: Vec<ModelMetadata>,
     pub confidence_threshold: Option<Vec<String>>,
+}
+
+impl DiscoveryCriteriaBody {
+    /// The confidence threshold of each criterion. The threshold is either a
+    /// single value that applies to every criterion, or one value per
+    /// criterion. Criteria default to a threshold of 0
+    pub fn confidence_thresholds(&self) -> Result<Vec<f64>, String> {
+        let thresholds = self.confidence_threshold.iter()
+            .flatten()
+            .map(|threshold| {
+                threshold.trim().parse::<f64>()
+                    .ok()
+                    .filter(|threshold| (0.0..=1.0).contains(threshold))
+                    .ok_or(format!("Invalid confidence threshold '{}'. Expected a number between 0 and 1", threshold))
+            })
+            .collect::<Result<Vec<f64>, String>>()?;
+
+        match thresholds.len() {
+            0 => Ok(vec![0.0; self.criteria.len()]),
+            1 => Ok(vec![thresholds[0]; self.criteria.len()]),
+            count if count == self.criteria.len() => Ok(thresholds),
+            count => Err(format!("Expected 1 or {} confidence thresholds. Found {}", self.criteria.len(), count)),
+        }
+    }
+}
+
+/// The confidence that a model matches a discovery criterion, which is the
+/// fraction of the criterion's fields that the model matches. A model must
+/// match at least one field and reach the criterion's threshold, so criteria
+/// without any field that can be evaluated match nothing
+pub fn discovery_confidence(matched: usize, total: usize, threshold: f64) -> Option<f64> {
+    if matched == 0 || total == 0 {
+        return None
+    }
+
+    let confidence = matched as f64 / total as f64;
+    (confidence >= threshold).then_some(confidence)
 }
\ No newline at end of file
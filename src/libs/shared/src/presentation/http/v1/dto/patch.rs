@@ -0,0 +1,34 @@
+use serde_json::Value;
+
+/// Applies a JSON merge patch (RFC 7386) to the target. Objects are merged
+/// recursively, `null` removes a field and any other value replaces the
+/// value in the target
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let patch_map = match patch {
+        Value::Object(map) => map,
+        _ => {
+            *target = patch.clone();
+            return;
+        }
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+
+    if let Value::Object(target_map) = target {
+        for (key, value) in patch_map {
+            if value.is_null() {
+                target_map.remove(key);
+                continue;
+            }
+
+            merge_patch(target_map.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "patch.test.rs"]
+mod patch_test;
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod patch_test {
+    use serde_json::json;
+    use crate::presentation::http::v1::dto::patch::merge_patch;
+
+    #[test]
+    fn test_merge_patch_replaces_and_adds_fields() {
+        let mut target = json!({"name": "model", "framework": "pytorch"});
+        merge_patch(&mut target, &json!({"framework": "tensorflow", "version": "1.0.0"}));
+
+        assert_eq!(target, json!({"name": "model", "framework": "tensorflow", "version": "1.0.0"}));
+    }
+
+    #[test]
+    fn test_merge_patch_null_removes_field() {
+        let mut target = json!({"name": "model", "license": "mit"});
+        merge_patch(&mut target, &json!({"license": null}));
+
+        assert_eq!(target, json!({"name": "model"}));
+    }
+
+    #[test]
+    fn test_merge_patch_merges_nested_objects_and_replaces_arrays() {
+        let mut target = json!({
+            "inference_hardware": {"cpus": 4, "memory_gb": 16},
+            "labels": ["a", "b"]
+        });
+        merge_patch(&mut target, &json!({
+            "inference_hardware": {"memory_gb": 32},
+            "labels": ["c"]
+        }));
+
+        assert_eq!(target, json!({
+            "inference_hardware": {"cpus": 4, "memory_gb": 32},
+            "labels": ["c"]
+        }));
+    }
+}
@@ -1,6 +1,38 @@
 use crate::domain::entities;
 use crate::presentation::http::v1::responses;
 
+impl From<entities::artifact::ArtifactType> for responses::ArtifactType {
+    fn from(value: entities::artifact::ArtifactType) -> Self {
+        match value {
+            entities::artifact::ArtifactType::Model => responses::ArtifactType::Model,
+            entities::artifact::ArtifactType::Dataset => responses::ArtifactType::Dataset,
+        }
+    }
+}
+
+impl From<entities::artifact::ArtifactProvenance> for responses::ArtifactProvenance {
+    fn from(value: entities::artifact::ArtifactProvenance) -> Self {
+        responses::ArtifactProvenance {
+            platform: value.platform,
+            source_id: value.source_id,
+            revision: value.revision,
+            resolved_revision: value.resolved_revision,
+        }
+    }
+}
+
+impl From<entities::artifact::Artifact> for responses::Artifact {
+    fn from(value: entities::artifact::Artifact) -> Self {
+        responses::Artifact {
+            id: value.id.to_string(),
+            artifact_type: responses::ArtifactType::from(value.artifact_type),
+            created_at: String::from(value.created_at),
+            last_modified: String::from(value.last_modified),
+            provenance: value.provenance.map(responses::ArtifactProvenance::from),
+        }
+    }
+}
+
 impl From<entities::artifact_ingestion::ArtifactIngestionStatus> for responses::ArtifactIngestionStatus {
     fn from(value: entities::artifact_ingestion::ArtifactIngestionStatus) -> Self {
         match value {
@@ -27,7 +59,8 @@ impl From<entities::artifact_ingestion::ArtifactIngestion> for responses::Artifa
             last_message: value.last_message,
             platform: value.platform,
             status: responses::ArtifactIngestionStatus::from(value.status),
-            webhook_url: value.webhook_url
+            webhook_url: value.webhook_url,
+            provenance: value.provenance.map(responses::ArtifactProvenance::from),
         }
     }
 }
@@ -60,6 +93,13 @@ impl From<entities::artifact_publication::ArtifactPublication> for responses::Ar
             target_platform: value.target_platform,
             attempts: value.attempts,
             status: responses::ArtifactPublicationStatus::from(value.status),
+            webhook_url: value.webhook_url,
+            published_ids: value.published_ids,
         }
     }
-}
\ No newline at end of file
+}
+
+// Unit tests
+#[cfg(test)]
+#[path = "domain_to_dto.test.rs"]
+mod domain_to_dto_test;
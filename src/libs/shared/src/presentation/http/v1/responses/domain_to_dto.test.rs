@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod domain_to_dto_test {
+    use std::collections::HashMap;
+    use serde_json::{json, to_value, Value};
+    use uuid::Uuid;
+    use crate::domain::entities::artifact_ingestion::{
+        ArtifactIngestion as ArtifactIngestionEntity, ArtifactIngestionFailureReason,
+        ArtifactIngestionStatus,
+    };
+    use crate::domain::entities::artifact_publication::{
+        ArtifactPublication as ArtifactPublicationEntity, ArtifactPublicationStatus,
+    };
+    use crate::presentation::http::v1::responses::{ArtifactIngestion, ArtifactPublication};
+
+    #[test]
+    fn test_artifact_ingestion() {
+        let artifact_id = Uuid::new_v4();
+        let mut ingestion = ArtifactIngestionEntity::new(
+            artifact_id,
+            String::from("huggingface"),
+            Some(String::from("https://example.org/hooks/ingestions")),
+        );
+        ingestion.status = ArtifactIngestionStatus::Failed(ArtifactIngestionFailureReason::FailedToDownload);
+        ingestion.last_message = Some(String::from("Repository not found"));
+        let ingestion_id = ingestion.id;
+
+        let resp = to_value(ArtifactIngestion::from(ingestion)).unwrap();
+
+        assert_eq!(resp["id"], json!(ingestion_id.to_string()));
+        assert_eq!(resp["artifact_id"], json!(artifact_id.to_string()));
+        assert_eq!(resp["platform"], "huggingface");
+        // The failure reason is internal and only the status is exposed
+        assert_eq!(resp["status"], "Failed");
+        assert_eq!(resp["last_message"], "Repository not found");
+        assert_eq!(resp["webhook_url"], "https://example.org/hooks/ingestions");
+        assert_eq!(resp["provenance"], Value::Null);
+    }
+
+    #[test]
+    fn test_artifact_publication() {
+        let artifact_id = Uuid::new_v4();
+        let mut publication = ArtifactPublicationEntity::new(artifact_id, String::from("patra"), None);
+        publication.status = ArtifactPublicationStatus::Finished;
+        publication.attempts = 1;
+        publication.published_ids = HashMap::from([(String::from("model_card_id"), String::from("1234"))]);
+        let publication_id = publication.id;
+
+        let resp = to_value(ArtifactPublication::from(publication)).unwrap();
+
+        assert_eq!(resp["id"], json!(publication_id.to_string()));
+        assert_eq!(resp["artifact_id"], json!(artifact_id.to_string()));
+        assert_eq!(resp["target_platform"], "patra");
+        assert_eq!(resp["status"], "Finished");
+        assert_eq!(resp["attempts"], 1);
+        assert_eq!(resp["published_ids"], json!({ "model_card_id": "1234" }));
+        assert_eq!(resp["webhook_url"], Value::Null);
+    }
+}
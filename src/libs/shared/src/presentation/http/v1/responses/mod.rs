@@ -1,5 +1,6 @@
 mod domain_to_dto;
 
+use std::collections::HashMap;
 use serde::Serialize;
 use serde_json::Value;
 
@@ -12,6 +13,57 @@ pub struct JsonResponse {
     pub version: Option<String>
 }
 
+#[derive(Serialize)]
+pub enum ArtifactType {
+    Model,
+    Dataset,
+}
+
+/// Where an artifact was ingested from
+#[derive(Serialize)]
+pub struct ArtifactProvenance {
+    pub platform: String,
+    pub source_id: String,
+    /// The tag, branch or commit that was requested
+    pub revision: Option<String>,
+    /// The commit sha the requested revision resolved to
+    pub resolved_revision: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Artifact {
+    pub id: String,
+    pub artifact_type: ArtifactType,
+    pub created_at: String,
+    pub last_modified: String,
+    pub provenance: Option<ArtifactProvenance>,
+}
+
+/// A model as listed or fetched from any platform. Every platform's clients
+/// map their native representation of a model into this shape so that
+/// consumers do not need to know which platform served the model
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ModelSummary {
+    /// Id of the model on the platform that serves it
+    pub id: String,
+    pub platform: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    pub version: Option<String>,
+    pub license: Option<String>,
+    /// The primary task of the model (ex. text-classification)
+    pub task: Option<String>,
+    pub framework: Option<String>,
+    pub tags: Vec<String>,
+    pub downloads: Option<u64>,
+    pub likes: Option<u64>,
+    pub created_at: Option<String>,
+    pub last_modified: Option<String>,
+    /// Paths of the files that make up the model, if known
+    pub files: Vec<String>,
+}
+
 #[derive(Serialize)]
 pub enum ArtifactIngestionStatus {
     Submitted,
@@ -35,6 +87,7 @@ pub struct ArtifactIngestion {
     pub created_at: String,
     pub last_modified: String,
     pub webhook_url: Option<String>,
+    pub provenance: Option<ArtifactProvenance>,
 }
 
 #[derive(Serialize)]
@@ -61,4 +114,7 @@ pub struct ArtifactPublication  {
     pub attempts: u8,
     pub created_at: String,
     pub last_modified: String,
+    pub webhook_url: Option<String>,
+    /// Ids the target platform assigned to what was published
+    pub published_ids: HashMap<String, String>,
 }
\ No newline at end of file
@@ -0,0 +1,3 @@
+//! Helpers shared by the tests of the client crates. Only used as a
+//! dev-dependency
+pub mod stub_server;
@@ -0,0 +1,117 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+
+/// A request received by the stub server
+pub struct StubRequest {
+    /// The request line (ex. "GET /api/models?limit=10 HTTP/1.1")
+    pub request_line: String,
+    /// Header names are lowercased
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl StubRequest {
+    pub fn path(&self) -> &str {
+        self.request_line.split(" ").nth(1).unwrap_or("")
+    }
+
+    pub fn method(&self) -> &str {
+        self.request_line.split(" ").next().unwrap_or("")
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(key, _)| key == &name.to_lowercase())
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct StubResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl StubResponse {
+    pub fn json(body: String) -> Self {
+        Self {
+            status: 200,
+            headers: vec![(String::from("Content-Type"), String::from("application/json"))],
+            body: body.into_bytes(),
+        }
+    }
+}
+
+/// Starts a local http server that responds to every request with the
+/// response built by the handler. Returns the base url of the server and a
+/// receiver for the requests it has handled
+pub fn start<F>(handler: F) -> (String, Receiver<StubRequest>)
+where
+    F: Fn(&StubRequest) -> StubResponse + Send + 'static
+{
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind stub server");
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = channel();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(_) => continue
+            };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut headers = Vec::new();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                if let Some((name, value)) = line.trim().split_once(":") {
+                    headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+                }
+                line.clear();
+            }
+
+            // Consume the body so the client does not see a reset connection
+            let content_length = headers.iter()
+                .find(|(name, _)| name == "content-length")
+                .and_then(|(_, value)| value.parse::<usize>().ok())
+                .unwrap_or(0);
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let request = StubRequest { request_line: request_line.trim().to_string(), headers, body };
+            let response = handler(&request);
+
+            // Record the request before responding so it has been received by
+            // the time the client sees the response
+            if sender.send(request).is_err() {
+                break
+            }
+
+            // A handler can set a Content-Length larger than the body to
+            // simulate a connection that drops mid-response
+            let mut headers = response.headers;
+            if !headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("content-length")) {
+                headers.push((String::from("Content-Length"), response.body.len().to_string()));
+            }
+
+            let headers: String = headers.iter()
+                .map(|(name, value)| format!("{}: {}\r\n", name, value))
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 {} Stub\r\n{}Connection: close\r\n\r\n",
+                response.status,
+                headers,
+            ).unwrap();
+            // The client may hang up early on an incomplete response
+            let _ = stream.write_all(&response.body);
+        }
+    });
+
+    (base_url, receiver)
+}